@@ -5,7 +5,8 @@ import matplotlib.pyplot as plt  # for plotting
 # Variable declaration
 nodes = []  # stores nodes in a list
 edges = []  # stores edges in a list
-switch = False  # serves as marker to know when all nodes are read
+paths = []  # stores edges of ranked alternative paths in a list
+section = 0  # serves as marker to know which section is read in version 1 (0: nodes, 1: edges, 2: ranked paths)
 file_in = "" # data file with coordinates (read in as parameter)
 file_out = "" # png file in which plot is saved (read in as parameter)
 name = sys.argv[0]
@@ -34,30 +35,115 @@ if file_in is None or file_out is None or not os.path.isfile(file_in):
 
 # Open graph data file:
 with open(file_in) as file:
-    lines = file.readlines()
-
+    lines = [line.strip() for line in file.readlines()]  # (Removes leading spaces)
+
+# Version 2 starts with a header line, version 1 has none
+content = [line for line in lines if line != '' and not line.startswith('#')]
+version = 2 if content and content[0].startswith('dijkstra-plot-data') else 1
+startnode = None  # id of start node (version 1: first node)
+
+if version == 2:
+    # Each table starts with its name and columns (name:type), so values are looked up by column name
+    if content[0].split()[1] != '2':
+        print(f"\033[1m{name}:\033[0m Unsupported data file version: {content[0]}")
+        sys.exit(1)
+
+    # Escapes in quoted strings (same as the reader of dijkstra-plot resolves them)
+    escapes = {'"': '"', '\\': '\\', 'n': '\n', 'r': '\r', 't': '\t'}
+
+    def tokenize(line):
+        # Splits line at whitespace outside of quotes into (value, quoted) pairs, escapes in quoted strings are resolved
+        tokens = []
+        i = 0
+        while i < len(line):
+            if line[i].isspace():
+                i += 1
+            elif line[i] == '"':
+                value = ''
+                i += 1
+                while i < len(line) and line[i] != '"':
+                    if line[i] == '\\' and i + 1 < len(line):
+                        if line[i + 1] not in escapes:
+                            raise ValueError(f"unknown escape sequence: \\{line[i + 1]}")
+                        value += escapes[line[i + 1]]
+                        i += 2
+                    else:
+                        value += line[i]
+                        i += 1
+                if i >= len(line):
+                    raise ValueError("unterminated string")
+                tokens.append((value, True))
+                i += 1
+            else:
+                start = i
+                while i < len(line) and not line[i].isspace():
+                    i += 1
+                tokens.append((line[start:i], False))
+        return tokens
+
+    def convert(value, kind, quoted):
+        if value == '-' and not quoted and kind.endswith('?'):
+            return None  # unknown value
+        kind = kind.rstrip('?')
+        if kind == 'int':
+            return int(value)
+        if kind == 'float':
+            return float(value)
+        if kind == 'bool':
+            return value == 'true'
+        return value  # string (quotes are already removed)
+
+    table = None  # name of current table
+    columns = []  # (name, type) of its columns
+    for line in content[1:]:
+        tokens = tokenize(line)  # quoted strings may contain spaces
+        values = [value for value, _ in tokens]
+        keyword = values[0] if not tokens[0][1] else None  # quoted strings are values (e.g. an edge called "start")
+        if keyword == 'start':
+            startnode = values[1]
+        elif keyword in ('nodes', 'edges', 'paths'):
+            table = values[0]
+            columns = [column.split(':') for column in values[1:]]
+        elif table is not None:
+            row = {column: convert(value, kind, quoted) for (column, kind), (value, quoted) in zip(columns, tokens)}
+            if table == 'nodes':
+                nodes.append((row['no'], row['x'], row['y'], row['id'], row['marked']))
+            elif table == 'edges':
+                (_, x1, y1, _, _), (_, x2, y2, _, _) = nodes[row['from']], nodes[row['to']]
+                edges.append((x1, y1, x2, y2, str(row['weight']), row['marked'], row['directed'], row['bend']))
+            else:
+                (_, x1, y1, _, _), (_, x2, y2, _, _) = nodes[row['from']], nodes[row['to']]
+                paths.append((row['rank'], x1, y1, x2, y2, str(row['cost']), row['bend']))
+else:
     # Iterate through each line of file
     for line in lines:
-        line = line.strip()  # (Removes leading spaces)
+        # Comment lines (e.g. negative cycle information) carry no plot data
+        if line.startswith('#'):
+            continue
 
         # Between nodes and edges a blank line is added to mark the section (therefore it is absolutley necessary that source file is not changed manually!)
         if line == '':
-            switch = True
+            section += 1
             continue
 
-        if not switch:
+        if section == 0:
             # there are still nodes to read
             no, x, y, id, marked = line.split()
             nodes.append((int(no), float(x), float(y), str(id), bool(int(marked) == 1)))
-        else:
-            # all nodes have been read, so turn to edges
+        elif section == 1:
+            # all nodes have been read, so turn to edges (version 1 knows neither direction nor parallel edges)
             x1, y1, x2, y2, id, marked = line.split()
-            edges.append((float(x1), float(y1), float(x2), float(y2), str(id), bool(int(marked) == 1)))
+            edges.append((float(x1), float(y1), float(x2), float(y2), str(id), bool(int(marked) == 1), False, 0.0))
+        else:
+            # optional: edges of alternative paths with their rank (k shortest paths)
+            rank, x1, y1, x2, y2, cost = line.split()
+            paths.append((int(rank), float(x1), float(y1), float(x2), float(y2), str(cost), 0.0))
 
 # Convert data to matplotlib readable structures:
 fig, ax = plt.subplots(figsize=(12, 12))
 
-_, _, _, startnode, _ = nodes[0]  # unpack first entry (is by definition start node)
+if startnode is None:
+    _, _, _, startnode, _ = nodes[0]  # unpack first entry (is by definition start node of version 1)
 
 # Plot title
 plt.title("Dijkstra Graph Plot (Start Node: " + startnode + ")", fontsize=30)
@@ -68,15 +154,40 @@ ax.get_yaxis().set_visible(False)
 
 # Convert and add edges to plot:
 for edge in edges:
-    x1, y1, x2, y2, weight, marked = edge  # unpack
+    x1, y1, x2, y2, weight, marked, directed, bend = edge  # unpack
     color = 'red' if marked else 'blue'  # set color for each edge
     mid_x = (x1 + x2) / 2  # calculate position of label
     mid_y = (y1 + y2) / 2
-    ax.plot([x1, x2], [y1, y2], linewidth=4, color=color, label=weight,
-            zorder=1)  # linewidth = Linienbreite (draw lines)
+    if directed or bend != 0:
+        # Parallel edges are curved (bend spacings to the left), directed ones get an arrowhead
+        ax.annotate('', (x2, y2), xytext=(x1, y1), zorder=1,
+                    arrowprops=dict(arrowstyle='-|>' if directed else '-', mutation_scale=25, linewidth=4, color=color,
+                                    shrinkA=0, shrinkB=8, connectionstyle=f'arc3,rad={-0.2 * bend}'))
+        mid_x, mid_y = mid_x - 0.1 * bend * (y2 - y1), mid_y + 0.1 * bend * (x2 - x1)  # middle of curve (roughly)
+    else:
+        ax.plot([x1, x2], [y1, y2], linewidth=4, color=color, label=weight,
+                zorder=1)  # linewidth = Linienbreite (draw lines)
     ax.annotate(str(weight), (mid_x, mid_y), textcoords='offset points', xytext=(0, 10), ha='center',
                 fontsize=14, zorder=2)  # add it to plot
 
+# Add alternative paths (if any) below edges: Lines get wider with increasing rank so overlapping paths stay visible
+path_colors = ['red', 'orange', 'green', 'purple', 'brown', 'pink', 'olive', 'cyan']
+path_costs = {}  # rank -> costs of path
+max_rank = max([path[0] for path in paths], default=0)
+for path in paths:
+    rank, x1, y1, x2, y2, cost, bend = path  # unpack
+    path_costs[rank] = cost
+    color = path_colors[(rank - 1) % len(path_colors)]
+    zorder = 0.5 - rank / (max_rank + 1)  # higher ranks are drawn further below
+    if bend != 0:
+        # Follow the curve of the (parallel) edge the path takes
+        ax.annotate('', (x2, y2), xytext=(x1, y1), zorder=zorder,
+                    arrowprops=dict(arrowstyle='-', linewidth=4 + 5 * rank, color=color, alpha=0.6, capstyle='round',
+                                    shrinkA=0, shrinkB=0, connectionstyle=f'arc3,rad={-0.2 * bend}'))
+    else:
+        ax.plot([x1, x2], [y1, y2], linewidth=4 + 5 * rank, color=color, alpha=0.6, solid_capstyle='round',
+                zorder=zorder)
+
 # Convert and add nodes to plot:
 for node in nodes:
     no, x, y, id, marked = node  # unpack from list
@@ -96,6 +207,9 @@ legend_elements = [
     plt.Line2D([0], [0], marker='o', color='w', label='Marked Node', markerfacecolor='red', markersize=15),
     plt.Line2D([0], [0], color='blue', label='Unmarked Edge', linewidth=4),
     plt.Line2D([0], [0], color='red', label='Marked Edge', linewidth=4)]
+for rank in sorted(path_costs):
+    legend_elements.append(plt.Line2D([0], [0], color=path_colors[(rank - 1) % len(path_colors)], alpha=0.6,
+                                      label=f'Path #{rank} (costs: {path_costs[rank]})', linewidth=8))
 ax.legend(handles=legend_elements, loc='best', fontsize=14)
 
 # Save plot:
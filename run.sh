@@ -3,17 +3,25 @@
 # This script was made to start dijkstra-plot application.
 # To do this it requests various input parameters such as GraphML-input-file,
 # data output file and dijkstra algorithm-specific information such as start/destination
-# node. It then runs a python script that draws the computed graph and outputs
-# it to a separate window. A png file of the output is also be created.
+# node. It then draws the computed graph as PNG image (no python needed; plot.py
+# can still be used on the data file Graph.dat).
 # Stefan Lindörfer - 2023
 
 # TODO: run "cargo build" here in the script? Figure out how path to rust application is been found.
 
-# 1. Read in necessary information from user:
-read -p $'\e[1mInsert GraphML-File:\e[0m ' input_path
-read -p $'\e[1mInsert Data Output File:\e[0m ' output_path
-read -p $'\e[1mInsert Start Node:\e[0m ' start
-read -p $'\e[1mInsert Destination Node:\e[0m ' dest
+# Usage: ./run.sh [GRAPHML-FILE [PLOT-FILE [START-NODE [DESTINATION-NODE]]]]
+# Parameters that are passed are not asked for (so script can be run without any interaction).
+
+# 1. Read in necessary information from user (only those that weren't passed as parameters):
+input_path="$1"
+output_path="$2"
+start="$3"
+dest="$4"
+
+if [ $# -lt 1 ]; then read -p $'\e[1mInsert GraphML-File:\e[0m ' input_path; fi
+if [ $# -lt 2 ]; then read -p $'\e[1mInsert Plot Output File:\e[0m ' output_path; fi
+if [ $# -lt 3 ]; then read -p $'\e[1mInsert Start Node:\e[0m ' start; fi
+if [ $# -lt 4 ]; then read -p $'\e[1mInsert Destination Node:\e[0m ' dest; fi
 
 # TODO: Just for debugging!
 if [ -z "$input_path" ]; then # Checks only if output path is empty and assigns them a default value instead
@@ -36,14 +44,19 @@ fi
 echo -e "\033[1m$BASH_SOURCE:\033[0m Dijkstra Plot is now going to be executed..."
 
 # 2.1 Execute dijkstra-plot with given parameters
-./target/debug/dijkstra-plot -input="$input_path" -output="Graph.dat" -start="$start" -dest="$dest" # & means that application script continues while app is running
+# (see ./target/debug/dijkstra-plot route --help for all options)
+./target/debug/dijkstra-plot route --input="$input_path" --output="Graph.dat" --start="$start" --dest="$dest"
 
-# 2.2 Check if application returned with error code
-if [ $? -eq 1 ]; then # $? is a shell variable that contains exit code of last executed command (here: application)
-  echo -e "\033[1m$BASH_SOURCE:\033[0m \033[31mError in rust application!\033[0m"
-  exit 1 # End script with error code 1
-else
+# 2.2 Check if application returned with error code ($? is a shell variable that contains exit code of last executed command (here: application))
+# Exit codes: 0 success, 2 usage error, 3 I/O error, 4 parse error, 5 validation error (--strict), 6 destination unreachable
+exit_code=$?
+if [ $exit_code -eq 0 ]; then
   echo -e "\033[1m$BASH_SOURCE:\033[0m Dijkstra Plot executed successfully!"
+elif [ $exit_code -eq 6 ]; then # Graph.dat is written anyway, so plot can still be created
+  echo -e "\033[1m$BASH_SOURCE:\033[0m \033[31mDestination node is unreachable from start node!\033[0m"
+else
+  echo -e "\033[1m$BASH_SOURCE:\033[0m \033[31mError in rust application (exit code $exit_code)!\033[0m"
+  exit $exit_code # End script with error code of application
 fi
 
 # 2.3 Check if Graph.dat was created in root directory (Graph.dat is a constant value, output file from rust application is always named as "Graph.dat"!)
@@ -53,12 +66,14 @@ else
 	echo -e "\033[1m$BASH_SOURCE:\033[0m \033[31mGraph.dat was not created!\033[0m"
 fi
 
-# 3. Check if matlibplot library is installed and if yes: Run python script to create plot
-if [ $(pip show matplotlib >/dev/null 2>&1; echo $?) -eq 0 ]; then
-    # Run python script (that writes specific information into output stream)
-    python3 plot.py -input=$input_path -output="$output_path"
-  else
-    echo -e "\033[1m$BASH_SOURCE:\033[0m \033[31mPython script couldn't be executed because matlibplot library isn't installed on the system!\033[0m"
+# 3. Draw graph as PNG image (same computation as above, see ./target/debug/dijkstra-plot render --help)
+./target/debug/dijkstra-plot render --input="$input_path" --output="$output_path" --start="$start" --dest="$dest" > /dev/null
+render_code=$?
+if [ $render_code -eq 0 ] || [ $render_code -eq 6 ]; then
+  echo -e "\033[1m$BASH_SOURCE:\033[0m Plot was saved at \033[1m$output_path\033[0m"
+else
+  echo -e "\033[1m$BASH_SOURCE:\033[0m \033[31mPlot could not be created (exit code $render_code)!\033[0m"
+  exit $render_code # End script with error code of application
 fi
 
 # 4. Finished!
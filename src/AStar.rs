@@ -0,0 +1,109 @@
+pub mod heuristic;
+
+pub use crate::AStar::heuristic::{Heuristic, HeuristicKind};
+
+// Own objects.
+use crate::Dijkstra::{Dijkstra, DijkstraHeapEle, DijkstraPath, INFINITE_COSTS};
+use crate::Graph::{EdgeIndex, Graph, IgraphObject, NodeIndex};
+use crate::PriorityQueue::{BinaryHeap, PriorityQueue};
+
+// Result of an A* search: Path to destination and number of nodes that had to be expanded to find it.
+pub struct AStarResult {
+    pub path: DijkstraPath,
+    pub expanded: usize,
+}
+
+pub struct AStar;
+
+impl AStar {
+    // Reads coordinates of each node from the node keys with given attribute names. Nodes without numeric coordinates get None.
+    pub fn coordinates(graph: &Graph, keys: (&str, &str)) -> Vec<Option<(f64, f64)>> {
+        graph
+            .nodes()
+            .iter()
+            .map(|node| Some((node.attr_f64(keys.0)?, node.attr_f64(keys.1)?)))
+            .collect()
+    }
+
+    // Searches cheapest path from start to dest. Nodes are expanded in order of costs so far plus estimated remaining costs,
+    // so (with a good heuristic) only a fraction of the nodes dijkstra algorithm settles has to be looked at. Fails if the
+    // heuristic needs coordinates but dest has none (every estimation would be 0, e.g. because the key names are wrong) or
+    // if the graph has negative weights.
+    pub fn run(graph: &Graph, start: NodeIndex, dest: NodeIndex, heuristic: &dyn Heuristic, keys: (&str, &str)) -> Result<AStarResult, String> {
+        Dijkstra::check_weights(graph)?;
+
+        let node_len = graph.node_len();
+        let coordinates = Self::coordinates(graph, keys);
+
+        if start.index() >= node_len || dest.index() >= node_len {
+            return Ok(AStarResult { path: DijkstraPath::Unreachable, expanded: 0 });
+        }
+        let (s, t) = (start.index(), dest.index());
+
+        if heuristic.uses_coordinates() && coordinates[t].is_none() {
+            return Err(format!("Destination node {} has no numeric coordinates in keys {} and {}", graph.node(dest).get_id(), keys.0, keys.1));
+        }
+
+        // Estimated remaining costs to dest. Rounded down so estimation stays admissible (the cast saturates at i64::MAX);
+        // nodes without coordinates get 0.
+        let estimate = |v: usize| -> i64 {
+            match (coordinates[v], coordinates[t]) {
+                (Some(from), Some(to)) => heuristic.estimate(from, to).max(0.0).floor() as i64,
+                _ => 0,
+            }
+        };
+
+        // Currently known costs and predecessors, indexed like graph.nodes().
+        let mut dist: Vec<Option<i64>> = vec![None; node_len];
+        let mut prev: Vec<Option<(NodeIndex, EdgeIndex)>> = vec![None; node_len];
+        let mut expanded_nodes: Vec<usize> = Vec::new();
+
+        // Open list ordered by f = g + h.
+        let mut q = BinaryHeap::new(node_len, 0);
+
+        dist[s] = Some(0);
+        q.push(s, estimate(s));
+
+        while let Some((u, _)) = q.pop() {
+            expanded_nodes.push(u);
+            let Some(g) = dist[u] else {
+                continue; // only reached nodes are queued
+            };
+
+            if u == t {
+                break;
+            }
+
+            for &(v, e) in graph.neighbors(NodeIndex::new(u)).iter() {
+                let Some(cost) = Dijkstra::add_costs(g, graph.edge(e).weight()) else {
+                    continue; // costs can't be represented
+                };
+
+                // A node is opened again if a cheaper path to it is found (possible if heuristic is not consistent). Keys
+                // are only an order, so they may saturate.
+                if dist[v.index()].is_none_or(|dist| cost < dist) {
+                    dist[v.index()] = Some(cost);
+                    prev[v.index()] = Some((NodeIndex::new(u), e));
+                    q.push(v.index(), cost.saturating_add(estimate(v.index())));
+                }
+            }
+        }
+
+        let expanded = expanded_nodes.len();
+
+        if dist[t].is_none() {
+            return Ok(AStarResult { path: DijkstraPath::Unreachable, expanded });
+        }
+
+        // Convert search tree into dijkstra result so path can be reconstructed the same way.
+        expanded_nodes.sort_unstable();
+        expanded_nodes.dedup();
+
+        let result: Vec<DijkstraHeapEle> = expanded_nodes
+            .into_iter()
+            .map(|v| DijkstraHeapEle::new(NodeIndex::new(v), prev[v], dist[v].unwrap_or(INFINITE_COSTS)))
+            .collect();
+
+        Ok(AStarResult { path: Dijkstra::path_to(graph, &result, dest), expanded })
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+// Mean earth radius in kilometers (used by haversine formula).
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+// Estimates costs between two node coordinates. To keep A* exact the estimation must never be greater than
+// the real costs of the cheapest path (admissible). scale converts coordinate distances into edge weight units.
+pub trait Heuristic {
+    fn estimate(&self, from: (f64, f64), to: (f64, f64)) -> f64;
+
+    // Whether estimate() looks at the coordinates at all (A* refuses to run without them otherwise).
+    fn uses_coordinates(&self) -> bool {
+        true
+    }
+}
+
+// Straight-line distance in the plane.
+pub struct Euclidean {
+    pub scale: f64,
+}
+
+// Sum of absolute coordinate differences (admissible if edges only run parallel to axes).
+pub struct Manhattan {
+    pub scale: f64,
+}
+
+// Great-circle distance in kilometers between (lat, lon) coordinates given in degrees.
+pub struct Haversine {
+    pub scale: f64,
+}
+
+// No estimation at all: A* behaves exactly like dijkstra algorithm.
+pub struct Zero;
+
+impl Heuristic for Euclidean {
+    fn estimate(&self, from: (f64, f64), to: (f64, f64)) -> f64 {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        self.scale * f64::sqrt(dx.powi(2) + dy.powi(2))
+    }
+}
+
+impl Heuristic for Manhattan {
+    fn estimate(&self, from: (f64, f64), to: (f64, f64)) -> f64 {
+        self.scale * ((to.0 - from.0).abs() + (to.1 - from.1).abs())
+    }
+}
+
+impl Heuristic for Haversine {
+    fn estimate(&self, from: (f64, f64), to: (f64, f64)) -> f64 {
+        let (lat1, lon1) = (from.0.to_radians(), from.1.to_radians());
+        let (lat2, lon2) = (to.0.to_radians(), to.1.to_radians());
+
+        let a = ((lat2 - lat1) / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+        let distance = 2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin();
+
+        self.scale * distance
+    }
+}
+
+impl Heuristic for Zero {
+    fn estimate(&self, _from: (f64, f64), _to: (f64, f64)) -> f64 {
+        0.0
+    }
+
+    fn uses_coordinates(&self) -> bool {
+        false
+    }
+}
+
+// Available heuristics (can be selected by command line).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum HeuristicKind {
+    Euclidean,
+    Manhattan,
+    Haversine,
+    Zero,
+}
+
+impl HeuristicKind {
+    // Creates heuristic object with given scale factor.
+    pub fn create(&self, scale: f64) -> Box<dyn Heuristic> {
+        match self {
+            HeuristicKind::Euclidean => Box::new(Euclidean { scale }),
+            HeuristicKind::Manhattan => Box::new(Manhattan { scale }),
+            HeuristicKind::Haversine => Box::new(Haversine { scale }),
+            HeuristicKind::Zero => Box::new(Zero),
+        }
+    }
+
+    // Names of node keys coordinates are read from if not specified otherwise.
+    pub fn default_keys(&self) -> (&'static str, &'static str) {
+        match self {
+            HeuristicKind::Haversine => ("lat", "lon"),
+            _ => ("x", "y"),
+        }
+    }
+}
+
+impl FromStr for HeuristicKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "euclidean" => Ok(HeuristicKind::Euclidean),
+            "manhattan" => Ok(HeuristicKind::Manhattan),
+            "haversine" => Ok(HeuristicKind::Haversine),
+            "zero" => Ok(HeuristicKind::Zero),
+            _ => Err(format!("unknown heuristic: {} (expected euclidean, manhattan, haversine or zero)", s)),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// Own objects.
+use crate::BellmanFord::BellmanFord;
+use crate::Dijkstra::Dijkstra;
+use crate::Graph::graph_type::graph_enum::GraphType;
+use crate::Graph::{Edge, EdgeIndex, Graph};
+
+// Standard library.
+use std::str::FromStr;
+
+// Costs of cheapest paths between all pairs of nodes: matrix[u][v] with u, v as node indices (None if v can't be reached from u).
+pub type DistanceMatrix = Vec<Vec<Option<i64>>>;
+
+// Result of an all-pairs computation: Distance matrix or a negative cycle (then there are no cheapest paths).
+pub enum AllPairsResult {
+    Distances(DistanceMatrix),
+    NegativeCycle(Vec<EdgeIndex>),
+}
+
+// Available all-pairs algorithms (can be selected by command line).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AllPairsAlgorithm {
+    FloydWarshall,
+    Johnson,
+    Auto, // chosen by density of the graph
+}
+
+impl FromStr for AllPairsAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "floyd" | "floyd-warshall" => Ok(AllPairsAlgorithm::FloydWarshall),
+            "johnson" => Ok(AllPairsAlgorithm::Johnson),
+            "auto" => Ok(AllPairsAlgorithm::Auto),
+            _ => Err(format!("unknown all-pairs algorithm: {} (expected floyd, johnson or auto)", s)),
+        }
+    }
+}
+
+pub struct AllPairs;
+
+impl AllPairs {
+    // Graphs with more than this share of all possible connections are considered dense.
+    const DENSITY_THRESHOLD: f64 = 0.25;
+
+    // Runs given algorithm (Auto: Floyd-Warshall for dense graphs, Johnson for sparse ones).
+    pub fn run(graph: &Graph, algorithm: AllPairsAlgorithm) -> AllPairsResult {
+        match Self::resolve(graph, algorithm) {
+            AllPairsAlgorithm::Johnson => Self::johnson(graph),
+            _ => Self::floyd_warshall(graph),
+        }
+    }
+
+    // Replaces Auto by the algorithm that fits the density of the graph.
+    pub fn resolve(graph: &Graph, algorithm: AllPairsAlgorithm) -> AllPairsAlgorithm {
+        if algorithm != AllPairsAlgorithm::Auto {
+            return algorithm;
+        }
+
+        let n = graph.node_len() as f64;
+        let density = if n > 0.0 { graph.edge_len() as f64 / (n * n) } else { 0.0 };
+
+        if density > Self::DENSITY_THRESHOLD { AllPairsAlgorithm::FloydWarshall } else { AllPairsAlgorithm::Johnson }
+    }
+
+    // Floyd-Warshall: Allows every node in turn as intermediate node on all paths. Complexity: O(V^3)
+    pub fn floyd_warshall(graph: &Graph) -> AllPairsResult {
+        let n = graph.node_len();
+        let mut dist: DistanceMatrix = graph.get_weight_matrix();
+
+        // Staying at a node costs nothing (unless there's a negative self loop).
+        for (i, row) in dist.iter_mut().enumerate() {
+            row[i] = Some(row[i].map_or(0, |w| w.min(0)));
+        }
+
+        for k in 0..n {
+            // Row k doesn't change while k is the intermediate node (dist[k][k] >= 0 unless there's a negative cycle).
+            let row_k = dist[k].clone();
+
+            for row_i in dist.iter_mut() {
+                let Some(ik) = row_i[k] else {
+                    continue;
+                };
+
+                for (ij, kj) in row_i.iter_mut().zip(row_k.iter()) {
+                    if let Some(kj) = kj {
+                        let cost = ik.saturating_add(*kj);
+                        if ij.is_none_or(|ij| cost < ij) {
+                            *ij = Some(cost);
+                        }
+                    }
+                }
+            }
+        }
+
+        // A node that can reach itself with negative costs lies on a negative cycle. Floyd-Warshall doesn't keep track of
+        // the edges, so the cycle is looked up by Bellman-Ford.
+        if (0..n).any(|i| dist[i][i].is_some_and(|d| d < 0)) {
+            if let Err(cycle) = BellmanFord::potentials(graph) {
+                return AllPairsResult::NegativeCycle(cycle);
+            }
+        }
+
+        AllPairsResult::Distances(dist)
+    }
+
+    // Johnson: Node potentials h (from Bellman-Ford) turn each weight w(u, v) into w(u, v) + h(u) - h(v) >= 0, so that
+    // dijkstra algorithm can be run from every node. Costs are then corrected back. Complexity: O(V * E * log(V))
+    pub fn johnson(graph: &Graph) -> AllPairsResult {
+        let n = graph.node_len();
+
+        let h = match BellmanFord::potentials(graph) {
+            Ok(h) => h,
+            Err(cycle) => return AllPairsResult::NegativeCycle(cycle),
+        };
+
+        // Reweighted copy of the graph. Reweighting differs per direction, so undirected edges are split into two directed ones
+        // (ids of the copy are just numbers, only costs are read from it).
+        let mut edges: Vec<Edge> = Vec::new();
+        for edge in graph.edges().iter() {
+            let (hu, hv) = (h[edge.source().index()], h[edge.dest().index()]);
+
+            edges.push(Edge::new(edges.len().to_string(), edge.weight() + hu - hv, GraphType::Directed, edge.source(), edge.dest(), Vec::new()));
+            if *edge.etype() == GraphType::Undirected {
+                edges.push(Edge::new(edges.len().to_string(), edge.weight() + hv - hu, GraphType::Directed, edge.dest(), edge.source(), Vec::new()));
+            }
+        }
+        let reweighted = Graph::new(String::from("reweighted"), graph.nodes().to_vec(), edges, Vec::new()).expect("copy of a valid graph has unique ids");
+
+        let mut dist: DistanceMatrix = vec![vec![None; n]; n];
+
+        for start in reweighted.node_indices() {
+            let u = start.index();
+
+            let result = Dijkstra::run(&reweighted, start).expect("reweighted graph has no negative weights");
+            for entry in result.iter().filter(|entry| entry.is_reachable()) {
+                let v = entry.owner.index();
+                dist[u][v] = Some(entry.cost() - h[u] + h[v]);
+            }
+        }
+
+        AllPairsResult::Distances(dist)
+    }
+}
@@ -0,0 +1,126 @@
+// Own objects.
+use crate::Dijkstra::{DijkstraHeapEle, INFINITE_COSTS};
+use crate::Graph::{EdgeIndex, Graph, IgraphObject, NodeIndex};
+
+// Result of Bellman-Ford algorithm: Either costs and predecessors for each node (same form as dijkstra algorithm
+// returns them) or a negative cycle reachable from start node, in which case there are no cheapest paths.
+pub enum BellmanFordResult {
+    Paths(Vec<DijkstraHeapEle>),
+    NegativeCycle(Vec<EdgeIndex>), // edges in order of the cycle
+}
+
+pub struct BellmanFord;
+
+impl BellmanFord {
+    // Each traversable connection (u, v, edge, weight): directed edges from source to dest, undirected ones in both directions.
+    fn arcs(graph: &Graph) -> Vec<(usize, usize, EdgeIndex, i64)> {
+        graph
+            .node_indices()
+            .flat_map(|u| graph.neighbors(u).iter().map(move |&(v, e)| (u.index(), v.index(), e, graph.edge(e).weight())))
+            .collect()
+    }
+
+    // Relaxes all connections until nothing changes anymore. Without negative cycles this happens after at most V-1 rounds,
+    // so a node that is still relaxed in round V proves a negative cycle: In this case the cycle is returned.
+    fn relax(arcs: &[(usize, usize, EdgeIndex, i64)], dist: &mut [i64], prev: &mut [Option<(usize, EdgeIndex)>]) -> Option<Vec<EdgeIndex>> {
+        let node_len = dist.len();
+        let mut relaxed: Option<usize> = None;
+
+        for _ in 0..node_len {
+            relaxed = None;
+
+            for &(u, v, edge, weight) in arcs.iter() {
+                if dist[u] == INFINITE_COSTS {
+                    continue;
+                }
+
+                let cost = dist[u].saturating_add(weight);
+                if cost < dist[v] {
+                    dist[v] = cost;
+                    prev[v] = Some((u, edge));
+                    relaxed = Some(v);
+                }
+            }
+
+            if relaxed.is_none() {
+                break;
+            }
+        }
+
+        relaxed.map(|v| Self::extract_cycle(prev, v, node_len))
+    }
+
+    // Solves single source shortest paths with arbitrary (also negative) edge weights. Complexity: O(V * E)
+    pub fn run(graph: &Graph, start: NodeIndex) -> BellmanFordResult {
+        let node_len = graph.node_len();
+        let arcs = Self::arcs(graph);
+
+        let mut dist: Vec<i64> = vec![INFINITE_COSTS; node_len];
+        let mut prev: Vec<Option<(usize, EdgeIndex)>> = vec![None; node_len];
+
+        if start.index() >= node_len {
+            return BellmanFordResult::Paths(Vec::new());
+        }
+        dist[start.index()] = 0;
+
+        if let Some(cycle) = Self::relax(&arcs, &mut dist, &mut prev) {
+            return BellmanFordResult::NegativeCycle(cycle);
+        }
+
+        let result = graph
+            .node_indices()
+            .map(|v| DijkstraHeapEle::new(v, prev[v.index()].map(|(p, e)| (NodeIndex::new(p), e)), dist[v.index()]))
+            .collect();
+
+        BellmanFordResult::Paths(result)
+    }
+
+    // Costs of cheapest paths from a virtual node that is connected to every node with weight 0 (indexed like graph.nodes()).
+    // Used by Johnson's algorithm as node potentials, which turn all weights non-negative. Returns any negative cycle instead.
+    pub fn potentials(graph: &Graph) -> Result<Vec<i64>, Vec<EdgeIndex>> {
+        let node_len = graph.node_len();
+        let arcs = Self::arcs(graph);
+
+        // Virtual node reaches every node directly with costs 0.
+        let mut dist: Vec<i64> = vec![0; node_len];
+        let mut prev: Vec<Option<(usize, EdgeIndex)>> = vec![None; node_len];
+
+        match Self::relax(&arcs, &mut dist, &mut prev) {
+            Some(cycle) => Err(cycle),
+            None => Ok(dist),
+        }
+    }
+
+    // Walks back along predecessors from a node relaxed in the last round. After V steps the walk is certainly inside the
+    // cycle, which is then collected by following predecessors until the same node is reached again.
+    fn extract_cycle(prev: &[Option<(usize, EdgeIndex)>], relaxed: usize, node_len: usize) -> Vec<EdgeIndex> {
+        let mut v = relaxed;
+        for _ in 0..node_len {
+            if let Some((p, _)) = prev[v] {
+                v = p;
+            }
+        }
+
+        let mut cycle: Vec<EdgeIndex> = Vec::new();
+        let first = v;
+        while let Some((p, edge)) = prev[v] {
+            cycle.push(edge);
+            v = p;
+            if v == first || cycle.len() > node_len {
+                break;
+            }
+        }
+
+        // Edges were collected backwards.
+        cycle.reverse();
+        cycle
+    }
+
+    // Prints edge ids of a negative cycle into console.
+    pub fn print_cycle(graph: &Graph, cycle: &[EdgeIndex]) {
+        let ids: Vec<&str> = cycle.iter().map(|&e| graph.edge(e).get_id()).collect();
+        let costs: i64 = cycle.iter().map(|&e| graph.edge(e).weight()).sum();
+
+        println!("Negative cycle found (costs: {}): {}", costs, ids.join(", "));
+    }
+}
@@ -0,0 +1,149 @@
+// Own objects.
+use crate::Dijkstra::{Dijkstra, DijkstraHeapEle, DijkstraPath, INFINITE_COSTS};
+use crate::Graph::{EdgeIndex, Graph, NodeIndex};
+use crate::PriorityQueue::{BinaryHeap, PriorityQueue};
+
+// Result of a bidirectional search: Path to destination and number of nodes settled by both searches together.
+pub struct BidirectionalResult {
+    pub path: DijkstraPath,
+    pub expanded: usize,
+}
+
+// State of one of both searches (forward from start or backward from destination).
+struct Search {
+    dist: Vec<Option<i64>>,
+    prev: Vec<Option<(usize, EdgeIndex)>>, // forward: predecessor towards start, backward: successor towards destination (with edge between both)
+    settled: Vec<bool>,
+    queue: BinaryHeap,
+    last: i64, // key of last settled node (all remaining keys are at least as large)
+}
+
+impl Search {
+    fn new(node_len: usize, origin: usize) -> Self {
+        let mut search = Search {
+            dist: vec![None; node_len],
+            prev: vec![None; node_len],
+            settled: vec![false; node_len],
+            queue: BinaryHeap::new(node_len, 0),
+            last: 0,
+        };
+
+        search.dist[origin] = Some(0);
+        search.queue.push(origin, 0);
+
+        search
+    }
+}
+
+pub struct BidirectionalDijkstra;
+
+impl BidirectionalDijkstra {
+    // Searches cheapest path from start to dest by running dijkstra algorithm forward from start and backward (over
+    // reversed edges) from dest at the same time. Search stops as soon as no path through unsettled nodes can be cheaper
+    // than the best path found so far, which is usually long before all nodes are settled. Fails if the graph has negative weights.
+    pub fn run(graph: &Graph, start: NodeIndex, dest: NodeIndex) -> Result<BidirectionalResult, String> {
+        Dijkstra::check_weights(graph)?;
+
+        let node_len = graph.node_len();
+
+        if start.index() >= node_len || dest.index() >= node_len {
+            return Ok(BidirectionalResult { path: DijkstraPath::Unreachable, expanded: 0 });
+        }
+        let (s, t) = (start.index(), dest.index());
+
+        // Forward search uses edges as dijkstra algorithm does (undirected edges in both directions, directed ones only
+        // from source to dest), backward search uses each of these connections the other way round.
+        let forward_adjacency = graph.adjacency();
+        let backward_adjacency = forward_adjacency.reversed();
+
+        let mut forward = Search::new(node_len, s);
+        let mut backward = Search::new(node_len, t);
+
+        // Costs of best path found so far (INFINITE_COSTS as long as there's none) and the node where both searches met on it.
+        let mut best: i64 = if s == t { 0 } else { INFINITE_COSTS };
+        let mut meeting: Option<usize> = if s == t { Some(s) } else { None };
+        let mut expanded: usize = 0;
+
+        loop {
+            // Continue with the search that is less advanced so both grow evenly.
+            let is_forward = forward.last <= backward.last;
+            let (search, other, adjacency) = if is_forward {
+                (&mut forward, &backward, forward_adjacency)
+            } else {
+                (&mut backward, &forward, &backward_adjacency)
+            };
+
+            // If one search ran out of nodes, every path it could contribute to has already been seen.
+            let Some((u, c)) = search.queue.pop() else {
+                break;
+            };
+            search.last = c;
+
+            // Meeting criterion: Any path not yet seen costs at least the smallest keys of both queues together (paths that
+            // can't be represented never beat best).
+            if Dijkstra::add_costs(c, other.last).is_none_or(|bound| bound >= best) {
+                break;
+            }
+
+            search.settled[u] = true;
+            expanded += 1;
+
+            for &(v, e) in adjacency.neighbors(NodeIndex::new(u)).iter() {
+                let v = v.index();
+                if search.settled[v] {
+                    continue;
+                }
+
+                let Some(cost) = Dijkstra::add_costs(c, graph.edge(e).weight()) else {
+                    continue; // costs can't be represented
+                };
+                if search.dist[v].is_none_or(|dist| cost < dist) {
+                    search.dist[v] = Some(cost);
+                    search.prev[v] = Some((u, e));
+                    search.queue.push(v, cost);
+                }
+
+                // Connection u -> v joins both searches: Check if combined path is better than best one so far.
+                let total = search.dist[v].zip(other.dist[v]).and_then(|(dist, other_dist)| Dijkstra::add_costs(dist, other_dist));
+                if let Some(total) = total.filter(|&total| total < best) {
+                    best = total;
+                    meeting = Some(v);
+                }
+            }
+        }
+
+        let Some(m) = meeting else {
+            return Ok(BidirectionalResult { path: DijkstraPath::Unreachable, expanded });
+        };
+
+        // Collect path: start -> meeting node along forward predecessors, then meeting node -> dest along backward successors.
+        // edges[i] leads from nodes[i] to nodes[i + 1].
+        let mut nodes: Vec<usize> = vec![m];
+        let mut edges: Vec<EdgeIndex> = Vec::new();
+        while let Some((p, e)) = forward.prev[nodes[nodes.len() - 1]] {
+            nodes.push(p);
+            edges.push(e);
+        }
+        nodes.reverse();
+        edges.reverse();
+        let meeting_pos = nodes.len() - 1;
+        while let Some((n, e)) = backward.prev[nodes[nodes.len() - 1]] {
+            nodes.push(n);
+            edges.push(e);
+        }
+
+        // Convert path into a dijkstra result so it can be reconstructed (including edges) the same way.
+        let result: Vec<DijkstraHeapEle> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                let cost = if i <= meeting_pos { forward.dist[v] } else { backward.dist[v].map(|dist| best - dist) };
+                let prev = if i > 0 { Some((NodeIndex::new(nodes[i - 1]), edges[i - 1])) } else { None };
+
+                DijkstraHeapEle::new(NodeIndex::new(v), prev, cost.unwrap_or(INFINITE_COSTS))
+            })
+            .collect();
+
+        Ok(BidirectionalResult { path: Dijkstra::path_to(graph, &result, dest), expanded })
+    }
+}
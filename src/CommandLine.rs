@@ -0,0 +1,376 @@
+/*
+    Command line of the application: A subcommand followed by long options, e.g.
+
+        dijkstra-plot route --input=testgraph.xml --start A --dest K
+
+    Each subcommand describes its options (name, value, default and a short text), so arguments can be checked against
+    them and the help texts are generated from the same description.
+ */
+
+// Standard library.
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Description of a single option of a subcommand.
+pub struct OptionSpec {
+    pub name: &'static str, // without leading "--"
+    pub value: Option<&'static str>, // placeholder of the value in help text (None: option is a flag)
+    pub default: Option<&'static str>,
+    pub required: bool,
+    pub help: &'static str,
+}
+
+impl OptionSpec {
+    const fn value(name: &'static str, value: &'static str, help: &'static str) -> Self {
+        OptionSpec { name, value: Some(value), default: None, required: false, help }
+    }
+
+    const fn flag(name: &'static str, help: &'static str) -> Self {
+        OptionSpec { name, value: None, default: None, required: false, help }
+    }
+
+    const fn or(self, default: &'static str) -> Self {
+        OptionSpec { default: Some(default), ..self }
+    }
+
+    const fn required(self) -> Self {
+        OptionSpec { required: true, ..self }
+    }
+}
+
+// Options that are shared by all subcommands reading a GraphML file.
+const GRAPHML_INPUT: OptionSpec = OptionSpec::value("input", "FILE", "GraphML file to read").required();
+const WEIGHT_KEY: OptionSpec = OptionSpec::value("weight-key", "KEY", "Id or attr.name of the edge key containing weights (default: key named weight)");
+const UNIT_WEIGHT: OptionSpec = OptionSpec::value("unit-weight", "N", "Weight of edges without weight information").or("1");
+const STRICT: OptionSpec = OptionSpec::flag("strict", "Refuse to run if any element of the file was rejected");
+
+// Options of the shortest-path algorithms (route writes their result as plot data, render draws it). The options
+// following output describe the file to write.
+macro_rules! route_options {
+    ($output:expr $(, $file:expr)*) => {
+        &[
+            GRAPHML_INPUT,
+            $output,
+            $($file,)*
+            OptionSpec::value("start", "NODE", "Id of the start node (not needed with --all-pairs)"),
+            OptionSpec::value("dest", "NODE", "Id of the destination node (default: paths to all nodes)"),
+            OptionSpec::value("queue", "QUEUE", "Priority queue: binary, pairing, fibonacci, radix or dial (full dijkstra run)"),
+            OptionSpec::value("astar", "HEURISTIC", "A* search with euclidean, manhattan, haversine or zero heuristic"),
+            OptionSpec::value("x-key", "KEY", "Node key containing x coordinate (or latitude) for A*"),
+            OptionSpec::value("y-key", "KEY", "Node key containing y coordinate (or longitude) for A*"),
+            OptionSpec::value("heuristic-scale", "FACTOR", "Edge weight units per coordinate unit for A*").or("1.0"),
+            OptionSpec::value("all-pairs", "ALGORITHM", "All-pairs shortest paths: floyd, johnson or auto"),
+            OptionSpec::value("matrix", "FILE", "CSV file for the all-pairs distance matrix").or("Distances.csv"),
+            OptionSpec::value("heatmap", "FILE", "Heatmap data file for the all-pairs distance matrix"),
+            OptionSpec::value("k-shortest", "K", "Number of alternative paths to destination (Yen's algorithm)"),
+            OptionSpec::flag("ties", "Keep all equal-cost predecessors (shortest-path DAG)"),
+            OptionSpec::value("enumerate", "N", "List up to N equal-cost shortest paths to destination"),
+            OptionSpec::value("graphml", "FILE", "Also write the graph with distances, predecessors, path and positions as GraphML"),
+            WEIGHT_KEY,
+            UNIT_WEIGHT,
+            STRICT,
+        ]
+    };
+}
+
+// Plot data is written in version 2 unless a script needs the old format.
+const DATA_V1: OptionSpec = OptionSpec::flag("data-v1", "Write plot data in old format version 1 (without header, ids must not contain spaces)");
+
+const ROUTE_OPTIONS: &[OptionSpec] = route_options!(OptionSpec::value("output", "FILE", "Plot data file to write").or("Graph.dat"), DATA_V1);
+
+const LAYOUT_OPTIONS: &[OptionSpec] = &[
+    GRAPHML_INPUT,
+    OptionSpec::value("output", "FILE", "Plot data file to write").or("Graph.dat"),
+    OptionSpec::value("start", "NODE", "Id of the node in the center (default: first node)"),
+    DATA_V1,
+    WEIGHT_KEY,
+    UNIT_WEIGHT,
+    STRICT,
+];
+
+const RENDER_OPTIONS: &[OptionSpec] = route_options!(
+    OptionSpec::value("output", "FILE", "Image to write").or("Plot.svg"),
+    OptionSpec::value("format", "FORMAT", "Image format: svg or png (default: by extension of output)"),
+    OptionSpec::value("size", "WxH", "Image size in inches").or("12x12"),
+    OptionSpec::value("dpi", "N", "Resolution of PNG images in pixels per inch").or("100")
+);
+
+const CONVERT_OPTIONS: &[OptionSpec] = &[
+    GRAPHML_INPUT,
+    OptionSpec::value("output", "FILE", "File to write").required(),
+    OptionSpec::value("to", "FORMAT", "Output format: edges (CSV edge list), matrix (CSV weight matrix) or graphml").or("edges"),
+    WEIGHT_KEY,
+    UNIT_WEIGHT,
+    STRICT,
+];
+
+const VALIDATE_OPTIONS: &[OptionSpec] = &[GRAPHML_INPUT, WEIGHT_KEY, UNIT_WEIGHT];
+
+const STATS_OPTIONS: &[OptionSpec] = &[GRAPHML_INPUT, WEIGHT_KEY, UNIT_WEIGHT, STRICT];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Command {
+    Route,
+    Layout,
+    Render,
+    Convert,
+    Validate,
+    Stats,
+}
+
+impl Command {
+    pub const ALL: [Command; 6] = [Command::Route, Command::Layout, Command::Render, Command::Convert, Command::Validate, Command::Stats];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Route => "route",
+            Command::Layout => "layout",
+            Command::Render => "render",
+            Command::Convert => "convert",
+            Command::Validate => "validate",
+            Command::Stats => "stats",
+        }
+    }
+
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Command::Route => "Compute shortest paths and write them as plot data",
+            Command::Layout => "Position the nodes and write them as plot data (nothing marked)",
+            Command::Render => "Compute shortest paths like route and draw them as SVG or PNG image",
+            Command::Convert => "Write a GraphML file in another format",
+            Command::Validate => "Check a GraphML file and report all problems found",
+            Command::Stats => "Print metrics of a graph",
+        }
+    }
+
+    pub fn options(&self) -> &'static [OptionSpec] {
+        match self {
+            Command::Route => ROUTE_OPTIONS,
+            Command::Layout => LAYOUT_OPTIONS,
+            Command::Render => RENDER_OPTIONS,
+            Command::Convert => CONVERT_OPTIONS,
+            Command::Validate => VALIDATE_OPTIONS,
+            Command::Stats => STATS_OPTIONS,
+        }
+    }
+
+    // Help text of the subcommand: usage line and one line per option.
+    pub fn help(&self) -> String {
+        let mut usage = format!("Usage: {} {}", PROGRAM, self.name());
+        for spec in self.options().iter().filter(|spec| spec.required) {
+            usage.push_str(&format!(" --{} <{}>", spec.name, spec.value.unwrap_or("")));
+        }
+        usage.push_str(" [OPTIONS]");
+
+        let mut lines: Vec<(String, String)> = self
+            .options()
+            .iter()
+            .map(|spec| {
+                let name = match spec.value {
+                    Some(value) => format!("--{} <{}>", spec.name, value),
+                    None => format!("--{}", spec.name),
+                };
+                let help = match (spec.required, spec.default) {
+                    (true, _) => format!("{} (required)", spec.help),
+                    (false, Some(default)) => format!("{} [default: {}]", spec.help, default),
+                    (false, None) => spec.help.to_string(),
+                };
+                (name, help)
+            })
+            .collect();
+        lines.push((String::from("-h, --help"), String::from("Print this help")));
+
+        format!("{} - {}\n\n{}\n\nOptions:\n{}", self.name(), self.summary(), usage, columns(&lines))
+    }
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Command::ALL
+            .into_iter()
+            .find(|command| command.name() == s)
+            .ok_or_else(|| format!("unknown command: {} (see {} --help)", s, PROGRAM))
+    }
+}
+
+// Output formats of the convert command.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConvertFormat {
+    Edges,
+    Matrix,
+    GraphML,
+}
+
+impl FromStr for ConvertFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "edges" => Ok(ConvertFormat::Edges),
+            "matrix" => Ok(ConvertFormat::Matrix),
+            "graphml" => Ok(ConvertFormat::GraphML),
+            _ => Err(format!("unknown format: {} (expected edges, matrix or graphml)", s)),
+        }
+    }
+}
+
+// Image formats of the render command.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImageFormat {
+    Svg,
+    Png,
+}
+
+impl ImageFormat {
+    // Format given by the extension of file_name, SVG if it's unknown.
+    pub fn of_file(file_name: &str) -> Self {
+        if file_name.to_lowercase().ends_with(".png") {
+            ImageFormat::Png
+        } else {
+            ImageFormat::Svg
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "svg" => Ok(ImageFormat::Svg),
+            "png" => Ok(ImageFormat::Png),
+            _ => Err(format!("unknown image format: {} (expected svg or png)", s)),
+        }
+    }
+}
+
+const PROGRAM: &str = "dijkstra-plot";
+
+// Help text of the application itself (without a subcommand).
+pub fn help() -> String {
+    let lines: Vec<(String, String)> = Command::ALL.iter().map(|command| (command.name().to_string(), command.summary().to_string())).collect();
+
+    format!(
+        "{} {}\nShortest paths on GraphML graphs and their preparation for plotting.\n\nUsage: {} <COMMAND> [OPTIONS]\n\nCommands:\n{}\n\nRun '{} <COMMAND> --help' for the options of a command.",
+        PROGRAM,
+        env!("CARGO_PKG_VERSION"),
+        PROGRAM,
+        columns(&lines),
+        PROGRAM
+    )
+}
+
+// Two-column list with aligned second column.
+fn columns(lines: &[(String, String)]) -> String {
+    let width = lines.iter().map(|(left, _)| left.len()).max().unwrap_or(0);
+
+    lines.iter().map(|(left, right)| format!("  {:<width$}  {}", left, right, width = width)).collect::<Vec<_>>().join("\n")
+}
+
+// What the command line asks for.
+pub enum Invocation {
+    Run(Arguments),
+    Help(String), // help text was requested
+    Version,
+}
+
+// Checked options of a subcommand.
+pub struct Arguments {
+    pub command: Command,
+    values: HashMap<&'static str, String>,
+    flags: Vec<&'static str>,
+}
+
+impl Arguments {
+    // Parses arguments (without program name). Options are accepted as "--name=value" and "--name value", each at most once.
+    pub fn parse(args: &[String]) -> Result<Invocation, String> {
+        let Some(first) = args.first() else {
+            return Err(format!("Missing command\n\n{}", help()));
+        };
+
+        match first.as_str() {
+            "-h" | "--help" => return Ok(Invocation::Help(help())),
+            "-V" | "--version" => return Ok(Invocation::Version),
+            // "help route" is the same as "route --help"
+            "help" => {
+                return match args.get(1) {
+                    Some(name) => Ok(Invocation::Help(name.parse::<Command>()?.help())),
+                    None => Ok(Invocation::Help(help())),
+                };
+            }
+            _ => {}
+        }
+
+        let command: Command = first.parse()?;
+        let mut arguments = Arguments { command, values: HashMap::new(), flags: Vec::new() };
+        let mut rest = args[1..].iter();
+
+        while let Some(arg) = rest.next() {
+            if arg == "-h" || arg == "--help" {
+                return Ok(Invocation::Help(command.help()));
+            }
+
+            let Some(option) = arg.strip_prefix("--") else {
+                return Err(format!("Unexpected argument '{}' (see {} {} --help)", arg, PROGRAM, command.name()));
+            };
+            let (name, inline_value) = match option.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (option, None),
+            };
+            let Some(spec) = command.options().iter().find(|spec| spec.name == name) else {
+                return Err(format!("Unknown option --{} for {} (see {} {} --help)", name, command.name(), PROGRAM, command.name()));
+            };
+
+            if arguments.values.contains_key(spec.name) || arguments.flags.contains(&spec.name) {
+                return Err(format!("Option --{} is given more than once", spec.name));
+            }
+
+            match (spec.value, inline_value) {
+                (None, None) => arguments.flags.push(spec.name),
+                (None, Some(_)) => return Err(format!("Option --{} doesn't take a value", spec.name)),
+                (Some(_), Some(value)) => {
+                    arguments.values.insert(spec.name, value.to_string());
+                }
+                (Some(placeholder), None) => {
+                    let Some(value) = rest.next() else {
+                        return Err(format!("Option --{} requires a value <{}>", spec.name, placeholder));
+                    };
+                    arguments.values.insert(spec.name, value.clone());
+                }
+            }
+        }
+
+        if let Some(spec) = command.options().iter().find(|spec| spec.required && !arguments.values.contains_key(spec.name)) {
+            return Err(format!("Missing option --{} <{}> (see {} {} --help)", spec.name, spec.value.unwrap_or(""), PROGRAM, command.name()));
+        }
+
+        Ok(Invocation::Run(arguments))
+    }
+
+    // Value of an option or its default (None if neither is there).
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str).or_else(|| self.spec(name).default)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(&self.spec(name).name)
+    }
+
+    // Value of an option (or its default) converted to T.
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String>
+    where
+        T::Err: Display,
+    {
+        self.get(name)
+            .map(|text| text.parse::<T>().map_err(|err| format!("Invalid value '{}' for --{}: {}", text, name, err)))
+            .transpose()
+    }
+
+    // Options are only asked for by name used in the description above, so an unknown name is a bug.
+    fn spec(&self, name: &str) -> &'static OptionSpec {
+        self.command.options().iter().find(|spec| spec.name == name).unwrap_or_else(|| panic!("{} has no option --{}", self.command.name(), name))
+    }
+}
@@ -0,0 +1,288 @@
+/*
+    Messages about problems found while reading a GraphML file: Each diagnostic has a severity, a code that
+    identifies the kind of problem, the id of the element concerned and (if it could be found) its position
+    in the file including the source line, so it can be shown like a compiler message.
+ */
+
+// Standard library.
+use std::fmt;
+
+// How serious a problem is.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Severity {
+    Error, // document can't be read at all
+    Warning, // element or value was ignored (or replaced by a default)
+    Info, // something unusual that doesn't change the graph
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// Kinds of problems. The numbers (see code()) are stable so they can be looked up or filtered.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DiagnosticCode {
+    Io,
+    InvalidXml,
+    MissingGraph,
+    MissingGraphId,
+    InvalidEdgeDefault,
+    IncompleteKey,
+    UnknownKeyType,
+    DuplicateKeyId,
+    UnsupportedKeyScope,
+    InvalidValue,
+    UnknownDataKey,
+    MissingNodeId,
+    DuplicateNodeId,
+    InvalidDirection,
+    DanglingEndpoint,
+    InvalidWeight,
+    MissingWeightKey,
+    DuplicateEdgeId,
+}
+
+impl DiagnosticCode {
+    pub fn code(&self) -> &'static str {
+        match self {
+            DiagnosticCode::Io => "GML001",
+            DiagnosticCode::InvalidXml => "GML002",
+            DiagnosticCode::MissingGraph => "GML003",
+            DiagnosticCode::MissingGraphId => "GML004",
+            DiagnosticCode::InvalidEdgeDefault => "GML005",
+            DiagnosticCode::IncompleteKey => "GML006",
+            DiagnosticCode::UnknownKeyType => "GML007",
+            DiagnosticCode::DuplicateKeyId => "GML008",
+            DiagnosticCode::UnsupportedKeyScope => "GML009",
+            DiagnosticCode::InvalidValue => "GML010",
+            DiagnosticCode::UnknownDataKey => "GML011",
+            DiagnosticCode::MissingNodeId => "GML012",
+            DiagnosticCode::DuplicateNodeId => "GML013",
+            DiagnosticCode::InvalidDirection => "GML014",
+            DiagnosticCode::DanglingEndpoint => "GML015",
+            DiagnosticCode::InvalidWeight => "GML016",
+            DiagnosticCode::MissingWeightKey => "GML017",
+            DiagnosticCode::DuplicateEdgeId => "GML018",
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            DiagnosticCode::Io | DiagnosticCode::InvalidXml | DiagnosticCode::MissingGraph => Severity::Error,
+            DiagnosticCode::MissingGraphId | DiagnosticCode::UnsupportedKeyScope => Severity::Info,
+            _ => Severity::Warning,
+        }
+    }
+}
+
+// Line and column (both starting at 1) of an element in the file.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    pub message: String,
+    pub element_id: Option<String>, // id of the element concerned (if it has one)
+    pub position: Option<Position>,
+    source_line: Option<String>, // line of the file at position
+}
+
+impl Diagnostic {
+    // Diagnostic that doesn't refer to a place in the file (e.g. file couldn't be read).
+    pub fn new(code: DiagnosticCode, message: String) -> Self {
+        Diagnostic {
+            severity: code.severity(),
+            code,
+            message,
+            element_id: None,
+            position: None,
+            source_line: None,
+        }
+    }
+
+    // Formats diagnostic with file name, position and the source line marked below, e.g.:
+    //   warning[GML015]: Unknown 'target' node Z in edge e3 (edge is ignored)
+    //     --> graph.xml:31:9
+    //      |
+    //   31 |         <edge id="e3" source="A" target="Z"/>
+    //      |         ^
+    pub fn render(&self, file_name: &str) -> String {
+        let mut text = self.to_string();
+
+        let Some(position) = self.position else {
+            return text;
+        };
+        let line_no = position.line.to_string();
+        let gutter = " ".repeat(line_no.len());
+
+        text.push_str(&format!("\n{}--> {}:{}:{}", gutter, file_name, position.line, position.column));
+
+        if let Some(line) = self.source_line.as_deref() {
+            // Tabs are kept so the marker is below the right character.
+            let indent: String = line.chars().take(position.column - 1).map(|c| if c == '\t' { '\t' } else { ' ' }).collect();
+
+            text.push_str(&format!("\n{} |", gutter));
+            text.push_str(&format!("\n{} | {}", line_no, line));
+            text.push_str(&format!("\n{} | {}^", gutter, indent));
+        }
+
+        text
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.code.code(), self.message)
+    }
+}
+
+// Element of the document a diagnostic refers to: n-th element with a tag name among its siblings (nodes and edges are
+// children of the graph element, keys and the graph itself children of the root element) or n-th <data> element within
+// such an element.
+pub(crate) enum Location {
+    Document,
+    Element(&'static str, usize),
+    Data(&'static str, usize, usize),
+}
+
+// Start tag of the document.
+struct Tag {
+    name: String, // local name in lower case
+    offset: usize, // offset of '<'
+    parent: Option<usize>, // index of the enclosing tag (None for the root element)
+}
+
+// Start tags of a document with their byte offsets and nesting, so that elements found by the xml parser (which doesn't
+// keep positions) can be located again in the text.
+struct SourceMap<'s> {
+    source: &'s str,
+    tags: Vec<Tag>,
+}
+
+impl<'s> SourceMap<'s> {
+    fn new(source: &'s str) -> Self {
+        let mut tags: Vec<Tag> = Vec::new();
+        let mut open: Vec<usize> = Vec::new(); // tags that weren't closed yet (innermost last)
+        let mut i = 0;
+
+        while let Some(found) = source[i..].find('<') {
+            let start = i + found;
+            let rest = &source[start..];
+
+            // Comments, CDATA sections and processing instructions are no elements, end tags close the innermost one.
+            let skip_to = |end: &str| rest.find(end).map_or(source.len(), |e| start + e + end.len());
+            i = if rest.starts_with("<!--") {
+                skip_to("-->")
+            } else if rest.starts_with("<![CDATA[") {
+                skip_to("]]>")
+            } else if rest.starts_with("</") {
+                open.pop();
+                start + 1
+            } else if rest.starts_with("<?") || rest.starts_with("<!") {
+                start + 1
+            } else {
+                let name: String = rest[1..].chars().take_while(|c| !c.is_whitespace() && *c != '/' && *c != '>').collect();
+                let local = name.rsplit(':').next().unwrap_or("").to_lowercase();
+                tags.push(Tag { name: local, offset: start, parent: open.last().copied() });
+
+                // Tag ends at the first '>' outside of attribute values, "/>" means there are no children.
+                let mut quote: Option<char> = None;
+                let end = rest.char_indices().find(|&(_, c)| match quote {
+                    Some(q) => {
+                        if c == q {
+                            quote = None;
+                        }
+                        false
+                    }
+                    None if c == '"' || c == '\'' => {
+                        quote = Some(c);
+                        false
+                    }
+                    None => c == '>',
+                });
+                if !end.is_some_and(|(e, _)| rest[..e].ends_with('/')) {
+                    open.push(tags.len() - 1);
+                }
+                start + 1
+            };
+        }
+
+        SourceMap { source, tags }
+    }
+
+    // Index of the index-th tag with given name whose enclosing tag is parent.
+    fn nth_child(&self, parent: usize, name: &str, index: usize) -> Option<usize> {
+        self.tags.iter().enumerate().filter(|(_, tag)| tag.parent == Some(parent) && tag.name == name).nth(index).map(|(i, _)| i)
+    }
+
+    // Index of the tag of an element: Nodes and edges are looked up in the graph element, all others in the root element.
+    fn element(&self, name: &str, index: usize) -> Option<usize> {
+        let root = self.tags.iter().position(|tag| tag.parent.is_none())?;
+        let parent = match name {
+            "node" | "edge" => self.nth_child(root, "graph", 0)?,
+            _ => root,
+        };
+        self.nth_child(parent, name, index)
+    }
+
+    fn offset_of(&self, location: &Location) -> Option<usize> {
+        let tag = match *location {
+            Location::Document => None,
+            Location::Element(name, index) => self.element(name, index),
+            Location::Data(parent, parent_index, index) => {
+                let parent = self.element(parent, parent_index)?;
+                self.nth_child(parent, "data", index)
+            }
+        };
+        tag.map(|tag| self.tags[tag].offset)
+    }
+
+    fn position(&self, offset: usize) -> (Position, &'s str) {
+        let line_start = self.source[..offset].rfind('\n').map_or(0, |p| p + 1);
+        let line_end = self.source[offset..].find('\n').map_or(self.source.len(), |p| offset + p);
+
+        let position = Position {
+            line: self.source[..offset].matches('\n').count() + 1,
+            column: self.source[line_start..offset].chars().count() + 1,
+        };
+
+        (position, self.source[line_start..line_end].trim_end_matches('\r'))
+    }
+}
+
+// Collects diagnostics while a document is parsed and locates them in its source.
+pub(crate) struct Report<'s> {
+    map: SourceMap<'s>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'s> Report<'s> {
+    pub fn new(source: &'s str) -> Self {
+        Report { map: SourceMap::new(source), diagnostics: Vec::new() }
+    }
+
+    pub fn add(&mut self, code: DiagnosticCode, location: Location, element_id: Option<&str>, message: String) {
+        let mut diagnostic = Diagnostic::new(code, message);
+        diagnostic.element_id = element_id.map(str::to_string);
+
+        if let Some(offset) = self.map.offset_of(&location) {
+            let (position, line) = self.map.position(offset);
+            diagnostic.position = Some(position);
+            diagnostic.source_line = Some(line.to_string());
+        }
+
+        self.diagnostics.push(diagnostic);
+    }
+}
@@ -1,170 +1,320 @@
 // Own objects.
-use crate::Graph::Graph;
-use crate::Graph::node::Node;
-//use crate::Graph::edge::Edge;
+use crate::Graph::{EdgeIndex, Graph, NodeIndex};
+use crate::Graph::IgraphObject;
+use crate::PriorityQueue::{BinaryHeap, DialQueue, FibonacciHeap, PairingHeap, PriorityQueue, QueueKind, RadixHeap};
 
 // Standard library.
-use std::borrow::BorrowMut;
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
-use crate::Graph::graph_type::graph_enum::GraphType;
-//use crate::Graph::graph_type::graph_enum::GraphType;//::Undirected;
+
+// Costs of nodes that could not be reached.
+pub const INFINITE_COSTS: i64 = i64::MAX;
+
+// Largest edge weight dial queue is used for (one bucket per weight, so larger weights would allocate gigabytes).
+pub const MAX_DIAL_WEIGHT: i64 = 1 << 20;
 
 // Traits are needed so standard functions can be performed.
 #[derive(PartialEq, Eq, Clone)]
-pub struct DijkstraHeapEle<'a> {
-    pub  owner: &'a Node,
-    pub prev: Option<&'a Node>,
-    c: u32,
+pub struct DijkstraHeapEle {
+    pub  owner: NodeIndex,
+    pub prev: Option<NodeIndex>,
+    pub prev_edge: Option<EdgeIndex>, // edge taken from prev to owner (tells which one if there are parallel edges)
+    c: i64,
+}
+
+impl DijkstraHeapEle {
+    // prev is the predecessor together with the edge leading from it.
+    pub fn new(owner: NodeIndex, prev: Option<(NodeIndex, EdgeIndex)>, c: i64) -> Self {
+        DijkstraHeapEle { owner, prev: prev.map(|(p, _)| p), prev_edge: prev.map(|(_, e)| e), c }
+    }
+
+    // Summarized costs from start node to owner (INFINITE_COSTS if owner could not be reached).
+    pub fn cost(&self) -> i64 {
+        self.c
+    }
+
+    pub fn is_reachable(&self) -> bool {
+        self.c != INFINITE_COSTS
+    }
+}
+
+// Result of a path reconstruction from start node to a destination node.
+#[derive(Clone)]
+pub enum DijkstraPath {
+    // Nodes are ordered from start to destination, edges[i] connects nodes[i] and nodes[i + 1].
+    Reachable {
+        nodes: Vec<NodeIndex>,
+        edges: Vec<EdgeIndex>,
+        cost: i64,
+    },
+    // There's no path from start node to destination node.
+    Unreachable,
+}
+
+impl DijkstraPath {
+    pub fn contains_node(&self, node: NodeIndex) -> bool {
+        match self {
+            DijkstraPath::Reachable { nodes, .. } => nodes.contains(&node),
+            DijkstraPath::Unreachable => false,
+        }
+    }
+
+    pub fn contains_edge(&self, edge: EdgeIndex) -> bool {
+        match self {
+            DijkstraPath::Reachable { edges, .. } => edges.contains(&edge),
+            DijkstraPath::Unreachable => false,
+        }
+    }
 }
 
 // Trait implementation.
-impl<'a> PartialOrd<Self> for DijkstraHeapEle<'a> {
+impl PartialOrd<Self> for DijkstraHeapEle {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.c.cmp(&other.c).reverse())
     }
 }
 
 // Trait implementation.
-impl<'a> Ord for DijkstraHeapEle<'a> {
+impl Ord for DijkstraHeapEle {
     fn cmp(&self, other: &Self) -> Ordering {
         self.c.cmp(&other.c).reverse()
     }
 }
 
-// Struct definition.
-pub struct Dijkstra<'a> {
-    marker: std::marker::PhantomData<&'a ()>, /* Necessary to convince compiler, lifetime parameter is necessary */
+// Outcome of dijkstra core (indexed like graph.nodes()).
+pub struct SearchTree {
+    pub dist: Vec<Option<i64>>, // None for nodes that weren't settled
+    pub prev: Vec<Option<(NodeIndex, EdgeIndex)>>, // predecessor node and the edge leading from it
+    pub order: Vec<NodeIndex>, // nodes in the order they were settled
+}
+
+// Nodes and edges (flags indexed like graph.nodes() and graph.edges()) dijkstra core must not use.
+#[derive(Default)]
+pub struct Restrictions {
+    pub nodes: Vec<bool>,
+    pub edges: Vec<bool>,
 }
 
+impl Restrictions {
+    pub fn has_node(&self, v: NodeIndex) -> bool {
+        self.nodes.get(v.index()).copied().unwrap_or(false)
+    }
+
+    pub fn has_edge(&self, e: EdgeIndex) -> bool {
+        self.edges.get(e.index()).copied().unwrap_or(false)
+    }
+}
+
+// Struct definition.
+pub struct Dijkstra;
+
 // Implementation.
-impl<'a> Dijkstra<'a> {
-    // Prepares graph to be ready for execution of Dijkstra algorithm by creating a min-heap with node_len-entries. Complexity: O(n)
-    fn init(graph: &'a Graph<'a>, start: &'a Node) -> BinaryHeap<DijkstraHeapEle<'a>> {
-        let mut costs: BinaryHeap<DijkstraHeapEle> = BinaryHeap::new();
-
-        // Set distance to each node - except start node - to "infinity" and its predecessor to null (predecessor is determined in main function).
-        for node in graph.nodes.iter() /* O(n) */ {
-            if node != start {
-                costs.push(DijkstraHeapEle {
-                    owner: node,
-                    prev: None,
-                    c: u32::MAX,
-                });
-            } else {
-                costs.push(DijkstraHeapEle {
-                    owner: start,
-                    prev: None,
-                    c: 0, /* costs to start node are zero */
-                });
-            }
+impl Dijkstra {
+    // Runs dijkstra algorithm with default priority queue (indexed binary heap). Fails if the graph has negative weights.
+    pub fn run(graph: &Graph, start: NodeIndex) -> Result<Vec<DijkstraHeapEle>, String> {
+        Self::run_with::<BinaryHeap>(graph, start)
+    }
+
+    // Runs dijkstra algorithm with priority queue selected at runtime (e.g. by command line). Fails for dial queue if the
+    // graph has weights larger than MAX_DIAL_WEIGHT (radix heap is monotone as well, but needs no bucket per weight).
+    pub fn run_using(graph: &Graph, start: NodeIndex, queue: QueueKind) -> Result<Vec<DijkstraHeapEle>, String> {
+        if queue == QueueKind::Dial && Self::max_weight(graph) > MAX_DIAL_WEIGHT {
+            return Err(format!("Edge weights are too large for dial queue (max. {})!", MAX_DIAL_WEIGHT));
         }
 
-        return costs;
+        match queue {
+            QueueKind::Binary => Self::run_with::<BinaryHeap>(graph, start),
+            QueueKind::Pairing => Self::run_with::<PairingHeap>(graph, start),
+            QueueKind::Fibonacci => Self::run_with::<FibonacciHeap>(graph, start),
+            QueueKind::Radix => Self::run_with::<RadixHeap>(graph, start),
+            QueueKind::Dial => Self::run_with::<DialQueue>(graph, start),
+        }
+    }
+
+    // Largest edge weight of the graph (needed by bucket based queues).
+    pub fn max_weight(graph: &Graph) -> i64 {
+        graph.edges().iter().map(|edge| edge.weight()).max().unwrap_or(0).max(0)
     }
 
-    pub fn run(graph: &'a Graph<'a>, start: &'a Node) -> Vec<DijkstraHeapEle<'a>> {
-        // Initialize graph before dijkstra starts:
-        let mut Q = Self::init(graph, start);
-        // Q is a min-heap in which the node with minimum costs to get is on top.
+    pub fn has_negative_weights(graph: &Graph) -> bool {
+        graph.edges().iter().any(|edge| edge.weight() < 0)
+    }
+
+    // Dijkstra algorithm (and every search built on it) requires non-negative weights, graphs with negative weights have to
+    // be solved by BellmanFord. Fails with the first edge that has a negative weight.
+    pub fn check_weights(graph: &Graph) -> Result<(), String> {
+        match graph.edges().iter().find(|edge| edge.weight() < 0) {
+            Some(edge) => Err(format!("Edge {} has negative weight {}, shortest paths need Bellman-Ford!", edge.get_id(), edge.weight())),
+            None => Ok(()),
+        }
+    }
+
+    pub fn run_with<Q: PriorityQueue>(graph: &Graph, start: NodeIndex) -> Result<Vec<DijkstraHeapEle>, String> {
+        Self::check_weights(graph)?;
 
-        // Creates set of nodes to which most favorable path already has been found.
-        let mut N_ : HashSet<&Node> = HashSet::new(); // graph.nodes.iter().collect();
-        N_.insert(start); // start node is trivial
+        let node_len = graph.node_len();
 
         // Result of executed algorithm: Contains summarized costs and predecessor for each node.
-        let mut result: Vec<DijkstraHeapEle> = Vec::new();
-
-        // Copies edges of given graph and sorts them first by id of source node and second by weight.
-        let edges = graph.edges.clone();
-
-        // Closure iterates through given BinaryHeap until specific node was found, updates its values and pushes each entry back into queue.
-        let update_node =
-            |node: &Node, predecessor: Option<&'a Node>, costs: u32, q: &mut BinaryHeap<DijkstraHeapEle<'a>>| /* WC: O(n*log(n)) */ {
-                let mut _q: BinaryHeap<DijkstraHeapEle> = q.clone();
-                let mut _a: BinaryHeap<DijkstraHeapEle> = BinaryHeap::new();
-
-                for _ in 0..=_q.len() /* iterate through q without iterator() */
-                {
-                    if let Some(obj) = _q.pop() /* WC: O(log(n)) */ {
-                        // If taken node is searched not its values are updated and pushed back...
-                        if obj.owner == node {
-                            _a.push(DijkstraHeapEle {
-                                owner: obj.owner, /* owner remains same */
-                                prev: predecessor, /* changed predecessor */
-                                c: costs, /* changed costs to node */
-                            }); // O(1)
-                        } else {
-                            // ... otherwise it is immediately pushed back unchanged.
-                            _a.push(obj); // O(1)
-                        }
-                    }
-                }
+        let mut result: Vec<DijkstraHeapEle> = Vec::with_capacity(node_len);
 
-                // Return q but with changed values for node.
-                return _a;
-            };
+        if start.index() >= node_len {
+            return Ok(result); // start node isn't part of the graph
+        }
 
-        // Closure iterates through given BinaryHeap until specific node was found and returns current known costs to it.
-        let get_cost = |node: &Node, q: &mut BinaryHeap<DijkstraHeapEle>| -> Option<u32> /* WC: O(N) */ {
-            // iter() generates an iterator. Every following operation is performed on each element in the heap which is iterated.
-            let i = q.iter().find(|&y| y.owner == node);
+        let tree = Self::search::<Q>(graph, start, None, Self::max_weight(graph), &Restrictions::default());
 
-            // Might be that searched node is not part of q so result is packed into Option container to prevent errors.
-            if let Some(obj) = i {
-                Some(obj.c)
-            } else {
-                None
+        for &u in tree.order.iter() {
+            result.push(DijkstraHeapEle::new(u, tree.prev[u.index()], tree.dist[u.index()].unwrap_or(INFINITE_COSTS)));
+        }
+
+        // Nodes that couldn't be reached remain with "infinite" costs and without predecessor.
+        for v in graph.node_indices() {
+            if tree.dist[v.index()].is_none() {
+                result.push(DijkstraHeapEle::new(v, None, INFINITE_COSTS));
             }
+        }
+
+        /* Cost complexity of this implementation of dijkstra algorithm: O((V + E) * log(V)) with binary heap, O(E + V * log(V)) with fibonacci heap */
+
+        Ok(result)
+    }
+
+    // Core of dijkstra algorithm on the adjacency of the graph, starting at node s. Stops as soon as target is settled (if
+    // given). Nodes and edges marked in restrictions are never used. Weights must have passed check_weights.
+    pub fn search<Q: PriorityQueue>(graph: &Graph, s: NodeIndex, target: Option<NodeIndex>, max_weight: i64, restrictions: &Restrictions) -> SearchTree {
+        let node_len = graph.node_len();
+
+        let mut tree = SearchTree {
+            dist: vec![None; node_len],
+            prev: vec![None; node_len],
+            order: Vec::with_capacity(node_len),
         };
+        // Marks nodes to which most favorable path already has been found.
+        let mut settled: Vec<bool> = vec![false; node_len];
+
+        if restrictions.has_node(s) {
+            return tree;
+        }
+
+        // Q is a min-priority queue in which the node with minimum costs to get is on top.
+        let mut q = Q::new(node_len, max_weight);
+
+        tree.dist[s.index()] = Some(0); // costs to start node are zero
+        q.push(s.index(), 0);
 
         /* Dijkstra algorithm:
-            Iterate as long as Q isn't empty (which is the max. number of nodes), select in each iteration
-            node with favorable costs and look for cheaper paths to its neighbours. If an such
-            path was found update information otherwise is favorable paths found.
-            After while loop cheapest paths to all nodes from start node are found.
+            Pop node with favorable costs and look for cheaper paths to its neighbours. If such
+            a path was found, decrease costs of neighbour in queue.
 
             Note: Works with directed and undirected edges!
         */
-        while !Q.is_empty() /* O(V) */ {
-            if let Some(u) = Q.pop() /* WC: O(log(V)) */ {
-                N_.insert(u.owner); // O(1), WC: O(V)
-
-                result.push(u.clone()); // O(1), WC: O(V)
-
-                for edge in edges.iter() /* WC: O(E) */ {
-                    // Might be that there's a more cheaper path to dest node when taking edge backwards. If its not forbidden (because directed edge) then check both directions!
-                    let origin_node_ref: Option<&Node> = {
-                        if edge.source() == u.owner /* If edge is not considered inverted, the consideration of the direction condition is superfluous */ {
-                            Some(edge.dest())
-                        } else if *edge.etype() == GraphType::Undirected && edge.dest() == u.owner /* If edge can be inverted, check if its a valid way */ {
-                            Some(edge.source())
-                        } else {
-                            None
-                        }
-                    };
-
-                    if let Some(v) = origin_node_ref {
-                        if !N_.contains(v) /* O(1), WC: O(V) */ {
-                            let dist: u32 = edge.weight() + u.c;
-
-                            if let Some(c) = get_cost(v, Q.borrow_mut()) /* WC: O(V) */ {
-                                // Potential new path costs must be real smaller than current path costs:
-                                if dist < c {
-                                    Q = update_node(v, Option::from(u.owner), dist, Q.borrow_mut()); // WC: O(V*log(V)) // TODO: Must be designed even more favorable!
-                                }
-                            }
-                        }
-                    }
+        while let Some((u, c)) = q.pop() /* V times pop, E times push/decrease-key */ {
+            if settled[u] {
+                continue;
+            }
+            settled[u] = true;
+            let u = NodeIndex::new(u);
+            tree.order.push(u);
+
+            if Some(u) == target {
+                break;
+            }
+
+            for &(v, e) in graph.neighbors(u).iter() {
+                if settled[v.index()] || restrictions.has_node(v) || restrictions.has_edge(e) {
+                    continue;
+                }
+
+                // Costs that don't fit into i64 (or would be taken for INFINITE_COSTS) can't be represented, such paths are skipped.
+                let Some(cost) = Self::add_costs(c, graph.edge(e).weight()) else {
+                    continue;
+                };
+
+                // Potential new path costs must be real smaller than current path costs:
+                if tree.dist[v.index()].is_none_or(|dist| cost < dist) {
+                    tree.dist[v.index()] = Some(cost);
+                    tree.prev[v.index()] = Some((u, e));
+                    q.push(v.index(), cost);
                 }
             }
         }
 
-        /* Cost complexity of this implementation of dijkstra algorithm: O(V*log(V)*(1+E)) (room for improvement) */
-        // TODO:
-        // - Cheaper implementation of update_node (probably best method is to use another common data structure which allows updating in O(n)-time)
-        // - Maybe implement fibonacci heap data structure by myself (will be ver difficult, at least...) to extract min entry in O(1)-time
+        // Costs of nodes that were reached but not settled (search stopped at target) are not final.
+        for (v, dist) in tree.dist.iter_mut().enumerate() {
+            if !settled[v] {
+                *dist = None;
+                tree.prev[v] = None;
+            }
+        }
+
+        tree
+    }
+
+    // Sum of path costs, None if it can't be represented (i.e. it would reach INFINITE_COSTS).
+    pub fn add_costs(a: i64, b: i64) -> Option<i64> {
+        a.checked_add(b).filter(|&sum| sum != INFINITE_COSTS)
+    }
+
+    // Reconstructs path from start node to dest by walking backwards along the prev links of a result returned by run(). Complexity: O(V + P) with P := number of nodes on path
+    pub fn path_to(graph: &Graph, result: &[DijkstraHeapEle], dest: NodeIndex) -> DijkstraPath {
+        // Entries of result by node index, so entry of a node can be found in O(1).
+        let mut entries: Vec<Option<&DijkstraHeapEle>> = vec![None; graph.node_len()];
+        for entry in result.iter().filter(|x| x.owner.index() < graph.node_len()) {
+            entries[entry.owner.index()] = Some(entry);
+        }
+        let find_entry = |node: NodeIndex| entries.get(node.index()).copied().flatten();
+
+        let mut current = match find_entry(dest) {
+            Some(entry) if entry.is_reachable() => entry,
+            _ => return DijkstraPath::Unreachable,
+        };
+        let cost = current.cost();
+
+        let mut nodes: Vec<NodeIndex> = vec![current.owner];
+        let mut edges: Vec<EdgeIndex> = Vec::new();
+
+        while let Some(prev) = current.prev {
+            // A path can never contain more edges than there are nodes; otherwise prev links are corrupted.
+            if edges.len() >= graph.node_len() {
+                return DijkstraPath::Unreachable;
+            }
+
+            let prev_entry = match find_entry(prev) {
+                Some(entry) => entry,
+                None => return DijkstraPath::Unreachable,
+            };
+
+            // Exactly the edge the algorithm took (parallel edges between both nodes may have other weights).
+            match current.prev_edge {
+                Some(edge) if edge.index() < graph.edge_len() => edges.push(edge),
+                _ => return DijkstraPath::Unreachable,
+            }
+            nodes.push(prev);
+
+            current = prev_entry;
+        }
+
+        // Path was collected from destination to start, so turn it around.
+        nodes.reverse();
+        edges.reverse();
 
-        return result;
+        DijkstraPath::Reachable { nodes, edges, cost }
     }
-}
\ No newline at end of file
+
+    // Prints a reconstructed path into console.
+    pub fn print_path(graph: &Graph, start: NodeIndex, dest: NodeIndex, path: &DijkstraPath) {
+        let (start, dest) = (graph.node(start), graph.node(dest));
+
+        match path {
+            DijkstraPath::Reachable { nodes, edges, cost } => {
+                let node_ids: Vec<&str> = nodes.iter().map(|&n| graph.node(n).get_id()).collect();
+                let edge_ids: Vec<&str> = edges.iter().map(|&e| graph.edge(e).get_id()).collect();
+
+                println!("Shortest path from {} to {} (costs: {}): {}", start.get_id(), dest.get_id(), cost, node_ids.join(" -> "));
+                println!("Edges on path: {}", edge_ids.join(", "));
+            }
+            DijkstraPath::Unreachable => {
+                println!("Node {} is unreachable from node {}!", dest.get_id(), start.get_id());
+            }
+        }
+    }
+}
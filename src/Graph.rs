@@ -1,6 +1,9 @@
+pub mod adjacency;
+pub mod attr_value;
 pub mod edge;
 pub mod graph_type;
 pub mod igraph_object;
+pub mod index;
 pub mod key;
 pub mod key_type;
 pub mod node;
@@ -8,35 +11,57 @@ pub mod node;
 pub use crate::Graph::node::Node;
 pub use crate::Graph::edge::Edge;
 pub use crate::Graph::key::Key;
+pub use crate::Graph::attr_value::AttrValue;
 pub use crate::Graph::igraph_object::IgraphObject;
+pub use crate::Graph::index::{EdgeIndex, NodeIndex};
+use crate::Graph::adjacency::Adjacency;
+use crate::Graph::graph_type::graph_enum::GraphType;
 
-//#[derive(Hash)]
-pub struct Graph<'a> {
-    id: String, /* 'id: str<'a>' alternative syntax (if it were not a reference to str). Equivalent to 'id: &'a str'. Useful syntax if no reference is needed but a lifetime ! */
-    pub nodes: Vec<Node>, // TODO: Not sure if it is correct. Nodes must live until as long as edges need reference on them which is 'b lifetime... node need only lifetime for id (str) and 'b is given them according to above syntax rule (if compiler accepts)
-    pub edges: Vec<Edge<'a>>,
+// Standard library.
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+// Nodes and edges are stored in arenas (vectors) and refer to each other by NodeIndex/EdgeIndex only, so a graph owns
+// all its data and can be moved, returned or changed like any other value.
+#[derive(Clone)]
+pub struct Graph {
+    id: String,
+    nodes: Vec<Node>,
+    edges: Vec<Edge>, // endpoints are indices into nodes
     keys: Vec<Key>,
-    pub node_len: usize,
-    pub edge_len: usize
-    //marker: std::marker::PhantomData<&'a &'b()>
+    adjacency: OnceLock<Adjacency>, // connections leaving each node (derived from edges, built on first use after they changed)
 }
 
-impl<'a> Graph<'a> {
-    // Constructor
-    pub fn new(id: String, nodes: Vec<Node>, edges: Vec<Edge<'a>>, keys: Vec<Key>) -> Self {
-        // Necessary to store it here because by initiate Self nodes and edges are moved and there is no access to len() anymore
-        let node_len =  nodes.len();
-        let edge_len = edges.len();
+impl Graph {
+    // Constructor: Fails (like add_node and add_edge) if node ids or edge ids aren't unique or an endpoint isn't a valid
+    // index into nodes.
+    pub fn new(id: String, nodes: Vec<Node>, edges: Vec<Edge>, keys: Vec<Key>) -> Result<Self, String> {
+        let mut node_ids: HashSet<&str> = HashSet::with_capacity(nodes.len());
+        for node in nodes.iter() {
+            if !node_ids.insert(node.get_id()) {
+                return Err(format!("Node {} already exists!", node.get_id()));
+            }
+        }
+
+        let mut edge_ids: HashSet<&str> = HashSet::with_capacity(edges.len());
+        for edge in edges.iter() {
+            if !edge_ids.insert(edge.get_id()) {
+                return Err(format!("Edge {} already exists!", edge.get_id()));
+            }
+            if edge.source().index() >= nodes.len() || edge.dest().index() >= nodes.len() {
+                return Err(format!("Edge {} is connected to a node that doesn't exist!", edge.get_id()));
+            }
+        }
+
+        let adjacency = OnceLock::from(Adjacency::build(nodes.len(), &edges));
 
-        Self {
+        Ok(Self {
             id,
             nodes,
             edges,
             keys,
-            node_len,
-            edge_len,
-            //marker: std::marker::PhantomData::default()
-        }
+            adjacency,
+        })
     }
 
     // return type is immutable because '&self' parameter! To be mutable, it must be '&mut self'. Function returns a boxed matrix
@@ -44,34 +69,53 @@ impl<'a> Graph<'a> {
     pub fn get_adjacency_matrix(&self) -> Vec<Vec<bool>>
     {
         // creates N x N matrix within N := self.nodes.len() of type bool
-        let mut matrix: Vec<Vec<bool>> = vec![vec![false; self.node_len()]; self.node_len()];
+        self.get_weight_matrix()
+            .into_iter()
+            .map(|row| row.into_iter().map(|w| w.is_some()).collect())
+            .collect()
+    }
+
+    // N x N matrix indexed by node index: matrix[u][v] contains the weight of the cheapest edge that can be taken from u to v
+    // (undirected edges in both directions) or None if there's no such edge.
+    pub fn get_weight_matrix(&self) -> Vec<Vec<Option<i64>>>
+    {
+        let mut matrix: Vec<Vec<Option<i64>>> = vec![vec![None; self.node_len()]; self.node_len()];
 
-        // enumerate returns tuple with (position, current object)
-        for (_, e) in self.edges.iter().enumerate() {
-            matrix[e.source().no() as usize][e.dest().no() as usize] = true;
+        let mut set = |u: usize, v: usize, weight: i64| {
+            if u < self.node_len() && v < self.node_len() {
+                matrix[u][v] = Some(matrix[u][v].map_or(weight, |w| w.min(weight)));
+            }
+        };
+
+        for e in self.edges.iter() {
+            let (u, v) = (e.source().index(), e.dest().index());
+
+            set(u, v, e.weight());
+            if *e.etype() == GraphType::Undirected {
+                set(v, u, e.weight());
+            }
         }
 
-        return matrix;
+        matrix
     }
 
-    /* obj: &'a mut (dyn IgraphObject + 'a) means:
-        &'a mut : mutable reference valid as long as 'a lives to ...
-        (dyn IgraphObject + 'a) : an object which implements the IgraphObject trait and exists at least as long 'a does (+ is used to connect constraints)
-     */
-    pub fn add_key(obj: &'a mut (dyn IgraphObject<'a> + 'a), key: Key) {
-        let keys: &'a mut Vec<Key> = obj.get_keys();//.to_vec().clone();
+    // Sets value of an attribute (given as key with its value) of a node, an edge or the graph itself: If obj already has
+    // a key with same id its value is replaced, otherwise the key is added. E.g. Graph::add_key(graph.node_mut(v), key)
+    pub fn add_key(obj: &mut dyn IgraphObject, key: Key) {
+        let keys: &mut Vec<Key> = obj.get_keys();//.to_vec().clone();
 
         // find out if key already exists
         if let Some(index) = keys.iter().position(|x| x.id == key.id) {
             // element exists so update value
-            keys[index].default = key.default;
+            keys[index].value = key.value;
         } else {
             keys.push(key);
         }
     }
 
-    pub fn delete_key(obj: &'a mut (dyn IgraphObject<'a> + 'a), id: &'a str) {
-        let keys: &'a mut Vec<Key> = obj.get_keys();
+    // Removes attribute with given key id from a node, an edge or the graph itself.
+    pub fn delete_key(obj: &mut dyn IgraphObject, id: &str) {
+        let keys: &mut Vec<Key> = obj.get_keys();
 
         if let Some(index) = keys.iter().position(|x| x.id == id) {
             // remove object
@@ -79,7 +123,7 @@ impl<'a> Graph<'a> {
         } // (else: element not found in vector so nothing needs to be done)
     }
 
-    pub fn get_pos_key_by_id(obj: &'a mut (dyn IgraphObject<'a> + 'a), id: &'a str) -> Result<usize, String> {
+    pub fn get_pos_key_by_id(obj: &mut dyn IgraphObject, id: &str) -> Result<usize, String> {
         if let Some(index) = obj.get_keys().iter().position(|x| x.id == id) {
             Ok(index)
         } else {
@@ -87,7 +131,7 @@ impl<'a> Graph<'a> {
         }
     }
 
-    pub fn get_pos_by_attrname(obj: &'a mut (dyn IgraphObject<'a> + 'a), attrname: &'a str) -> Result<usize, String> {
+    pub fn get_pos_by_attrname(obj: &mut dyn IgraphObject, attrname: &str) -> Result<usize, String> {
         if let Some(index) = obj.get_keys().iter().position(|x| x.attrname == attrname) {
             Ok(index)
         } else {
@@ -95,17 +139,148 @@ impl<'a> Graph<'a> {
         }
     }
     pub fn node_len(&self) -> usize {
-        self.node_len
+        self.nodes.len()
     }
     pub fn edge_len(&self) -> usize {
-        self.edge_len
+        self.edges.len()
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    pub fn node(&self, index: NodeIndex) -> &Node {
+        &self.nodes[index.index()]
+    }
+
+    pub fn edge(&self, index: EdgeIndex) -> &Edge {
+        &self.edges[index.index()]
+    }
+
+    pub fn node_indices(&self) -> impl Iterator<Item = NodeIndex> {
+        (0..self.nodes.len()).map(NodeIndex::new)
+    }
+
+    pub fn edge_indices(&self) -> impl Iterator<Item = EdgeIndex> {
+        (0..self.edges.len()).map(EdgeIndex::new)
+    }
+
+    // Index of the node with given id (if there's one).
+    pub fn node_index(&self, id: &str) -> Option<NodeIndex> {
+        self.nodes.iter().position(|node| node.get_id() == id).map(NodeIndex::new)
+    }
+
+    // Connections (neighbour, edge) that can be taken from u: Directed edges from source to dest, undirected ones in both directions.
+    pub fn neighbors(&self, u: NodeIndex) -> &[(NodeIndex, EdgeIndex)] {
+        self.adjacency().neighbors(u)
+    }
+
+    // Adjacency is rebuilt here if edges were changed since it was used last time. Complexity: O(1), rebuild O(V + E)
+    pub fn adjacency(&self) -> &Adjacency {
+        self.adjacency.get_or_init(|| Adjacency::build(self.nodes.len(), &self.edges))
+    }
+
+    // Mutable access to a node, e.g. to change its attributes. Id can't be changed this way, so ids stay unique.
+    pub fn node_mut(&mut self, index: NodeIndex) -> &mut Node {
+        &mut self.nodes[index.index()]
+    }
+
+    // Mutable access to an edge, e.g. to change its attributes or weight. Direction and endpoints can only be changed by the graph.
+    pub fn edge_mut(&mut self, index: EdgeIndex) -> &mut Edge {
+        &mut self.edges[index.index()]
+    }
+
+    pub fn edge_index(&self, id: &str) -> Option<EdgeIndex> {
+        self.edges.iter().position(|edge| edge.get_id() == id).map(EdgeIndex::new)
+    }
+
+    // Adds a node and returns its index (always the next free one). Fails if there's already a node with the same id.
+    // A new node has no connections yet, so adjacency just gets an empty entry for it.
+    pub fn add_node(&mut self, node: Node) -> Result<NodeIndex, String> {
+        if self.node_index(node.get_id()).is_some() {
+            return Err(format!("Node {} already exists!", node.get_id()));
+        }
+
+        self.nodes.push(node);
+        if let Some(adjacency) = self.adjacency.get_mut() {
+            adjacency.push_node();
+        }
+
+        Ok(NodeIndex::new(self.nodes.len() - 1))
+    }
+
+    // Adds an edge and returns its index (always the next free one). Fails if there's already an edge with the same id or
+    // one of its endpoints doesn't exist. Adjacency is rebuilt the next time it's used, so adding many edges one after
+    // another costs O(V + E) only once.
+    pub fn add_edge(&mut self, edge: Edge) -> Result<EdgeIndex, String> {
+        if self.edge_index(edge.get_id()).is_some() {
+            return Err(format!("Edge {} already exists!", edge.get_id()));
+        }
+        if edge.source().index() >= self.nodes.len() || edge.dest().index() >= self.nodes.len() {
+            return Err(format!("Edge {} is connected to a node that doesn't exist!", edge.get_id()));
+        }
+
+        self.edges.push(edge);
+        self.adjacency.take();
+
+        Ok(EdgeIndex::new(self.edges.len() - 1))
+    }
+
+    // Removes a node together with all edges connected to it. Like Vec::remove, all nodes (and edges) behind the removed
+    // ones move one position forward, so indices stay dense: Indices obtained before are invalid afterwards. Complexity: O(V + E)
+    pub fn remove_node(&mut self, index: NodeIndex) -> Option<Node> {
+        if index.index() >= self.nodes.len() {
+            return None;
+        }
+
+        let node = self.nodes.remove(index.index());
+
+        self.edges.retain(|edge| edge.source() != index && edge.dest() != index);
+        let shift = |v: NodeIndex| if v > index { NodeIndex::new(v.index() - 1) } else { v };
+        for edge in self.edges.iter_mut() {
+            let (source, dest) = (shift(edge.source()), shift(edge.dest()));
+            edge.set_endpoints(source, dest);
+        }
+
+        self.adjacency.take();
+
+        Some(node)
+    }
+
+    // Removes an edge. Edges behind it move one position forward (indices obtained before are invalid afterwards).
+    pub fn remove_edge(&mut self, index: EdgeIndex) -> Option<Edge> {
+        if index.index() >= self.edges.len() {
+            return None;
+        }
+
+        let edge = self.edges.remove(index.index());
+        self.adjacency.take();
+
+        Some(edge)
+    }
+
+    pub fn set_weight(&mut self, index: EdgeIndex, weight: i64) {
+        self.edges[index.index()].set_weight(weight);
+    }
+
+    // Changes whether an edge can only be taken from source to dest (Directed) or in both directions (Undirected).
+    pub fn set_direction(&mut self, index: EdgeIndex, etype: GraphType) {
+        self.edges[index.index()].set_etype(etype);
+        self.adjacency.take();
     }
 }
 
-impl<'a> IgraphObject<'a> for Graph<'a> {
-    fn get_id(&'a self) -> &'a str {
+impl IgraphObject for Graph {
+    fn get_id(&self) -> &str {
         &self.id
     }
+    fn keys(&self) -> &[Key] {
+        &self.keys
+    }
     fn get_keys(&mut self) -> &mut Vec<Key> {
         &mut self.keys
     }
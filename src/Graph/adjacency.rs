@@ -0,0 +1,69 @@
+// Own objects.
+use crate::Graph::edge::Edge;
+use crate::Graph::graph_type::graph_enum::GraphType;
+use crate::Graph::index::{EdgeIndex, NodeIndex};
+
+// Compact adjacency (compressed sparse row): All connections leaving node u are stored next to each other in
+// arcs[offsets[u]..offsets[u + 1]] as (neighbour, edge). Directed edges are stored from source to dest, undirected ones
+// in both directions.
+#[derive(Clone, Default)]
+pub struct Adjacency {
+    offsets: Vec<usize>, // node_len + 1 entries
+    arcs: Vec<(NodeIndex, EdgeIndex)>,
+}
+
+impl Adjacency {
+    // Builds adjacency by counting sort on the source nodes, so connections of a node keep the order of the edges. Complexity: O(V + E)
+    pub fn build(node_len: usize, edges: &[Edge]) -> Self {
+        let arcs = edges.iter().enumerate().flat_map(|(e, edge)| {
+            let e = EdgeIndex::new(e);
+            let backward = (*edge.etype() == GraphType::Undirected).then_some((edge.dest(), edge.source(), e));
+
+            std::iter::once((edge.source(), edge.dest(), e)).chain(backward)
+        });
+
+        Self::from_arcs(node_len, arcs)
+    }
+
+    // Same connections with opposite direction (e.g. for searching backwards from a destination node).
+    pub fn reversed(&self) -> Self {
+        let node_len = self.offsets.len().saturating_sub(1);
+        let arcs = (0..node_len).map(NodeIndex::new).flat_map(|u| self.neighbors(u).iter().map(move |&(v, e)| (v, u, e)));
+
+        Self::from_arcs(node_len, arcs)
+    }
+
+    fn from_arcs(node_len: usize, arcs: impl Iterator<Item = (NodeIndex, NodeIndex, EdgeIndex)> + Clone) -> Self {
+        // Number of connections per node first, prefix sums of them are the offsets.
+        let mut offsets: Vec<usize> = vec![0; node_len + 1];
+        for (u, _, _) in arcs.clone() {
+            offsets[u.index() + 1] += 1;
+        }
+        for u in 0..node_len {
+            offsets[u + 1] += offsets[u];
+        }
+
+        // Each connection is written to the next free slot of its node.
+        let mut next: Vec<usize> = offsets[..node_len].to_vec();
+        let mut slots: Vec<(NodeIndex, EdgeIndex)> = vec![(NodeIndex::new(0), EdgeIndex::new(0)); offsets[node_len]];
+        for (u, v, e) in arcs {
+            slots[next[u.index()]] = (v, e);
+            next[u.index()] += 1;
+        }
+
+        Adjacency { offsets, arcs: slots }
+    }
+
+    // Appends a node without connections (its range of arcs is empty). Complexity: O(1)
+    pub fn push_node(&mut self) {
+        if self.offsets.is_empty() {
+            self.offsets.push(0);
+        }
+        self.offsets.push(self.arcs.len());
+    }
+
+    // Connections (neighbour, edge) that can be taken from u.
+    pub fn neighbors(&self, u: NodeIndex) -> &[(NodeIndex, EdgeIndex)] {
+        &self.arcs[self.offsets[u.index()]..self.offsets[u.index() + 1]]
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+use crate::Graph::key_type::key_enum::KeyType;
+
+// Value of a GraphML attribute, converted according to the attr.type of its key.
+#[derive(PartialEq, Clone, Debug)]
+pub enum AttrValue {
+    Bool(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+}
+
+impl AttrValue {
+    // Converts text of a <default> or <data> element into a value of given type. Surrounding whitespace is ignored for all
+    // types but string.
+    pub fn parse(text: &str, attrtype: &KeyType) -> Result<Self, String> {
+        let trimmed = text.trim();
+        let invalid = || format!("'{}' is not a valid {} value", text, attrtype);
+
+        match attrtype {
+            // xs:boolean allows 1 and 0 as well
+            KeyType::Boolean => match trimmed {
+                "true" | "1" => Ok(AttrValue::Bool(true)),
+                "false" | "0" => Ok(AttrValue::Bool(false)),
+                _ => Err(invalid()),
+            },
+            KeyType::Int => trimmed.parse().map(AttrValue::Int).map_err(|_| invalid()),
+            KeyType::Long => trimmed.parse().map(AttrValue::Long).map_err(|_| invalid()),
+            KeyType::Float => trimmed.parse().map(AttrValue::Float).map_err(|_| invalid()),
+            KeyType::Double => trimmed.parse().map(AttrValue::Double).map_err(|_| invalid()),
+            KeyType::String => Ok(AttrValue::String(text.to_string())),
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            AttrValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    // Integral values of both widths.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            AttrValue::Int(i) => Some(i64::from(*i)),
+            AttrValue::Long(l) => Some(*l),
+            _ => None,
+        }
+    }
+
+    // All numeric values (e.g. coordinates may be given as int or double).
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            AttrValue::Int(i) => Some(f64::from(*i)),
+            AttrValue::Long(l) => Some(*l as f64),
+            AttrValue::Float(f) => Some(f64::from(*f)),
+            AttrValue::Double(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AttrValue::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+}
+
+// Writes value the way GraphML expects it (so it can be read again with parse()).
+impl fmt::Display for AttrValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttrValue::Bool(b) => write!(f, "{}", b),
+            AttrValue::Int(i) => write!(f, "{}", i),
+            AttrValue::Long(l) => write!(f, "{}", l),
+            AttrValue::Float(x) => write!(f, "{}", x),
+            AttrValue::Double(x) => write!(f, "{}", x),
+            AttrValue::String(s) => write!(f, "{}", s),
+        }
+    }
+}
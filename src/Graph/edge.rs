@@ -1,20 +1,20 @@
-use crate::Node;
+use crate::Graph::index::NodeIndex;
 use crate::Graph::graph_type::graph_enum::GraphType as GraphType;
 use crate::Graph::key::Key as Key;
 use crate::Graph::igraph_object::IgraphObject;
 
 #[derive(Clone)]
-pub struct Edge<'a> {
+pub struct Edge {
     id: String,
-    weight: u32,
+    weight: i64,
     etype: GraphType,
-    source: &'a Node,
-    dest: &'a Node,
+    source: NodeIndex,
+    dest: NodeIndex,
     keys: Vec<Key>,
 }
 
-impl<'a> Edge<'a> {
-    pub fn new(id: String, weight: u32, etype: GraphType, source: &'a Node, dest: &'a Node, keys: Vec<Key>) -> Self {
+impl Edge {
+    pub fn new(id: String, weight: i64, etype: GraphType, source: NodeIndex, dest: NodeIndex, keys: Vec<Key>) -> Self {
         Self {
             id,
             weight,
@@ -24,26 +24,44 @@ impl<'a> Edge<'a> {
             keys
         }
     }
-    pub fn weight(&self) -> u32 {
+    pub fn weight(&self) -> i64 {
         self.weight
     }
     pub fn etype(&self) -> &GraphType {
         &self.etype
     }
-    pub fn source(&self) -> &'a Node {
+    pub fn source(&self) -> NodeIndex {
         self.source
     }
-    pub fn dest(&self) -> &'a Node {
+    pub fn dest(&self) -> NodeIndex {
         self.dest
     }
+
+    pub fn set_weight(&mut self, weight: i64) {
+        self.weight = weight;
+    }
+
+    // Direction and endpoints determine the adjacency of the graph, so only the graph may change them.
+    pub(crate) fn set_etype(&mut self, etype: GraphType) {
+        self.etype = etype;
+    }
+
+    pub(crate) fn set_endpoints(&mut self, source: NodeIndex, dest: NodeIndex) {
+        self.source = source;
+        self.dest = dest;
+    }
 }
 
 
-impl<'a> IgraphObject<'a> for Edge<'a> {
-    fn get_id(&'a self) -> &'a str {
+impl IgraphObject for Edge {
+    fn get_id(&self) -> &str {
         self.id.as_str()
     }
 
+    fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
     fn get_keys(&mut self) -> &mut Vec<Key> {
         & mut self.keys
     }
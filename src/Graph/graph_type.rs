@@ -19,4 +19,15 @@ impl FromStr for GraphType {
             _ => Err(format!("unknown graph type: {}", s))
         }
     }
-}
\ No newline at end of file
+}
+
+impl GraphType {
+    // Values of the edgedefault attribute of <graph> elements (FromStr reads the directed attribute of <edge> elements).
+    pub fn from_edgedefault(s: &str) -> Result<Self, String> {
+        match s {
+            "directed" => Ok(GraphType::Directed),
+            "undirected" => Ok(GraphType::Undirected),
+            _ => Err(format!("unknown edgedefault: {}", s))
+        }
+    }
+}
@@ -1,7 +1,30 @@
+use crate::Graph::attr_value::AttrValue;
 use crate::Graph::key::Key;
 
-pub trait IgraphObject<'a> {
-    fn get_id(&'a self) -> &'a str;
+pub trait IgraphObject {
+    fn get_id(&self) -> &str;
+    fn keys(&self) -> &[Key];
     fn get_keys(&mut self) -> &mut Vec<Key>;
     fn set_keys(&mut self, keys: Vec<Key>);
-}
\ No newline at end of file
+
+    // Value of the attribute with given name (e.g. "x" for a coordinate), None if there's no such key or it has no value.
+    fn attr(&self, attrname: &str) -> Option<&AttrValue> {
+        self.keys().iter().find(|key| key.attrname == attrname).and_then(|key| key.value.as_ref())
+    }
+
+    fn attr_bool(&self, attrname: &str) -> Option<bool> {
+        self.attr(attrname)?.as_bool()
+    }
+
+    fn attr_i64(&self, attrname: &str) -> Option<i64> {
+        self.attr(attrname)?.as_i64()
+    }
+
+    fn attr_f64(&self, attrname: &str) -> Option<f64> {
+        self.attr(attrname)?.as_f64()
+    }
+
+    fn attr_str(&self, attrname: &str) -> Option<&str> {
+        self.attr(attrname)?.as_str()
+    }
+}
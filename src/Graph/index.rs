@@ -0,0 +1,29 @@
+// Typed handles into the arenas of a graph (positions in Graph::nodes() and Graph::edges()). Unlike plain numbers a
+// NodeIndex can't be mixed up with an EdgeIndex by accident.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct NodeIndex(u32);
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct EdgeIndex(u32);
+
+impl NodeIndex {
+    pub fn new(index: usize) -> Self {
+        NodeIndex(index as u32)
+    }
+
+    // Position in the node arena (e.g. to address vectors that are indexed like the nodes).
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl EdgeIndex {
+    pub fn new(index: usize) -> Self {
+        EdgeIndex(index as u32)
+    }
+
+    // Position in the edge arena.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
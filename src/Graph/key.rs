@@ -1,10 +1,11 @@
+use crate::Graph::attr_value::AttrValue;
 use crate::Graph::key_type::key_enum::KeyType;
 
-#[derive(PartialEq, Eq, Clone, Hash)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct Key {
     pub id: String,
     pub attrname: String,
     pub attrtype: KeyType,
-    pub default: String, // TODO: Better name would be "default" to better correspond to default graphml node
+    pub value: Option<AttrValue>, // <data> value of the element or <default> of the key (None if there's neither)
     //marker: std::marker::PhantomData<&'a &'b()>
-}
\ No newline at end of file
+}
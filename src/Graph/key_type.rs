@@ -1,5 +1,9 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::Graph::key_type::key_enum::KeyType;
+
 pub mod key_enum {
-    #[derive(PartialEq, Eq, Clone, Hash)]
+    #[derive(PartialEq, Eq, Clone, Hash, Debug)]
     pub enum KeyType {
         Boolean,
         Int,
@@ -8,4 +12,35 @@ pub mod key_enum {
         Double,
         String,
     }
-}
\ No newline at end of file
+}
+
+// Values of attr.type in GraphML key elements.
+impl FromStr for KeyType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "boolean" => Ok(KeyType::Boolean),
+            "int" => Ok(KeyType::Int),
+            "long" => Ok(KeyType::Long),
+            "float" => Ok(KeyType::Float),
+            "double" => Ok(KeyType::Double),
+            "string" => Ok(KeyType::String),
+            _ => Err(format!("unknown attribute type: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            KeyType::Boolean => "boolean",
+            KeyType::Int => "int",
+            KeyType::Long => "long",
+            KeyType::Float => "float",
+            KeyType::Double => "double",
+            KeyType::String => "string",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,272 @@
+// Standard library.
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+// Third crates.
+use minidom::Element; // xml parser
+
+// Own objects.
+use crate::Diagnostic::{Diagnostic, DiagnosticCode, Location, Report, Severity};
+use crate::Graph::graph_type::graph_enum::GraphType;
+use crate::Graph::{AttrValue, Edge, Graph, IgraphObject, Key, Node, NodeIndex};
+use crate::KeyCollection::KeyCollection;
+
+pub const NS: &str = "http://graphml.graphdrawing.org/xmlns";
+
+// Settings for reading GraphML files.
+pub struct ReadOptions {
+    pub weight_key: Option<String>, // id or attr.name of the edge key containing weights (None: key with attr.name "weight")
+    pub unit_weight: i64, // weight of edges without any weight information
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        ReadOptions { weight_key: None, unit_weight: 1 }
+    }
+}
+
+const DEFAULT_WEIGHT_KEY: &str = "weight";
+
+// Content of a GraphML file: The graph itself and messages about elements that couldn't be read (they are skipped) and
+// other problems found in the file.
+pub struct GraphDocument {
+    pub graph: Graph,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+// Parsing functions
+// Values of <data key="..."> elements overwrite default values of their keys (keys contains the keys that apply to the
+// element, which is the index-th element named element_kind). Values that don't match the type of their key and data for
+// keys that don't apply are reported and ignored.
+fn apply_data(element: &Element, element_kind: &'static str, index: usize, id: &str, keys: &mut [Key], report: &mut Report) {
+    for (data_index, data) in element.children().filter(|c| c.name() == "data").enumerate() {
+        let location = Location::Data(element_kind, index, data_index);
+
+        let Some(key_id) = data.attr("key") else {
+            report.add(DiagnosticCode::UnknownDataKey, location, Some(id), format!("Data without key in {} {} is ignored", element_kind, id));
+            continue;
+        };
+
+        match keys.iter_mut().find(|key| key.id == key_id) {
+            Some(key) => match AttrValue::parse(&data.text(), &key.attrtype) {
+                Ok(value) => key.value = Some(value),
+                Err(err) => report.add(DiagnosticCode::InvalidValue, location, Some(id), format!("Invalid value of key {} in {} {}: {}", key.id, element_kind, id, err)),
+            },
+            None => report.add(DiagnosticCode::UnknownDataKey, location, Some(id), format!("Data of {} {} refers to key {} which isn't declared for {} elements", element_kind, id, key_id, element_kind)),
+        }
+    }
+}
+
+// Edge weights have to be whole numbers; they may be stored in keys of any numeric type (e.g. "3.0" as double) or string.
+fn weight_from(value: &AttrValue) -> Option<i64> {
+    match value {
+        AttrValue::Float(_) | AttrValue::Double(_) => value
+            .as_f64()
+            .filter(|w| w.fract() == 0.0 && w.abs() <= i64::MAX as f64)
+            .map(|w| w as i64),
+        AttrValue::String(s) => s.trim().parse().ok(),
+        _ => value.as_i64(),
+    }
+}
+
+// Finds the key that contains edge weights: Id or attr.name must match (id is preferred).
+fn find_weight_key<'k>(keys: &'k [Key], options: &ReadOptions) -> Option<&'k Key> {
+    let name = options.weight_key.as_deref().unwrap_or(DEFAULT_WEIGHT_KEY);
+    keys.iter().find(|key| key.id == name).or_else(|| keys.iter().find(|key| key.attrname == name))
+}
+
+fn parseNode(node: &Element, index: usize, report: &mut Report, node_keys: &[Key]) -> Option<Node> {
+    // Read and convert attribute once
+    let id_raw: Option<&str> = node.attr("id");
+
+    if let Some(id) = id_raw {
+        let mut keys = node_keys.to_vec();
+
+        // <data> elements contain e.g. coordinates used by A*.
+        apply_data(node, "node", index, id, &mut keys, report);
+
+        Some(Node::new(id.to_string(), keys))
+    } else {
+        report.add(DiagnosticCode::MissingNodeId, Location::Element("node", index), None, format!("Node without id (number {} in file) is ignored", index + 1));
+        None
+    }
+}
+
+fn parseEdge(edge: &Element, nodes: &HashMap<&str, NodeIndex>, index: usize, report: &mut Report, edge_keys: &[Key], edgedefault: &GraphType, options: &ReadOptions) -> Option<Edge> {
+    // Read and convert attributes once
+    let generated_id = format!("e{}", index); // ids of edges are optional in GraphML (e.g. NetworkX doesn't write them)
+    let id = edge.attr("id").unwrap_or(&generated_id);
+    let kind_raw = edge.attr("directed");
+    let source_raw = edge.attr("source");
+    let target_raw = edge.attr("target");
+    let weight_raw = edge.attr("weight"); // not part of GraphML, but still supported
+
+    // Extract parsed values: Direction of an edge overrides edgedefault of its graph.
+    let kind = match kind_raw {
+        Some(k) => k.parse::<GraphType>().ok(),
+        None => Some(edgedefault.clone()),
+    };
+    let source = source_raw.and_then(|k| nodes.get(k).copied());
+    let target = target_raw.and_then(|k| nodes.get(k).copied());
+
+    let mut has_error = false;
+
+    let mut keys = edge_keys.to_vec();
+    apply_data(edge, "edge", index, id, &mut keys, report);
+
+    // Weight is taken from (in this order): <data> of the weight key, weight attribute, default of the weight key, unit weight.
+    let weight_key = find_weight_key(&keys, options);
+    let has_weight_data = weight_key.is_some_and(|key| edge.children().any(|c| c.name() == "data" && c.attr("key") == Some(key.id.as_str())));
+    let weight = match (weight_key.and_then(|key| key.value.as_ref()), weight_raw) {
+        (Some(value), raw) if has_weight_data || raw.is_none() => weight_from(value).ok_or_else(|| value.to_string()),
+        (_, Some(raw)) => raw.parse::<i64>().map_err(|_| raw.to_string()),
+        (_, None) => Ok(options.unit_weight),
+    };
+
+    let mut reject = |code: DiagnosticCode, message: String| {
+        report.add(code, Location::Element("edge", index), Some(id), format!("{} (edge is ignored)", message));
+        has_error = true;
+    };
+
+    if kind.is_none() {
+        reject(DiagnosticCode::InvalidDirection, format!("Invalid 'directed' attribute '{}' in edge {}", kind_raw.unwrap_or_default(), id));
+    }
+    for (attr, raw, node) in [("source", source_raw, source), ("target", target_raw, target)] {
+        match (raw, node) {
+            (None, _) => reject(DiagnosticCode::DanglingEndpoint, format!("Missing '{}' node in edge {}", attr, id)),
+            (Some(raw), None) => reject(DiagnosticCode::DanglingEndpoint, format!("Unknown '{}' node {} in edge {}", attr, raw, id)),
+            _ => {}
+        }
+    }
+    if let Err(value) = &weight {
+        reject(DiagnosticCode::InvalidWeight, format!("Invalid weight '{}' in edge {} (must be a whole number)", value, id));
+    }
+
+    if has_error {
+        None
+    } else {
+        Some(Edge::new(
+            id.to_string(),
+            weight.unwrap(),
+            kind.unwrap(),
+            source.unwrap(),
+            target.unwrap(),
+            keys,
+        ))
+    }
+}
+
+impl GraphDocument {
+    // Reads and parses a GraphML file.
+    pub fn load(path: &str) -> Result<Self, Diagnostic> {
+        Self::load_with(path, &ReadOptions::default())
+    }
+
+    pub fn load_with(path: &str, options: &ReadOptions) -> Result<Self, Diagnostic> {
+        let xml_str = fs::read_to_string(path)
+            .map_err(|err| Diagnostic::new(DiagnosticCode::Io, format!("Something went wrong reading the file '{}': {}", path, err)))?;
+        Self::parse_with(&xml_str, options)
+    }
+
+    // Parses GraphML content. Invalid nodes and edges are skipped and reported in diagnostics, only a document that isn't
+    // XML or has no graph element fails as a whole.
+    pub fn parse(xml_str: &str) -> Result<Self, Diagnostic> {
+        Self::parse_with(xml_str, &ReadOptions::default())
+    }
+
+    pub fn parse_with(xml_str: &str, options: &ReadOptions) -> Result<Self, Diagnostic> {
+        let root: Element = xml_str.parse().map_err(|err| Diagnostic::new(DiagnosticCode::InvalidXml, format!("Failed to parse XML: {}", err)))?;
+
+        let mut report = Report::new(xml_str); // collects all problems that occur
+
+        // Get the graph element (no root node!).
+        let Some(graph) = root.get_child("graph", NS) else {
+            // at this point it makes no sense to go further.
+            return Err(Diagnostic::new(DiagnosticCode::MissingGraph, "No graph element".to_string()));
+        };
+
+        let graphId = match graph.attr("id") {
+            Some(id) => id.to_string(),
+            None => {
+                report.add(DiagnosticCode::MissingGraphId, Location::Element("graph", 0), None, "Missing graph id".to_string());
+                "unknown".to_string()
+            }
+        };
+
+        // Direction of edges that don't specify one themselves.
+        let edgedefault = match graph.attr("edgedefault").map(GraphType::from_edgedefault) {
+            Some(Ok(edgedefault)) => edgedefault,
+            Some(Err(err)) => {
+                report.add(DiagnosticCode::InvalidEdgeDefault, Location::Element("graph", 0), Some(&graphId), format!("Invalid graph attribute: {} (edges are undirected by default)", err));
+                GraphType::Undirected
+            }
+            None => GraphType::Undirected,
+        };
+
+        let key_elements: Vec<Element> = root.children()
+            .filter(|e| e.name() == "key" && e.ns() == NS)
+            .cloned()
+            .collect();
+        let keys = KeyCollection::collect(&key_elements, &mut report);
+
+        // Attributes of the graph itself.
+        let mut graph_keys = keys.graph.clone();
+        apply_data(graph, "graph", 0, &graphId, &mut graph_keys, &mut report);
+
+        // A weight key that was asked for explicitly should exist.
+        if let Some(name) = options.weight_key.as_deref() {
+            if find_weight_key(&keys.edge, options).is_none() {
+                report.add(DiagnosticCode::MissingWeightKey, Location::Document, None, format!("Weight key {} not found: edges without weight attribute get weight {}", name, options.unit_weight));
+            }
+        }
+
+        let mut nodes: Vec<Node> = Vec::new(); // stores all nodes
+        let mut node_indices: HashMap<&str, NodeIndex> = HashMap::new(); // index of each node by its id
+        for (index, id, node) in graph.children()
+            .filter(|e: &&Element | e.name().eq_ignore_ascii_case("node")) // 1. filters only elements with name equal to "node" and returns bool [(Auto-Dereferencing! e.name() means: (**e).names())]
+            .enumerate() // 2. enumerates all filtered elements and provides (index: usize, node: &Element)
+            .filter_map(|(index , node)| {
+                parseNode(node, index, &mut report, &keys.node) // 4. after parseNode() and and_then() are executed on every piece filter_map removes all Nones and returns the Some values, resulting the (_, _, node: Node) iterator, used in the for-loop
+                    .and_then(|n| node.attr("id").map(|id| (index, id, n))) // 3. and_then takes the result from parseNode (Option<Node>) and turns it into Option<(index, id, Node)>, id is borrowed from the element (parseNode only succeeds if there's one)
+            }) // and_then returns Option<(..)> and filter_map returns an iterator consisting of (..) thats why node in the for-loop is of type Node and not &Element !
+            .collect::<Vec<_>>()
+        {
+            // Node ids must be unique, otherwise edges couldn't tell which node they are connected to.
+            if node_indices.contains_key(id) {
+                report.add(DiagnosticCode::DuplicateNodeId, Location::Element("node", index), Some(id), format!("Node id {} is used more than once, only the first node is used", id));
+                continue;
+            }
+
+            node_indices.insert(id, NodeIndex::new(nodes.len()));
+            nodes.push(node); // 5. node is of type Node here (not Option!) because filter_map unwraps the Some(...)
+        }
+
+        let mut edges: Vec<Edge> = Vec::new(); // stores all edges with indices of their nodes
+        let mut edge_ids: HashSet<String> = HashSet::new();
+        for (index, edge) in graph.children()
+            .filter(|e: &&Element | e.name().eq_ignore_ascii_case("edge"))
+            .enumerate()
+            .filter_map(|(index, edge)| parseEdge(edge, &node_indices, index, &mut report, &keys.edge, &edgedefault, options).map(|e| (index, e)))
+            .collect::<Vec<_>>()
+        {
+            // Edge ids must be unique as well (a generated id like e3 can also be used by another edge of the file).
+            if !edge_ids.insert(edge.get_id().to_string()) {
+                let id = edge.get_id();
+                report.add(DiagnosticCode::DuplicateEdgeId, Location::Element("edge", index), Some(id), format!("Edge id {} is used more than once, only the first edge is used", id));
+                continue;
+            }
+
+            edges.push(edge)
+        }
+
+        // Duplicates and edges with unknown endpoints were skipped above, so the graph can always be built.
+        let graph = Graph::new(graphId, nodes, edges, graph_keys).map_err(|err| Diagnostic::new(DiagnosticCode::InvalidValue, err))?;
+
+        Ok(GraphDocument { graph, diagnostics: report.diagnostics })
+    }
+
+    // Diagnostics of given severity or more serious ones.
+    pub fn diagnostics_at_least(&self, severity: Severity) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.iter().filter(move |d| d.severity <= severity)
+    }
+}
@@ -0,0 +1,325 @@
+/*
+    Writes a graph as GraphML file, e.g. to open results in yEd or Gephi. All keys of the graph, its nodes and edges are
+    declared and written as <data> elements, so GraphDocument reads the file into the same graph again. Results of a
+    computation are added as generated keys before (see annotate):
+
+        dijkstra.distance       node, long: costs of the cheapest path from start node (no value if unreachable)
+        dijkstra.predecessor    node, string: id of the node before on that path (no value for start node)
+        dijkstra.on_path        node and edge, boolean: part of the path to the destination node
+        layout.x, layout.y      node, double: position computed by the layout
+
+    Their names don't interfere with keys of the input file (e.g. x/y coordinates used by A*). A file that already
+    contains generated keys (because it was written by this writer) gets the new results in the same keys.
+ */
+
+// Own objects.
+use crate::Dijkstra::{DijkstraHeapEle, DijkstraPath};
+use crate::Graph::graph_type::graph_enum::GraphType;
+use crate::Graph::key_type::key_enum::KeyType;
+use crate::Graph::{AttrValue, Graph, IgraphObject, Key};
+use crate::GraphDocument::NS;
+use crate::GraphOutput::GraphOutput;
+use crate::GraphPositioning::NodePos;
+
+// Standard library.
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+// Attribute names of the generated keys.
+pub const DISTANCE: &str = "dijkstra.distance";
+pub const PREDECESSOR: &str = "dijkstra.predecessor";
+pub const ON_PATH: &str = "dijkstra.on_path";
+pub const LAYOUT_X: &str = "layout.x";
+pub const LAYOUT_Y: &str = "layout.y";
+
+// Same name the reader looks for by default (see GraphDocument::ReadOptions).
+const WEIGHT: &str = "weight";
+
+// Results that are added to the graph as generated keys (None: nothing is added for it).
+#[derive(Default, Clone, Copy)]
+pub struct Annotations<'a> {
+    pub paths: Option<&'a [DijkstraHeapEle]>, // distances and predecessors (result of dijkstra algorithm or Bellman-Ford)
+    pub path: Option<&'a DijkstraPath>, // path to the destination node
+    pub positions: Option<&'a [NodePos]>,
+}
+
+// Declaration of a key in the file: for is "all" if the key applies to several kinds of elements with the same meaning.
+struct Declaration {
+    id: String,
+    key_id: String, // id of the key in the graph (differs from id if that one was used for another key already)
+    attrname: String,
+    attrtype: KeyType,
+    scope: &'static str,
+}
+
+pub struct GraphMLWriter;
+
+impl GraphMLWriter {
+    // Writes graph with annotations added as generated keys.
+    pub fn write(file_name: &str, graph: &Graph, annotations: &Annotations) -> io::Result<()> {
+        let mut file = GraphOutput::create_file(file_name)?;
+        file.write_all(Self::render(&Self::annotate(graph, annotations)).as_bytes())
+    }
+
+    // Copy of graph whose nodes (and edges) carry the results as generated keys. Every node gets each generated key, the
+    // ones without result (e.g. distance of an unreachable node) without value.
+    pub fn annotate(graph: &Graph, annotations: &Annotations) -> Graph {
+        let mut annotated = graph.clone();
+
+        if let Some(paths) = annotations.paths {
+            let mut by_node: Vec<Option<&DijkstraHeapEle>> = vec![None; graph.node_len()];
+            for ele in paths.iter().filter(|ele| ele.owner.index() < graph.node_len()) {
+                by_node[ele.owner.index()] = Some(ele);
+            }
+
+            let distance = Self::generated_key(graph, "node", DISTANCE, KeyType::Long);
+            let predecessor = Self::generated_key(graph, "node", PREDECESSOR, KeyType::String);
+            for v in graph.node_indices() {
+                let ele = by_node[v.index()];
+                let cost = ele.filter(|ele| ele.is_reachable()).map(|ele| AttrValue::Long(ele.cost()));
+                let prev = ele.and_then(|ele| ele.prev).map(|prev| AttrValue::String(graph.node(prev).get_id().to_string()));
+
+                Graph::add_key(annotated.node_mut(v), Key { value: cost, ..distance.clone() });
+                Graph::add_key(annotated.node_mut(v), Key { value: prev, ..predecessor.clone() });
+            }
+        }
+
+        if let Some(path) = annotations.path {
+            let node_key = Self::generated_key(graph, "node", ON_PATH, KeyType::Boolean);
+            let edge_key = Self::generated_key(graph, "edge", ON_PATH, KeyType::Boolean);
+            for v in graph.node_indices() {
+                Graph::add_key(annotated.node_mut(v), Key { value: Some(AttrValue::Bool(path.contains_node(v))), ..node_key.clone() });
+            }
+            for e in graph.edge_indices() {
+                Graph::add_key(annotated.edge_mut(e), Key { value: Some(AttrValue::Bool(path.contains_edge(e))), ..edge_key.clone() });
+            }
+        }
+
+        if let Some(positions) = annotations.positions {
+            let x = Self::generated_key(graph, "node", LAYOUT_X, KeyType::Double);
+            let y = Self::generated_key(graph, "node", LAYOUT_Y, KeyType::Double);
+
+            let mut by_node: Vec<Option<(f32, f32)>> = vec![None; graph.node_len()];
+            for p in positions.iter().filter(|p| p.node.index() < graph.node_len()) {
+                by_node[p.node.index()] = Some(p.pos);
+            }
+
+            for v in graph.node_indices() {
+                let pos = by_node[v.index()];
+                Graph::add_key(annotated.node_mut(v), Key { value: pos.map(|(x, _)| AttrValue::Double(f64::from(x))), ..x.clone() });
+                Graph::add_key(annotated.node_mut(v), Key { value: pos.map(|(_, y)| AttrValue::Double(f64::from(y))), ..y.clone() });
+            }
+        }
+
+        annotated
+    }
+
+    // Creates GraphML document of graph as it is (use annotate() first to add results).
+    pub fn render(graph: &Graph) -> String {
+        let mut declarations = Self::declarations(graph);
+
+        // The reader takes weights from the edge key with id (or else attr.name) weight, so there has to be one (declared
+        // before keys for all elements, where the reader puts it as well).
+        let generated = Self::weight_key(&declarations).is_none();
+        let weight_key = match Self::weight_key(&declarations) {
+            Some(index) => index,
+            None => {
+                let index = declarations.iter().position(|d| d.scope == "all").unwrap_or(declarations.len());
+                declarations.insert(index, Declaration {
+                    id: Self::unique_id(graph, WEIGHT),
+                    key_id: WEIGHT.to_string(),
+                    attrname: WEIGHT.to_string(),
+                    attrtype: KeyType::Long,
+                    scope: "edge",
+                });
+                index
+            }
+        };
+        let weight_declaration = &declarations[weight_key];
+
+        // Direction that most edges have is default (undirected for a graph without edges).
+        let directed = graph.edges().iter().filter(|e| *e.etype() == GraphType::Directed).count();
+        let edgedefault = if graph.edge_len() > 0 && directed * 2 >= graph.edge_len() { GraphType::Directed } else { GraphType::Undirected };
+
+        let mut xml = String::new();
+        // (Writing into a String can't fail.)
+        let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(xml, r#"<graphml xmlns="{}">"#, NS);
+        for d in declarations.iter() {
+            let _ = writeln!(xml, r#"  <key id="{}" for="{}" attr.name="{}" attr.type="{}"/>"#, Self::escape(&d.id), d.scope, Self::escape(&d.attrname), d.attrtype);
+        }
+
+        let _ = writeln!(
+            xml,
+            r#"  <graph id="{}" edgedefault="{}">"#,
+            Self::escape(graph.get_id()),
+            if edgedefault == GraphType::Directed { "directed" } else { "undirected" }
+        );
+        Self::write_data(&mut xml, "    ", &Self::data(&declarations, "graph", graph.keys(), None));
+
+        for node in graph.nodes() {
+            let data = Self::data(&declarations, "node", node.keys(), None);
+            if data.is_empty() {
+                let _ = writeln!(xml, r#"    <node id="{}"/>"#, Self::escape(node.get_id()));
+                continue;
+            }
+            let _ = writeln!(xml, r#"    <node id="{}">"#, Self::escape(node.get_id()));
+            Self::write_data(&mut xml, "      ", &data);
+            let _ = writeln!(xml, "    </node>");
+        }
+
+        for edge in graph.edges() {
+            // An edge without value of the weight key (weight attribute in the file) or whose weight doesn't fit into the
+            // type of the key (e.g. int) gets its weight as attribute instead.
+            let has_value = generated || edge.keys().iter().any(|key| Self::declared_as(weight_declaration, key) && key.value.is_some());
+            let weight = has_value.then(|| AttrValue::parse(&edge.weight().to_string(), &weight_declaration.attrtype).ok()).flatten();
+            let _ = write!(
+                xml,
+                r#"    <edge id="{}" source="{}" target="{}""#,
+                Self::escape(edge.get_id()),
+                Self::escape(graph.node(edge.source()).get_id()),
+                Self::escape(graph.node(edge.dest()).get_id())
+            );
+            if *edge.etype() != edgedefault {
+                let _ = write!(xml, r#" directed="{}""#, *edge.etype() == GraphType::Directed);
+            }
+            if weight.is_none() {
+                let _ = write!(xml, r#" weight="{}""#, edge.weight());
+            }
+            let _ = writeln!(xml, ">");
+            Self::write_data(&mut xml, "      ", &Self::data(&declarations, "edge", edge.keys(), Some((weight_declaration.id.as_str(), weight))));
+            let _ = writeln!(xml, "    </edge>");
+        }
+
+        let _ = writeln!(xml, "  </graph>");
+        let _ = writeln!(xml, "</graphml>");
+        xml
+    }
+
+    // Keys of the graph, its nodes and edges (in this order, each in order of first appearance). A key found in several
+    // of them with same id, name and type was declared for all elements; an id that means different keys is made unique.
+    fn declarations(graph: &Graph) -> Vec<Declaration> {
+        let scopes: [(&'static str, Vec<&Key>); 3] = [
+            ("graph", graph.keys().iter().collect()),
+            ("node", graph.nodes().iter().flat_map(|node| node.keys()).collect()),
+            ("edge", graph.edges().iter().flat_map(|edge| edge.keys()).collect()),
+        ];
+
+        let mut specific: Vec<Declaration> = Vec::new();
+        let mut all: Vec<Declaration> = Vec::new();
+        let mut used: HashSet<String> = HashSet::new();
+
+        for (scope, keys) in scopes.iter() {
+            for key in keys.iter() {
+                if specific.iter().any(|d| d.scope == *scope && Self::declared_as(d, key)) || all.iter().any(|d| Self::declared_as(d, key)) {
+                    continue;
+                }
+
+                // The reader gives keys for all elements to the graph and to every node and edge.
+                let same = |(_, keys): &(&'static str, Vec<&Key>)| keys.iter().any(|k| k.id == key.id && k.attrname == key.attrname && k.attrtype == key.attrtype);
+                let for_all = *scope == "graph" && scopes[1..].iter().any(|(_, keys)| !keys.is_empty()) && scopes[1..].iter().all(|scope| scope.1.is_empty() || same(scope));
+                let conflicting = |(_, keys): &(&'static str, Vec<&Key>)| keys.iter().any(|k| k.id == key.id && (k.attrname != key.attrname || k.attrtype != key.attrtype));
+
+                let mut id = key.id.clone();
+                let mut n = 1;
+                while used.contains(&id) || (id != key.id && scopes.iter().any(|(_, keys)| keys.iter().any(|k| k.id == id))) {
+                    id = format!("{}_{}", key.id, n);
+                    n += 1;
+                }
+                used.insert(id.clone());
+
+                let declaration = Declaration { id, key_id: key.id.clone(), attrname: key.attrname.clone(), attrtype: key.attrtype.clone(), scope };
+                if for_all && !scopes.iter().any(conflicting) {
+                    all.push(Declaration { scope: "all", ..declaration });
+                } else {
+                    specific.push(declaration);
+                }
+            }
+        }
+
+        specific.extend(all);
+        specific
+    }
+
+    // Index of the declaration the reader takes edge weights from (same rule as GraphDocument).
+    fn weight_key(declarations: &[Declaration]) -> Option<usize> {
+        let applies = |d: &Declaration| d.scope == "edge" || d.scope == "all";
+        declarations
+            .iter()
+            .position(|d| applies(d) && d.id == WEIGHT)
+            .or_else(|| declarations.iter().position(|d| applies(d) && d.attrname == WEIGHT))
+    }
+
+    fn declared_as(declaration: &Declaration, key: &Key) -> bool {
+        declaration.key_id == key.id && declaration.attrname == key.attrname && declaration.attrtype == key.attrtype
+    }
+
+    // Key with given name and type for results: An existing one (from an earlier run) is reused, otherwise it gets an id
+    // made of scope and name that isn't used by any other key (e.g. node.dijkstra.on_path and edge.dijkstra.on_path).
+    fn generated_key(graph: &Graph, scope: &str, attrname: &str, attrtype: KeyType) -> Key {
+        let mut keys: Box<dyn Iterator<Item = &Key>> = match scope {
+            "node" => Box::new(graph.nodes().iter().flat_map(|node| node.keys())),
+            _ => Box::new(graph.edges().iter().flat_map(|edge| edge.keys())),
+        };
+        let id = match keys.find(|key| key.attrname == attrname && key.attrtype == attrtype) {
+            Some(key) => key.id.clone(),
+            None => Self::unique_id(graph, &format!("{}.{}", scope, attrname)),
+        };
+
+        Key { id, attrname: attrname.to_string(), attrtype, value: None }
+    }
+
+    // name itself or name with a number appended, so that no key of graph, its nodes or edges has that id.
+    fn unique_id(graph: &Graph, name: &str) -> String {
+        let ids: HashSet<&str> = graph
+            .keys()
+            .iter()
+            .chain(graph.nodes().iter().flat_map(|node| node.keys()))
+            .chain(graph.edges().iter().flat_map(|edge| edge.keys()))
+            .map(|key| key.id.as_str())
+            .collect();
+
+        let mut id = name.to_string();
+        let mut n = 1;
+        while ids.contains(id.as_str()) {
+            id = format!("{}_{}", name, n);
+            n += 1;
+        }
+        id
+    }
+
+    // Values of keys that apply to scope in order of their declarations (the order the reader gives the keys, so a file
+    // that is read and written again stays the same). Keys without value are only declared. weight replaces the value of
+    // the weight key.
+    fn data<'d>(declarations: &'d [Declaration], scope: &str, keys: &[Key], weight: Option<(&str, Option<AttrValue>)>) -> Vec<(&'d str, AttrValue)> {
+        declarations
+            .iter()
+            .filter(|d| d.scope == scope || d.scope == "all")
+            .filter_map(|d| {
+                let value = match &weight {
+                    Some((id, weight)) if *id == d.id => weight.clone(),
+                    _ => keys.iter().find(|key| Self::declared_as(d, key)).and_then(|key| key.value.clone()),
+                };
+                value.map(|value| (d.id.as_str(), value))
+            })
+            .collect()
+    }
+
+    fn write_data(xml: &mut String, indent: &str, data: &[(&str, AttrValue)]) {
+        for (id, value) in data {
+            let _ = writeln!(xml, r#"{}<data key="{}">{}</data>"#, indent, Self::escape(id), Self::escape(&value.to_string()));
+        }
+    }
+
+    // Ids and string values may contain characters that have a meaning in XML (or are normalized by XML parsers).
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\r', "&#13;")
+            .replace('\n', "&#10;")
+            .replace('\t', "&#9;")
+    }
+}
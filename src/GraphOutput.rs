@@ -6,181 +6,388 @@
  */
 
 // Own objects.
-use crate::Dijkstra::DijkstraHeapEle;
-use crate::Graph::{Graph, IgraphObject};
+use crate::Dijkstra::{DijkstraHeapEle, DijkstraPath, INFINITE_COSTS};
+use crate::Graph::{EdgeIndex, Graph, IgraphObject};
+use crate::Graph::graph_type::graph_enum::GraphType;
 use crate::GraphPositioning::NodePos;
+use crate::AllPairs::DistanceMatrix;
+use crate::ShortestPathDag::ShortestPathDag;
 
 // Standard library.
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::Write;
-use std::process::exit;
+use std::io::{self, Write};
 
 // Represents graphical form of a node.
-struct NodePlot {
-    no: u32,
-    x: f32,
-    y: f32,
-    id: String,
-    marked: bool,
+pub struct NodePlot {
+    pub no: u32,
+    pub x: f32,
+    pub y: f32,
+    pub id: String,
+    pub cost: Option<i64>, // costs from start node (if known from highlighted result and node is reachable)
+    pub marked: bool,
 }
 
 impl NodePlot {
     // Constructor (associative function)
-    fn new(no: u32, x: f32, y: f32, id: String, marked: bool) -> Self {
+    fn new(no: u32, x: f32, y: f32, id: String, cost: Option<i64>, marked: bool) -> Self {
         NodePlot {
             no,
             x,
             y,
             id,
+            cost,
             marked,
         }
     }
 }
 
 // Represents graphical form of an edge.
-struct EdgePlot {
-    from: u32,
-    to: u32,
-    weight: u32,
-    marked: bool,
+pub struct EdgePlot {
+    pub id: String,
+    pub from: u32, // number of source node (NodePlot.no)
+    pub to: u32,
+    pub weight: i64,
+    pub directed: bool,
+    pub marked: bool,
+    pub bend: f32, // 0 if it's the only edge between its nodes, otherwise offset (in edge spacings, to the left of from -> to) that separates it from the others
 }
 
 impl EdgePlot {
     // Constructor (associative function)
-    fn new(from: u32, to: u32, weight: u32, marked: bool) -> Self {
+    fn new(id: String, from: u32, to: u32, weight: i64, directed: bool, marked: bool) -> Self {
         EdgePlot {
+            id,
             from,
             to,
             weight,
+            directed,
             marked,
+            bend: 0.0,
         }
     }
 }
 
-pub struct GraphOutput<'a> {
-    marker: std::marker::PhantomData<&'a ()>, /* marker is necessary here to be allowed to use lifetime parameters! */
+// Edge of a ranked alternative path (k shortest paths).
+pub struct PathPlot {
+    pub rank: usize, // starting at 1
+    pub from: u32,
+    pub to: u32,
+    pub cost: i64, // costs of the whole path
+    pub bend: f32, // bend of the edge (see EdgePlot), so the path follows its curve
+}
+
+// Graphical form of a graph: Everything needed to draw it, either by the python script (see write2File) or as image.
+pub struct Plot {
+    pub nodes: Vec<NodePlot>, // indexed by node number
+    pub edges: Vec<EdgePlot>,
+    pub paths: Vec<PathPlot>,
+    pub negative_cycle: Option<Vec<String>>, // edge ids (if a negative cycle is marked)
+    pub start: Option<u32>, // number of start node (NodePlot.no)
+    pub dest: Option<u32>,
+}
+
+// Determines which nodes and edges are marked in the output file.
+pub enum Highlight<'a> {
+    None,
+    Tree(&'a [DijkstraHeapEle]), // whole shortest-path tree (result of dijkstra algorithm)
+    Path(&'a DijkstraPath), // only the path to a destination node
+    NegativeCycle(&'a [EdgeIndex]), // edges of a negative cycle (their ids are also written into a comment line)
+    Dag(&'a ShortestPathDag), // all equal-cost shortest paths
 }
 
-impl<'a> GraphOutput<'a> {
+pub struct GraphOutput;
+
+impl GraphOutput {
     // Writes a graph and result of dijkstra algorithm and information about the positioning of the graph into a text file:
-    pub fn write2File(file_name: String, graph: &'a Graph, position_information: &'a Vec<NodePos>, dijkstra_information: Option<&Vec<DijkstraHeapEle>>) {
-        let node_len = graph.node_len as usize;
-        let edge_len = graph.edge_len as usize;
+    // Nodes and edges are marked according to highlight (e.g. whole shortest-path tree or only the path to a destination node).
+    // Ranked alternative paths are written into a third section (after another empty line): one "rank x1 y1 x2 y2 costs" line per edge.
+    pub fn write2File(file_name: String, graph: &Graph, position_information: &[NodePos], highlight: &Highlight, ranked_paths: Option<&Vec<DijkstraPath>>) -> io::Result<()> {
+        // Create file (if that's not possible the error is passed to the caller):
+        let mut file = Self::create_file(&file_name)?;
+        let plot = Self::plot(graph, position_information, highlight, ranked_paths);
+
+        // Comment lines (starting with #) are skipped by python script.
+        if let Some(ids) = plot.negative_cycle.as_ref() {
+            writeln!(file, "# negative cycle: {}", ids.join(" "))?;
+        }
+
+        // Write nodes first:
+        for node in plot.nodes.iter() {
+            writeln!(file, "{} {} {} {} {}", node.no, node.x, node.y, node.id, if node.marked { "1" } else { "0" })?;
+        }
+
+        // Empty line between nodes and edges (very important for python script!)
+        writeln!(file)?;
+
+        // Then it is turn of edges:
+        for edge in plot.edges.iter() {
+            // Since EdgePlot has no fields for coordinates a simple conversion is here made through by inserting coordinates of start and end nodes
+            // References:
+            let src_node_ref = &plot.nodes[edge.from as usize];
+            let dst_node_ref = &plot.nodes[edge.to as usize];
 
-        // Create file:
-        let mut rfile = File::create(file_name.clone()); // has to be mutable?
-        let mut file: File;// = rfile.ok().unwrap();
+            // Coordinates of referenced nodes:
+            let (src_x, src_y) = (src_node_ref.x, src_node_ref.y); // packing
+            let (dst_x, dst_y) = (dst_node_ref.x, dst_node_ref.y); // packing
 
-        // Check if file could be created. If not exit application with error code.
-        if let Err(_) = rfile {
-            println!("Unable to create output file {}", file_name.clone());
-            exit(1);
+            // Write information into file:
+            writeln!(file, "{} {} {} {} {} {}", src_x, src_y, dst_x, dst_y, edge.weight, if edge.marked { "1" } else { "0" })?;
         }
-        else {
-            file = rfile.ok().unwrap();
+
+        // Finally alternative paths with their rank (so python script can draw them in different colors):
+        if ranked_paths.is_some() {
+            writeln!(file)?;
+
+            for path in plot.paths.iter() {
+                let (src_node_ref, dst_node_ref) = (&plot.nodes[path.from as usize], &plot.nodes[path.to as usize]);
+                writeln!(file, "{} {} {} {} {} {}", path.rank, src_node_ref.x, src_node_ref.y, dst_node_ref.x, dst_node_ref.y, path.cost)?;
+            }
         }
 
+        Ok(())
+    }
+
+    // Creates graphical form of a graph: Nodes at their positions, nodes and edges marked according to highlight and edges of
+    // ranked alternative paths (if any).
+    pub fn plot(graph: &Graph, position_information: &[NodePos], highlight: &Highlight, ranked_paths: Option<&Vec<DijkstraPath>>) -> Plot {
         // Sort nodes ascending by node number.
         let mut positions_sorted = position_information.to_vec(); // .to_vec() creates a deep copy of the vector while .clone() would return reference to vector!
         positions_sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
         let mut nodes_plot: Vec<NodePlot> = Vec::new();
         let mut edges_plot: Vec<EdgePlot> = Vec::new();
+        let (costs, start, dest) = Self::route(graph, highlight);
+
+        // Nodes and edges of the shortest-path tree: The result contains unreachable nodes as well, so only reachable ones are
+        // marked (indexed by node and by edge once, so marking doesn't have to search the result for every node or edge).
+        let mut in_tree: Vec<bool> = vec![false; graph.node_len()];
+        let mut tree_edges: Vec<bool> = vec![false; graph.edge_len()];
+        if let Highlight::Tree(paths) = highlight {
+            for path in paths.iter().filter(|path| path.is_reachable() && path.owner.index() < graph.node_len()) {
+                in_tree[path.owner.index()] = true;
+            }
+            for e in paths.iter().filter_map(|path| path.prev_edge).filter(|e| e.index() < graph.edge_len()) {
+                tree_edges[e.index()] = true;
+            }
+        }
 
         // Create objects for nodes first:
-        for i in 0..node_len {
-            let ref_node = &graph.nodes[i];
-            let ref_pos = &positions_sorted[i]; // positions must be sorted by node number !
+        for ref_node in graph.node_indices() {
+            let ref_pos = &positions_sorted[ref_node.index()]; // positions must be sorted by node index !
 
             let (x, y) = ref_pos.pos;
 
             // Find out if current node is part of dijkstra path:
-            let marked: bool = {
-                if let Some(paths) = dijkstra_information {
-                    if let Some(_) = paths.iter().find(|&x| x.owner == ref_node) {
-                        true
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                }
+            let marked: bool = match highlight {
+                Highlight::NegativeCycle(cycle) => cycle.iter().any(|&e| graph.edge(e).source() == ref_node || graph.edge(e).dest() == ref_node),
+                Highlight::Path(path) => path.contains_node(ref_node),
+                Highlight::Dag(dag) => dag.contains_node(ref_node),
+                Highlight::Tree(_) => in_tree[ref_node.index()],
+                Highlight::None => false,
             };
 
             // Creates new object with coordinates and co. and pushes it into vector.
             nodes_plot.push(NodePlot::new(
-                ref_node.no(),
+                ref_node.index() as u32,
                 x,
                 y,
-                ref_node.get_id().to_string(),
+                graph.node(ref_node).get_id().to_string(),
+                costs[ref_node.index()],
                 marked,
             ));
         }
 
         // Second for edges:
-        for edge in graph.edges.iter() {
+        for e in graph.edge_indices() {
             // Current edges data:
-            let from = edge.source().no();
-            let to = edge.dest().no();
+            let edge = graph.edge(e);
+            let from = edge.source().index() as u32;
+            let to = edge.dest().index() as u32;
             let weight = edge.weight();
 
-            // Find out if current edge is part of a shortest path. If so, mark it!
-            // Therefore check first if edge is connected with two nodes that are part of shortest path vector (so it must be excluded that two nodes have more than one connecting edge!)
-            // Code works but there is a shorter and readable (and more elegant) code below it:
-/*            let marked = {
-                if let Some(paths) = dijkstra_information {
-                    if let Some(_) = paths.iter().find(|&x: &DijkstraHeapEle| {
-                        if let Some(prev) = x.prev /* It is not mandatory that each node must have predecessor (prev)! */ {
-                            // Check for each DijkstraHeapEle if it is connected to the nodes the current edge is connected to also. Because it is excluded that there is more than one connection between to nodes it MUST be the current edge! Return true then otherwise false
-                            return (x.owner == edge.source() && prev == edge.dest()) || (x.owner == edge.dest() && prev == edge.source()) // {return true} else { return false }
-                        } else { return false; } }) { /* return value if elements in paths that match closure were find */ true }
-                    else { /* return value if no elements in paths that match closure are not found */ false }
-                } else { /* return value if no dijkstra path was given as parameter */ false }
+            // Find out if current edge is part of a shortest path. If so, mark it! Results name the edges they took, so only
+            // that one of several edges between the same nodes is marked (and directed edges only if taken in their direction).
+            let marked = match highlight {
+                Highlight::NegativeCycle(cycle) => cycle.contains(&e),
+                Highlight::Path(path) => path.contains_edge(e),
+                Highlight::Dag(dag) => dag.contains_edge(e),
+                Highlight::Tree(_) => tree_edges[e.index()],
+                Highlight::None => false,
             };
-*/
-            // TODO: Test if this code works as well! -> Seems so!
-            let marked = dijkstra_information.map_or(false, |paths| {
-                paths.iter().any(|path| {
-                    if let Some(prev) = path.prev {
-                        // Remember: Edge could also be reversed! So check in both directions as well!
-                        match (path.owner == edge.source(), prev == edge.dest(), path.owner == edge.dest(), prev == edge.source()) {
-                            (true, true, _, _) | (_, _, true, true) => true, // Check for each DijkstraHeapEle if it is connected to the nodes the current edge is connected to also. Because it is excluded that there is more than one connection between to nodes it MUST be the current edge!
-                            _ => false,
-                        }
-                    } else { false }
-                })
-            });
 
             // Creates new object with required information and push it into vector.
             edges_plot.push(EdgePlot::new(
+                edge.get_id().to_string(),
                 from,
                 to,
                 weight,
+                *edge.etype() == GraphType::Directed,
                 marked,
             ));
         }
 
-        // Write nodes first:
-        for node in nodes_plot.iter() {
-            writeln!(file, "{} {} {} {} {}", node.no, node.x, node.y, node.id, if node.marked { "1" } else { "0" }).unwrap();
+        Self::separate_parallel_edges(&mut edges_plot);
+
+        // Edges of alternative paths with their rank (all paths are drawn in different colors).
+        let mut paths_plot: Vec<PathPlot> = Vec::new();
+        for (rank, path) in ranked_paths.into_iter().flatten().enumerate() {
+            if let DijkstraPath::Reachable { edges, cost, .. } = path {
+                for &e in edges.iter() {
+                    let EdgePlot { from, to, bend, .. } = edges_plot[e.index()]; // (edges are plotted in order of the graph)
+                    paths_plot.push(PathPlot { rank: rank + 1, from, to, cost: *cost, bend });
+                }
+            }
         }
 
-        // Empty line between nodes and edges (very important for python script!)
-        writeln!(file, "").unwrap();
+        let negative_cycle = match highlight {
+            Highlight::NegativeCycle(cycle) => Some(cycle.iter().map(|&e| graph.edge(e).get_id().to_string()).collect()),
+            _ => None,
+        };
 
-        // Then it is turn of edges:
-        for edge in edges_plot.iter() {
-            // Since EdgePlot has no fields for coordinates a simple conversion is here made through by inserting coordinates of start and end nodes
-            // References:
-            let src_node_ref = &nodes_plot[edge.from as usize];
-            let dst_node_ref = &nodes_plot[edge.to as usize];
+        Plot {
+            nodes: nodes_plot,
+            edges: edges_plot,
+            paths: paths_plot,
+            negative_cycle,
+            start,
+            dest,
+        }
+    }
 
-            // Coordinates of referenced nodes:
-            let (src_x, src_y) = (src_node_ref.x, src_node_ref.y); // packing
-            let (dst_x, dst_y) = (dst_node_ref.x, dst_node_ref.y); // packing
+    // Costs of all nodes (indexed by node index), start and destination node as far as the highlighted result tells them.
+    // Callers that know more (e.g. the destination that couldn't be reached) may set them in the plot afterwards.
+    fn route(graph: &Graph, highlight: &Highlight) -> (Vec<Option<i64>>, Option<u32>, Option<u32>) {
+        let mut costs: Vec<Option<i64>> = vec![None; graph.node_len()];
+        let (mut start, mut dest) = (None, None);
 
-            // Write information into file:
-            writeln!(file, "{} {} {} {} {} {}", src_x, src_y, dst_x, dst_y, edge.weight, if edge.marked { "1" } else { "0" }).unwrap();
+        match highlight {
+            Highlight::Tree(paths) => {
+                for path in paths.iter().filter(|path| path.is_reachable()) {
+                    costs[path.owner.index()] = Some(path.cost());
+                    // Only the start node is reachable without predecessor.
+                    if path.prev.is_none() {
+                        start = Some(path.owner.index() as u32);
+                    }
+                }
+            }
+            Highlight::Path(DijkstraPath::Reachable { nodes, edges, .. }) => {
+                let mut cost = 0;
+                costs[nodes[0].index()] = Some(0);
+                for (&node, &e) in nodes[1..].iter().zip(edges.iter()) {
+                    cost += graph.edge(e).weight();
+                    costs[node.index()] = Some(cost);
+                }
+                start = nodes.first().map(|node| node.index() as u32);
+                dest = nodes.last().map(|node| node.index() as u32);
+            }
+            Highlight::Dag(dag) => {
+                for entry in dag.entries.iter().filter(|entry| entry.c != INFINITE_COSTS) {
+                    costs[entry.owner.index()] = Some(entry.c);
+                    if entry.preds.is_empty() && entry.c == 0 {
+                        start = Some(entry.owner.index() as u32);
+                    }
+                }
+            }
+            Highlight::Path(DijkstraPath::Unreachable) | Highlight::NegativeCycle(_) | Highlight::None => {}
+        }
+
+        (costs, start, dest)
+    }
+
+    // Parallel and antiparallel edges get different bends, so they can be drawn as separate curves instead of overlapping:
+    // Edges between the same nodes are spread symmetrically around the straight line (an odd one in the middle stays
+    // straight). Offsets are counted in one direction per node pair, so an edge in opposite direction gets its bend negated.
+    fn separate_parallel_edges(edges: &mut [EdgePlot]) {
+        let mut pairs: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+        for (i, edge) in edges.iter().enumerate() {
+            // Self loops have no line to bend.
+            if edge.from != edge.to {
+                pairs.entry((edge.from.min(edge.to), edge.from.max(edge.to))).or_default().push(i);
+            }
+        }
+
+        for parallel in pairs.values() {
+            let middle = (parallel.len() - 1) as f32 / 2.0;
+            for (k, &i) in parallel.iter().enumerate() {
+                let offset = k as f32 - middle;
+                edges[i].bend = if edges[i].from < edges[i].to || offset == 0.0 { offset } else { -offset }; // (no -0 in data files)
+            }
+        }
+    }
+
+    // Creates output file. The error message contains the file name, so caller knows which file couldn't be created.
+    pub(crate) fn create_file(file_name: &str) -> io::Result<File> {
+        File::create(file_name).map_err(|err| io::Error::new(err.kind(), format!("Unable to create output file {}: {}", file_name, err)))
+    }
+
+    // Node ids indexed by node index (rows/columns of a distance matrix).
+    fn labels(graph: &Graph) -> Vec<String> {
+        graph.nodes().iter().map(|node| node.get_id().to_string()).collect()
+    }
+
+    // Field of a CSV line (RFC 4180): Ids containing separators, quotes or line breaks are quoted, quotes inside are doubled.
+    fn csv_field(text: &str) -> String {
+        if text.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text.to_string()
         }
     }
-}
\ No newline at end of file
+
+    // Writes distance matrix as CSV: First row and first column contain node ids, unreachable pairs are written as "inf".
+    pub fn write_matrix_csv(file_name: &str, graph: &Graph, matrix: &DistanceMatrix) -> io::Result<()> {
+        let mut file = Self::create_file(file_name)?;
+        let labels: Vec<String> = Self::labels(graph).iter().map(|label| Self::csv_field(label)).collect();
+
+        writeln!(file, ",{}", labels.join(","))?;
+
+        for (label, row) in labels.iter().zip(matrix.iter()) {
+            let cells: Vec<String> = row.iter().map(|d| d.map_or(String::from("inf"), |d| d.to_string())).collect();
+            writeln!(file, "{},{}", label, cells.join(","))?;
+        }
+
+        Ok(())
+    }
+
+    // Writes all edges as CSV: One "id,source,target,weight,directed" line per edge (source and target as node ids).
+    pub fn write_edge_list_csv(file_name: &str, graph: &Graph) -> io::Result<()> {
+        let mut file = Self::create_file(file_name)?;
+
+        writeln!(file, "id,source,target,weight,directed")?;
+
+        for edge in graph.edges() {
+            let (source, target) = (graph.node(edge.source()).get_id(), graph.node(edge.dest()).get_id());
+            writeln!(
+                file,
+                "{},{},{},{},{}",
+                Self::csv_field(edge.get_id()),
+                Self::csv_field(source),
+                Self::csv_field(target),
+                edge.weight(),
+                *edge.etype() == GraphType::Directed
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Writes distance matrix as heatmap data: One "row column costs" line per pair and an empty line after each row
+    // (gnuplot's pm3d/image style can plot that directly, numpy.loadtxt reads it as well). Unreachable pairs are "nan".
+    pub fn write_heatmap(file_name: &str, graph: &Graph, matrix: &DistanceMatrix) -> io::Result<()> {
+        let mut file = Self::create_file(file_name)?;
+        let labels = Self::labels(graph);
+
+        writeln!(file, "# row column costs")?;
+        writeln!(file, "# nodes: {}", labels.join(" "))?;
+
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, d) in row.iter().enumerate() {
+                writeln!(file, "{} {} {}", i, j, d.map_or(String::from("nan"), |d| d.to_string()))?;
+            }
+            writeln!(file)?;
+        }
+
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use std::cmp::Ordering;
 use rand::Rng;
 
 // Own objects.
-use crate::Graph::{Graph, Node};
+use crate::Graph::{Graph, NodeIndex};
 
 // Standard library
 use std::ops::{Mul, Sub};
@@ -25,15 +25,15 @@ trait TupleSub<RHS> {
 // Represents A node in the algorithm.
 #[derive(Clone)]
 pub struct NodePos {
-    pub no: u32,
+    pub node: NodeIndex,
     pub pos: (f32, f32),
     pub vel: (f32, f32),
 }
 
 impl NodePos {
-    fn new(no: u32, x: f32, y: f32, dx: f32, dy: f32) -> Self {
+    fn new(node: NodeIndex, x: f32, y: f32, dx: f32, dy: f32) -> Self {
         NodePos {
-            no,
+            node,
             pos: (x, y),
             vel: (dx, dy),
         }
@@ -58,7 +58,7 @@ where
 // Implementation of PartialEq-trait (necessary for sorting)
 impl PartialEq<Self> for NodePos {
     fn eq(&self, other: &Self) -> bool {
-        self.no.eq(&other.no)
+        self.node.eq(&other.node)
     }
 }
 
@@ -79,15 +79,13 @@ where
 // Implementation of PartialOrd-trait (necessary for sorting)
 impl PartialOrd for NodePos {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.no.partial_cmp(&other.no)
+        self.node.partial_cmp(&other.node)
     }
 }
 
-pub struct GraphOptimization<'a> {
-    marker: std::marker::PhantomData<&'a ()>,
-}
+pub struct GraphOptimization;
 
-impl<'a> GraphOptimization<'a> {
+impl GraphOptimization {
     // Constants:
     const DT: f32 = 0.1; // time step dt
     const ITERATIONS: usize = 500; // maximum number of ITERATIONS
@@ -97,7 +95,7 @@ impl<'a> GraphOptimization<'a> {
     const A: f32 = 0.1; // attraction (0.2 - Did also work well!)
 
     // Preparatory steps for positioning algorithm: Creates a vector in which each node of given graph is associated to a NodePos element with random initial coordinates.
-    fn init(graph: &'a Graph<'a>, start: &'a Node) -> Vec<NodePos> /* WC: O(n * (1 + log(n) ) */ {
+    fn init(graph: &Graph, start: NodeIndex) -> Vec<NodePos> /* WC: O(n * (1 + log(n) ) */ {
         let mut positions: Vec<NodePos> = Vec::new();
 
         // Closure is used to return initial coordinates for each node so the algorithm doesn't get stuck.
@@ -111,19 +109,19 @@ impl<'a> GraphOptimization<'a> {
             (x, y)
         };
 
-        for (_, e) in graph.nodes.iter().enumerate() /* O(n) */ {
+        for e in graph.node_indices() /* O(n) */ {
             if e == start {
                 // Start node shall be in center of graphical representation.
-                positions.push(NodePos::new(start.no(), 0.0, 0.0, 0.0, 0.0));
+                positions.push(NodePos::new(start, 0.0, 0.0, 0.0, 0.0));
             } else {
                 // Other nodes are initialized around start node with different (not random) coordinates.
                 let (x, y) = init_logic();
 
-                positions.push(NodePos::new(e.no(), x, y, 0.0, 0.0));
+                positions.push(NodePos::new(e, x, y, 0.0, 0.0));
             }
         }
 
-        // Sort vector by NodePos.no to enable index-based access (so positions[x] -> node_x).
+        // Sort vector by NodePos.node to enable index-based access (so positions[x] -> node_x).
         positions.sort_by(|a, b| a.partial_cmp(b).unwrap()); // WC: O(n * log(n) )
 
         return positions;
@@ -131,9 +129,9 @@ impl<'a> GraphOptimization<'a> {
 
     // Execute positioning algorithm: Fundamental principle is that each node has both repulsion and attraction forces to all other nodes.
     // Algorithm tries to place each node in such way that acting forces become minimal or a maximum of iterations is performed.
-    pub fn run(graph: &'a Graph<'a>, start: &'a Node) -> Vec<NodePos> {
+    pub fn run(graph: &Graph, start: NodeIndex) -> Vec<NodePos> {
         let mut positions: Vec<NodePos> = Self::init(graph, start);
-        let node_len = graph.node_len;
+        let node_len = graph.node_len();
 
         // Returns amount of vector (tuple of size 2).
         let amount = |x: (f32, f32)| -> f32 {
@@ -165,16 +163,14 @@ impl<'a> GraphOptimization<'a> {
             norm.multiply_scalar(scalar)
         };
 
-        // Returns weight of an edge which connects src-node and dst-node.
-        let get_weight = |src: u32, dst: u32| -> u32 /* O(n) */ {
-            let opt_edge = graph
-                .edges
-                .iter()
-                .find(|&x| (x.source().no() == src && x.dest().no() == dst) || (x.dest().no() == src && x.source().no() == src));
+        // Returns weight of an edge which connects src-node and dst-node (in either direction, a directed edge pulls both nodes).
+        let get_weight = |src: usize, dst: usize| -> u32 /* O(deg(src) + deg(dst)) */ {
+            let find_edge = |from: usize, to: usize| graph.neighbors(NodeIndex::new(from)).iter().find(|&&(v, _)| v.index() == to).map(|&(_, e)| graph.edge(e));
+            let opt_edge = find_edge(src, dst).or_else(|| find_edge(dst, src));
 
             // Determines whether a connecting edge was found:
             match opt_edge {
-                Some(edge) => edge.weight(),
+                Some(edge) => edge.weight().unsigned_abs() as u32, /* negative weights (e.g. rebates) still connect nodes */
                 None => 1, /* Important that NOT 0 is returned! Otherwise nodes without connecting edge would have no or only little distance to each other! Since dijkstra algorithm forces weights greater than zero and integer, 1 is smallest value allowed */
             }
         };
@@ -198,7 +194,7 @@ impl<'a> GraphOptimization<'a> {
                     let u = &positions[j].pos;
 
                     // Try to find connecting edge between v and the j-th node.
-                    let cur_weight = (get_weight(i as u32, j as u32) as f32);
+                    let cur_weight = get_weight(i, j) as f32;
 
                     // Calculate distance between both nodes.
                     let distance = u.subtract_sub(v);
@@ -0,0 +1,126 @@
+// Own objects.
+use crate::Graph::graph_type::graph_enum::GraphType;
+use crate::Graph::Graph;
+
+// Standard library.
+use std::collections::HashSet;
+use std::fmt;
+
+// Metrics of a graph (e.g. to check a file before running the algorithms on it).
+#[derive(Clone, PartialEq, Debug)]
+pub struct GraphStats {
+    pub nodes: usize,
+    pub edges: usize,
+    pub directed_edges: usize,
+    pub undirected_edges: usize,
+    pub self_loops: usize,
+    pub parallel_edges: usize, // edges connecting the same two nodes as an earlier edge (in any direction)
+    pub isolated_nodes: usize,
+    pub components: usize, // weakly connected components (direction of edges is ignored)
+    pub min_degree: usize, // degree: number of edge ends at a node (a self loop counts twice)
+    pub max_degree: usize,
+    pub mean_degree: f64,
+    pub density: f64, // share of ordered node pairs (u, v) with an edge that can be taken from u to v
+    pub min_weight: Option<i64>, // None if graph has no edges
+    pub max_weight: Option<i64>,
+    pub negative_weights: usize,
+}
+
+impl GraphStats {
+    // Complexity: O(V + E)
+    pub fn of(graph: &Graph) -> Self {
+        let n = graph.node_len();
+        let mut degree = vec![0usize; n];
+        let mut pairs: HashSet<(usize, usize)> = HashSet::new();
+        let mut stats = GraphStats {
+            nodes: n,
+            edges: graph.edge_len(),
+            directed_edges: 0,
+            undirected_edges: 0,
+            self_loops: 0,
+            parallel_edges: 0,
+            isolated_nodes: 0,
+            components: 0,
+            min_degree: 0,
+            max_degree: 0,
+            mean_degree: 0.0,
+            density: 0.0,
+            min_weight: None,
+            max_weight: None,
+            negative_weights: 0,
+        };
+
+        // Union-find over node indices to count components.
+        let mut parent: Vec<usize> = (0..n).collect();
+        fn find(parent: &mut [usize], mut u: usize) -> usize {
+            while parent[u] != u {
+                parent[u] = parent[parent[u]]; // path halving
+                u = parent[u];
+            }
+            u
+        }
+
+        for edge in graph.edges() {
+            let (u, v) = (edge.source().index(), edge.dest().index());
+
+            match edge.etype() {
+                GraphType::Directed => stats.directed_edges += 1,
+                GraphType::Undirected => stats.undirected_edges += 1,
+            }
+            if u == v {
+                stats.self_loops += 1;
+            }
+            if !pairs.insert((u.min(v), u.max(v))) {
+                stats.parallel_edges += 1;
+            }
+            if edge.weight() < 0 {
+                stats.negative_weights += 1;
+            }
+            stats.min_weight = Some(stats.min_weight.map_or(edge.weight(), |w| w.min(edge.weight())));
+            stats.max_weight = Some(stats.max_weight.map_or(edge.weight(), |w| w.max(edge.weight())));
+
+            degree[u] += 1;
+            degree[v] += 1;
+
+            let (root_u, root_v) = (find(&mut parent, u), find(&mut parent, v));
+            parent[root_u] = root_v;
+        }
+
+        stats.isolated_nodes = degree.iter().filter(|&&d| d == 0).count();
+        stats.components = (0..n).filter(|&u| find(&mut parent, u) == u).count();
+        stats.min_degree = degree.iter().copied().min().unwrap_or(0);
+        stats.max_degree = degree.iter().copied().max().unwrap_or(0);
+
+        if n > 0 {
+            stats.mean_degree = degree.iter().sum::<usize>() as f64 / n as f64;
+        }
+
+        // Neighbors are taken from adjacency, so undirected edges count in both directions and parallel edges only once.
+        if n > 1 {
+            let connected: usize = graph
+                .node_indices()
+                .map(|u| graph.neighbors(u).iter().filter(|&&(v, _)| v != u).map(|&(v, _)| v).collect::<HashSet<_>>().len())
+                .sum();
+            stats.density = connected as f64 / (n * (n - 1)) as f64;
+        }
+
+        stats
+    }
+}
+
+// One "name: value" line per metric.
+impl fmt::Display for GraphStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let weight = |w: Option<i64>| w.map_or(String::from("-"), |w| w.to_string());
+
+        writeln!(f, "nodes:            {}", self.nodes)?;
+        writeln!(f, "edges:            {} ({} directed, {} undirected)", self.edges, self.directed_edges, self.undirected_edges)?;
+        writeln!(f, "self loops:       {}", self.self_loops)?;
+        writeln!(f, "parallel edges:   {}", self.parallel_edges)?;
+        writeln!(f, "isolated nodes:   {}", self.isolated_nodes)?;
+        writeln!(f, "components:       {}", self.components)?;
+        writeln!(f, "degree:           min {}, max {}, mean {:.2}", self.min_degree, self.max_degree, self.mean_degree)?;
+        writeln!(f, "density:          {:.4}", self.density)?;
+        write!(f, "weights:          min {}, max {}, {} negative", weight(self.min_weight), weight(self.max_weight), self.negative_weights)
+    }
+}
@@ -1,5 +1,5 @@
-use std::collections::hash_map::Keys;
-use crate::Graph::Key;
+use crate::Diagnostic::{DiagnosticCode, Location, Report};
+use crate::Graph::{AttrValue, Key};
 use minidom::Element;
 use crate::Graph::key_type::key_enum::KeyType;
 
@@ -26,44 +26,98 @@ impl KeyScope for AllScope {
     const SCOPE: &'static str = "all";
 }
 
-pub fn collect_keys_for<T: KeyScope>(keys: &[Element]) -> Vec<Key> {
+// Keys (with value of their for attribute) that belong to given scope.
+fn keys_for<T: KeyScope>(keys: &[(Key, String)]) -> Vec<Key> {
     keys.iter()
-        .filter(|key| {
-            key.name() == "key"
-                && key
-                .attr("for")
-                .map(|for_val| for_val.to_lowercase() == T::SCOPE)
-                .unwrap_or(false)
-        })
-        .filter_map(|key| {
-            // is is a must
-            let id = key.attr("id")?;
-            // attr.name is a must
-            let attrname = key.attr("attr.name")?.to_string();
-            // attr.type has to be parsed in KeyType
-            let attrtype_str = key.attr("attr.type")?;
-            let attrtype = match attrtype_str {
-                "boolean" => KeyType::Boolean,
-                "int" => KeyType::Int,
-                "long" => KeyType::Long,
-                "float" => KeyType::Float,
-                "double" => KeyType::Double,
-                "string" => KeyType::String,
-                _ => return None, // invalid type
-            };
-            
-            // default value (optional, empty string otherwise)
-            let default = key.children()
-                .find(|c| c.name() == "default")
-                .map(|c| c.text())
-                .unwrap_or_else(|| "".to_string());
-            
-            Some(Key {
-                id: id.to_string(),
-                attrname,
-                attrtype,
-                default,
-            })
-        })
+        .filter(|(_, scope)| scope == T::SCOPE)
+        .map(|(key, _)| key.clone())
         .collect()
-}
\ No newline at end of file
+}
+
+// Reads a <key> element (index-th key of the document). Default value is converted according to attr.type; an invalid
+// one is reported and the key is kept without default value.
+fn parse_key(key: &Element, index: usize, report: &mut Report) -> Option<(Key, String)> {
+    // id is a must
+    let Some(id) = key.attr("id") else {
+        report.add(DiagnosticCode::IncompleteKey, Location::Element("key", index), None, "Key without id is ignored".to_string());
+        return None;
+    };
+    // attr.name is a must
+    let Some(attrname) = key.attr("attr.name") else {
+        report.add(DiagnosticCode::IncompleteKey, Location::Element("key", index), Some(id), format!("Key {} without attr.name is ignored", id));
+        return None;
+    };
+    // attr.type has to be parsed in KeyType (string if not given)
+    let attrtype = match key.attr("attr.type").unwrap_or("string").parse::<KeyType>() {
+        Ok(attrtype) => attrtype,
+        Err(err) => {
+            report.add(DiagnosticCode::UnknownKeyType, Location::Element("key", index), Some(id), format!("Key {} is ignored: {}", id, err));
+            return None;
+        }
+    };
+    // keys without for attribute apply to all elements
+    let scope = key.attr("for").unwrap_or(AllScope::SCOPE).to_lowercase();
+    if ![NodeScope::SCOPE, EdgeScope::SCOPE, GraphScope::SCOPE, AllScope::SCOPE].contains(&scope.as_str()) {
+        report.add(DiagnosticCode::UnsupportedKeyScope, Location::Element("key", index), Some(id), format!("Key {} is for {} elements which aren't supported", id, scope));
+        return None;
+    }
+
+    // default value (optional)
+    let value = key.children()
+        .find(|c| c.name() == "default")
+        .and_then(|c| match AttrValue::parse(&c.text(), &attrtype) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                report.add(DiagnosticCode::InvalidValue, Location::Element("key", index), Some(id), format!("Invalid default value of key {}: {}", id, err));
+                None
+            }
+        });
+
+    Some((
+        Key {
+            id: id.to_string(),
+            attrname: attrname.to_string(),
+            attrtype,
+            value,
+        },
+        scope,
+    ))
+}
+
+// Keys of a document sorted by the elements they apply to. Keys for all elements are contained in each of them (after the
+// specific ones).
+pub struct KeyCollection {
+    pub node: Vec<Key>,
+    pub edge: Vec<Key>,
+    pub graph: Vec<Key>,
+}
+
+impl KeyCollection {
+    // Reads all <key> elements. Key ids must be unique within the document, later declarations of an id are ignored.
+    pub(crate) fn collect(keys: &[Element], report: &mut Report) -> Self {
+        let mut parsed: Vec<(Key, String)> = Vec::new();
+
+        for (index, key) in keys.iter().enumerate() {
+            if let Some(id) = key.attr("id").filter(|&id| parsed.iter().any(|(k, _)| k.id == id)) {
+                report.add(DiagnosticCode::DuplicateKeyId, Location::Element("key", index), Some(id), format!("Key {} is declared more than once, only the first declaration is used", id));
+                continue;
+            }
+
+            if let Some(key) = parse_key(key, index, report) {
+                parsed.push(key);
+            }
+        }
+
+        let all = keys_for::<AllScope>(&parsed);
+        let with_all = |mut specific: Vec<Key>| {
+            specific.extend_from_slice(&all);
+            specific
+        };
+
+        KeyCollection {
+            node: with_all(keys_for::<NodeScope>(&parsed)),
+            edge: with_all(keys_for::<EdgeScope>(&parsed)),
+            graph: with_all(keys_for::<GraphScope>(&parsed)),
+        }
+    }
+}
@@ -0,0 +1,433 @@
+/*
+    Plot data file (Graph.dat) in version 2: Everything needed to draw a graph, self-describing so scripts don't have to
+    know the position of each value. Version 1 (see GraphOutput::write2File) is still written on request for older scripts.
+
+        dijkstra-plot-data 2
+        start "A"
+        dest "K"
+        negative-cycle "e1" "e2"
+        nodes no:int x:float y:float id:string cost:int? marked:bool
+        0 0.5 -1.25 "A" 0 true
+        edges id:string from:int to:int weight:int directed:bool marked:bool bend:float
+        "e0" 0 1 5 false true 0
+        paths rank:int from:int to:int cost:int bend:float
+        1 0 1 10 0
+
+    The first line gives the version. start, dest and negative-cycle are optional and refer to node and edge ids. Each table
+    starts with its name and its columns (name:type, a type ending with ? allows "-" as unknown value), followed by one row
+    per node, edge or edge of a ranked path. Nodes are numbered in order starting at 0, edges and paths refer to these
+    numbers. Strings are quoted (escapes: \" \\ \n \r \t), so ids may contain spaces. Empty lines and lines starting with
+    # are ignored, as are columns a reader doesn't know (so columns can be added without a new version).
+ */
+
+// Own objects.
+use crate::GraphOutput::{EdgePlot, GraphOutput, NodePlot, PathPlot, Plot};
+
+// Standard library.
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+
+pub const VERSION: u32 = 2;
+const MAGIC: &str = "dijkstra-plot-data";
+
+// Columns of the tables with their types as written into the file.
+const NODE_COLUMNS: &[(&str, &str)] = &[("no", "int"), ("x", "float"), ("y", "float"), ("id", "string"), ("cost", "int?"), ("marked", "bool")];
+const EDGE_COLUMNS: &[(&str, &str)] = &[("id", "string"), ("from", "int"), ("to", "int"), ("weight", "int"), ("directed", "bool"), ("marked", "bool"), ("bend", "float")];
+const PATH_COLUMNS: &[(&str, &str)] = &[("rank", "int"), ("from", "int"), ("to", "int"), ("cost", "int"), ("bend", "float")];
+
+// Problem found while reading a plot data file.
+#[derive(Debug, PartialEq)]
+pub struct PlotDataError {
+    pub line: usize, // starting at 1 (0 if the problem concerns the whole file)
+    pub message: String,
+}
+
+impl fmt::Display for PlotDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "line {}: {}", self.line, self.message)
+        }
+    }
+}
+
+impl std::error::Error for PlotDataError {}
+
+// Single value of a row.
+#[derive(Clone, PartialEq, Debug)]
+enum Value {
+    Int(i64),
+    Float(f32),
+    Bool(bool),
+    Str(String),
+    Unknown, // "-"
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Table {
+    Nodes,
+    Edges,
+    Paths,
+}
+
+impl Table {
+    fn name(&self) -> &'static str {
+        match self {
+            Table::Nodes => "nodes",
+            Table::Edges => "edges",
+            Table::Paths => "paths",
+        }
+    }
+
+    fn columns(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Table::Nodes => NODE_COLUMNS,
+            Table::Edges => EDGE_COLUMNS,
+            Table::Paths => PATH_COLUMNS,
+        }
+    }
+}
+
+// Row of a table: Values by column name.
+struct Row<'a> {
+    columns: &'a [(String, String)],
+    values: Vec<Value>,
+}
+
+impl Row<'_> {
+    fn get(&self, name: &str) -> &Value {
+        // Tables are checked for their columns before rows are read.
+        let i = self.columns.iter().position(|(column, _)| column == name).expect("column was checked");
+        &self.values[i]
+    }
+
+    fn int(&self, name: &str) -> i64 {
+        match self.get(name) {
+            Value::Int(value) => *value,
+            _ => unreachable!("type of column was checked"),
+        }
+    }
+
+    fn optional_int(&self, name: &str) -> Option<i64> {
+        match self.get(name) {
+            Value::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn float(&self, name: &str) -> f32 {
+        match self.get(name) {
+            Value::Float(value) => *value,
+            _ => unreachable!("type of column was checked"),
+        }
+    }
+
+    fn bool(&self, name: &str) -> bool {
+        matches!(self.get(name), Value::Bool(true))
+    }
+
+    fn string(&self, name: &str) -> String {
+        match self.get(name) {
+            Value::Str(value) => value.clone(),
+            _ => unreachable!("type of column was checked"),
+        }
+    }
+}
+
+pub struct PlotData;
+
+impl PlotData {
+    pub fn write(file_name: &str, plot: &Plot) -> io::Result<()> {
+        let mut file = GraphOutput::create_file(file_name)?;
+        file.write_all(Self::render(plot).as_bytes())
+    }
+
+    // Creates content of a data file (version 2).
+    pub fn render(plot: &Plot) -> String {
+        let mut data = String::new();
+        let header = |columns: &[(&str, &str)]| columns.iter().map(|(name, kind)| format!("{}:{}", name, kind)).collect::<Vec<String>>().join(" ");
+        let bool = |value: bool| if value { "true" } else { "false" };
+
+        // (Writing into a String can't fail.)
+        let _ = writeln!(data, "{} {}", MAGIC, VERSION);
+        if let Some(start) = plot.start {
+            let _ = writeln!(data, "start {}", Self::quote(&plot.nodes[start as usize].id));
+        }
+        if let Some(dest) = plot.dest {
+            let _ = writeln!(data, "dest {}", Self::quote(&plot.nodes[dest as usize].id));
+        }
+        if let Some(cycle) = plot.negative_cycle.as_ref() {
+            let ids: Vec<String> = cycle.iter().map(|id| Self::quote(id)).collect();
+            let _ = writeln!(data, "negative-cycle {}", ids.join(" "));
+        }
+
+        let _ = writeln!(data, "nodes {}", header(NODE_COLUMNS));
+        for node in plot.nodes.iter() {
+            let cost = node.cost.map_or(String::from("-"), |cost| cost.to_string());
+            let _ = writeln!(data, "{} {} {} {} {} {}", node.no, node.x, node.y, Self::quote(&node.id), cost, bool(node.marked));
+        }
+
+        let _ = writeln!(data, "edges {}", header(EDGE_COLUMNS));
+        for edge in plot.edges.iter() {
+            let _ = writeln!(data, "{} {} {} {} {} {} {}", Self::quote(&edge.id), edge.from, edge.to, edge.weight, bool(edge.directed), bool(edge.marked), edge.bend);
+        }
+
+        if !plot.paths.is_empty() {
+            let _ = writeln!(data, "paths {}", header(PATH_COLUMNS));
+            for path in plot.paths.iter() {
+                let _ = writeln!(data, "{} {} {} {} {}", path.rank, path.from, path.to, path.cost, path.bend);
+            }
+        }
+
+        data
+    }
+
+    pub fn read(file_name: &str) -> Result<Plot, PlotDataError> {
+        let text = std::fs::read_to_string(file_name).map_err(|err| PlotDataError { line: 0, message: format!("Unable to read {}: {}", file_name, err) })?;
+        Self::parse(&text)
+    }
+
+    // Reads content of a data file (version 2).
+    pub fn parse(text: &str) -> Result<Plot, PlotDataError> {
+        let mut plot = Plot { nodes: Vec::new(), edges: Vec::new(), paths: Vec::new(), negative_cycle: None, start: None, dest: None };
+        let mut version_read = false;
+        let mut table: Option<(Table, Vec<(String, String)>)> = None;
+        // Line and id of start and destination node.
+        let mut start: Option<(usize, String)> = None;
+        let mut dest: Option<(usize, String)> = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let number = i + 1;
+            let error = |message: String| PlotDataError { line: number, message };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let tokens = Self::tokenize(line).map_err(error)?;
+
+            // Version comes first, version 1 has no header at all.
+            if !version_read {
+                match tokens.as_slice() {
+                    [(magic, false), (version, false)] if magic == MAGIC => {
+                        if version != &VERSION.to_string() {
+                            return Err(error(format!("unsupported version {} (expected {})", version, VERSION)));
+                        }
+                        version_read = true;
+                        continue;
+                    }
+                    _ => return Err(error(format!("missing header \"{} {}\" (is it a file of version 1?)", MAGIC, VERSION))),
+                }
+            }
+
+            // Keywords aren't quoted, rows start with a number or a quoted id.
+            match tokens[0].0.as_str() {
+                "start" | "dest" if !tokens[0].1 => {
+                    let [_, (id, true)] = tokens.as_slice() else {
+                        return Err(error(format!("{} needs a quoted node id", tokens[0].0)));
+                    };
+                    let reference = Some((number, id.clone()));
+                    if tokens[0].0 == "start" {
+                        start = reference;
+                    } else {
+                        dest = reference;
+                    }
+                }
+                "negative-cycle" if !tokens[0].1 => {
+                    if tokens[1..].iter().any(|(_, quoted)| !quoted) {
+                        return Err(error(String::from("negative-cycle needs quoted edge ids")));
+                    }
+                    plot.negative_cycle = Some(tokens[1..].iter().map(|(id, _)| id.clone()).collect());
+                }
+                "nodes" | "edges" | "paths" if !tokens[0].1 => {
+                    let kind = match tokens[0].0.as_str() {
+                        "nodes" => Table::Nodes,
+                        "edges" => Table::Edges,
+                        _ => Table::Paths,
+                    };
+                    table = Some((kind, Self::columns(kind, &tokens[1..]).map_err(error)?));
+                }
+                _ => {
+                    let Some((kind, columns)) = table.as_ref() else {
+                        return Err(error(format!("unknown keyword: {}", tokens[0].0)));
+                    };
+                    if tokens.len() != columns.len() {
+                        return Err(error(format!("{} values instead of {} in {} table", tokens.len(), columns.len(), kind.name())));
+                    }
+                    let values = tokens.iter().zip(columns.iter()).map(|(token, (name, kind))| Self::value(token, name, kind)).collect::<Result<Vec<Value>, String>>().map_err(error)?;
+                    Self::add_row(&mut plot, *kind, &Row { columns, values }).map_err(error)?;
+                }
+            }
+        }
+
+        if !version_read {
+            return Err(PlotDataError { line: 0, message: String::from("file is empty") });
+        }
+
+        // Node ids are known now.
+        for (reference, target) in [(start, &mut plot.start), (dest, &mut plot.dest)] {
+            if let Some((line, id)) = reference {
+                let node = plot.nodes.iter().find(|node| node.id == id).ok_or_else(|| PlotDataError { line, message: format!("unknown node id: {}", id) })?;
+                *target = Some(node.no);
+            }
+        }
+
+        Ok(plot)
+    }
+
+    // Columns of a table header. Known columns must have their type, unknown ones are kept (and ignored).
+    fn columns(table: Table, tokens: &[(String, bool)]) -> Result<Vec<(String, String)>, String> {
+        let mut columns: Vec<(String, String)> = Vec::new();
+
+        for (token, _) in tokens {
+            let Some((name, kind)) = token.split_once(':') else {
+                return Err(format!("column {} has no type (expected name:type)", token));
+            };
+            if !["int", "float", "bool", "string"].contains(&kind.trim_end_matches('?')) {
+                return Err(format!("unknown type of column {}: {}", name, kind));
+            }
+            if columns.iter().any(|(column, _)| column == name) {
+                return Err(format!("duplicate column: {}", name));
+            }
+            if let Some((_, expected)) = table.columns().iter().find(|(column, _)| *column == name) {
+                // An optional column may be declared as one that always has a value, but not vice versa.
+                if kind != *expected && format!("{}?", kind) != *expected {
+                    return Err(format!("column {} has type {} instead of {}", name, kind, expected));
+                }
+            }
+            columns.push((name.to_string(), kind.to_string()));
+        }
+
+        for (name, _) in table.columns() {
+            if !columns.iter().any(|(column, _)| column == name) {
+                return Err(format!("{} table lacks column {}", table.name(), name));
+            }
+        }
+
+        Ok(columns)
+    }
+
+    fn value((token, quoted): &(String, bool), name: &str, kind: &str) -> Result<Value, String> {
+        let invalid = || format!("invalid value of column {} ({}): {}", name, kind, token);
+
+        if !quoted && token == "-" {
+            return if kind.ends_with('?') { Ok(Value::Unknown) } else { Err(invalid()) };
+        }
+
+        match (kind.trim_end_matches('?'), quoted) {
+            ("string", true) => Ok(Value::Str(token.clone())),
+            ("int", false) => token.parse().map(Value::Int).map_err(|_| invalid()),
+            ("float", false) => token.parse().map(Value::Float).map_err(|_| invalid()),
+            ("bool", false) => match token.as_str() {
+                "true" => Ok(Value::Bool(true)),
+                "false" => Ok(Value::Bool(false)),
+                _ => Err(invalid()),
+            },
+            _ => Err(invalid()),
+        }
+    }
+
+    fn add_row(plot: &mut Plot, table: Table, row: &Row) -> Result<(), String> {
+        // Node numbers that edges and paths refer to.
+        let node_count = plot.nodes.len();
+        let node = |name: &str| -> Result<u32, String> {
+            let no = row.int(name);
+            if no < 0 || no as usize >= node_count {
+                return Err(format!("unknown node number in column {}: {} (nodes have to come first)", name, no));
+            }
+            Ok(no as u32)
+        };
+
+        match table {
+            Table::Nodes => {
+                let no = row.int("no");
+                if no != node_count as i64 {
+                    return Err(format!("node number {} instead of {} (nodes are numbered in order)", no, node_count));
+                }
+                plot.nodes.push(NodePlot { no: no as u32, x: row.float("x"), y: row.float("y"), id: row.string("id"), cost: row.optional_int("cost"), marked: row.bool("marked") });
+            }
+            Table::Edges => {
+                let (from, to) = (node("from")?, node("to")?);
+                plot.edges.push(EdgePlot { id: row.string("id"), from, to, weight: row.int("weight"), directed: row.bool("directed"), marked: row.bool("marked"), bend: row.float("bend") });
+            }
+            Table::Paths => {
+                let (from, to) = (node("from")?, node("to")?);
+                let rank = row.int("rank");
+                if rank < 1 {
+                    return Err(format!("invalid rank: {} (ranks start at 1)", rank));
+                }
+                plot.paths.push(PathPlot { rank: rank as usize, from, to, cost: row.int("cost"), bend: row.float("bend") });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn quote(text: &str) -> String {
+        let mut quoted = String::from("\"");
+        for c in text.chars() {
+            match c {
+                '"' => quoted.push_str("\\\""),
+                '\\' => quoted.push_str("\\\\"),
+                '\n' => quoted.push_str("\\n"),
+                '\r' => quoted.push_str("\\r"),
+                '\t' => quoted.push_str("\\t"),
+                _ => quoted.push(c),
+            }
+        }
+        quoted.push('"');
+        quoted
+    }
+
+    // Splits line at whitespace outside of quotes. Each token tells whether it was quoted (escapes are resolved).
+    fn tokenize(line: &str) -> Result<Vec<(String, bool)>, String> {
+        let mut tokens: Vec<(String, bool)> = Vec::new();
+        let mut chars = line.chars().peekable();
+
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+            } else if c == '"' {
+                chars.next();
+                let mut token = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => token.push(match chars.next() {
+                            Some('"') => '"',
+                            Some('\\') => '\\',
+                            Some('n') => '\n',
+                            Some('r') => '\r',
+                            Some('t') => '\t',
+                            Some(other) => return Err(format!("unknown escape sequence: \\{}", other)),
+                            None => return Err(String::from("unterminated string")),
+                        }),
+                        Some(other) => token.push(other),
+                        None => return Err(String::from("unterminated string")),
+                    }
+                }
+                // Quoted strings have to be separated from the next value.
+                if chars.peek().is_some_and(|c| !c.is_whitespace()) {
+                    return Err(String::from("missing space after string"));
+                }
+                tokens.push((token, true));
+            } else {
+                let mut token = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() {
+                        break;
+                    }
+                    if c == '"' {
+                        return Err(format!("quote inside of value: {}", token));
+                    }
+                    token.push(c);
+                    chars.next();
+                }
+                tokens.push((token, false));
+            }
+        }
+
+        Ok(tokens)
+    }
+}
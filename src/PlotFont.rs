@@ -0,0 +1,85 @@
+/*
+    Font embedded into the application (DejaVu Sans, like matplotlib uses by default, see fonts/LICENSE-DejaVu), so
+    text of images can be measured and drawn without any font installed on the system.
+ */
+
+// Third crates.
+use ab_glyph::{Font, FontRef, GlyphId, OutlineCurve};
+
+static DEJAVU_SANS: &[u8] = include_bytes!("../fonts/DejaVuSans.ttf");
+
+pub struct PlotFont {
+    font: FontRef<'static>,
+}
+
+impl PlotFont {
+    pub fn new() -> Self {
+        PlotFont {
+            font: FontRef::try_from_slice(DEJAVU_SANS).expect("embedded font is valid"),
+        }
+    }
+
+    // Factor from font units to the unit of size (size is the height of the em square, e.g. in points).
+    fn scale(&self, size: f32) -> f32 {
+        size / self.font.units_per_em().unwrap_or(2048.0)
+    }
+
+    // Glyphs of text with their horizontal offsets in font units (kerning included) and the total width.
+    fn layout(&self, text: &str) -> (Vec<(GlyphId, f32)>, f32) {
+        let mut glyphs: Vec<(GlyphId, f32)> = Vec::new();
+        let mut x = 0.0;
+        let mut previous: Option<GlyphId> = None;
+
+        for c in text.chars() {
+            let id = self.font.glyph_id(c);
+            if let Some(previous) = previous {
+                x += self.font.kern_unscaled(previous, id);
+            }
+            glyphs.push((id, x));
+            x += self.font.h_advance_unscaled(id);
+            previous = Some(id);
+        }
+
+        (glyphs, x)
+    }
+
+    pub fn text_width(&self, text: &str, size: f32) -> f32 {
+        self.layout(text).1 * self.scale(size)
+    }
+
+    // Distance between baseline and the middle of lower case letters (to center text vertically).
+    pub fn middle(&self, size: f32) -> f32 {
+        (self.font.ascent_unscaled() + self.font.descent_unscaled()) / 2.0 * self.scale(size)
+    }
+
+    // Outlines of all glyphs of text with the start of its baseline at origin (y axis pointing downwards like in images).
+    // Each curve is given by its points: 2 for a line, 3 for a quadratic and 4 for a cubic curve.
+    pub fn outline(&self, text: &str, size: f32, origin: (f32, f32)) -> Vec<Vec<(f32, f32)>> {
+        let scale = self.scale(size);
+        let (glyphs, _) = self.layout(text);
+        let mut curves = Vec::new();
+
+        for (id, offset) in glyphs {
+            let Some(outline) = self.font.outline(id) else {
+                continue; // e.g. space
+            };
+            let point = |p: ab_glyph::Point| (origin.0 + (offset + p.x) * scale, origin.1 - p.y * scale);
+
+            for curve in outline.curves {
+                curves.push(match curve {
+                    OutlineCurve::Line(a, b) => vec![point(a), point(b)],
+                    OutlineCurve::Quad(a, b, c) => vec![point(a), point(b), point(c)],
+                    OutlineCurve::Cubic(a, b, c, d) => vec![point(a), point(b), point(c), point(d)],
+                });
+            }
+        }
+
+        curves
+    }
+}
+
+impl Default for PlotFont {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,400 @@
+/*
+    Image of a plot as list of simple shapes (lines, circles, texts and rectangles), so SVG and PNG images are made of
+    the same shapes and look the same. The image looks like the one of plot.py (matplotlib): Edges with their weights,
+    nodes with their ids, marked nodes and edges in red, ranked alternative paths in different colors below the edges,
+    a title naming the start node and a legend.
+
+    All lengths are given in points (1/72 inch) like in matplotlib, so the numbers below can be compared with plot.py.
+ */
+
+// Own objects.
+use crate::GraphOutput::Plot;
+use crate::PlotFont::PlotFont;
+
+// Standard library.
+use std::cmp::Reverse;
+use std::str::FromStr;
+
+// Area of the axes within the image (matplotlib's default subplot parameters: left, right, bottom, top).
+const AXES_LEFT: f32 = 0.125;
+const AXES_RIGHT: f32 = 0.9;
+const AXES_TOP: f32 = 1.0 - 0.88;
+const AXES_BOTTOM: f32 = 1.0 - 0.11;
+
+// Space around the data within the axes (share of the data range on each side).
+const MARGIN: f32 = 0.05;
+
+const EDGE_WIDTH: f32 = 4.0;
+const BEND: f32 = 0.2; // curvature per spacing of parallel edges (like rad of matplotlib's arc3 connection style)
+const ARROW_LENGTH: f32 = 16.0;
+const ARROW_WIDTH: f32 = 12.0;
+const LABEL_OFFSET: f32 = 10.0; // distance between a point and the baseline of its label
+const NODE_SIZE: f32 = 120.0; // area of node markers (like scatter's s parameter)
+const START_NODE_SIZE: f32 = 250.0;
+
+const TITLE_SIZE: f32 = 30.0;
+const TITLE_PAD: f32 = 6.0;
+const WEIGHT_SIZE: f32 = 14.0;
+const NODE_LABEL_SIZE: f32 = 16.0;
+const START_LABEL_SIZE: f32 = 25.0;
+const LEGEND_SIZE: f32 = 14.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const WHITE: Color = Color(0xff, 0xff, 0xff);
+    pub const BLACK: Color = Color(0x00, 0x00, 0x00);
+    pub const RED: Color = Color(0xff, 0x00, 0x00);
+    pub const BLUE: Color = Color(0x00, 0x00, 0xff);
+
+    pub fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+const MARKED: Color = Color::RED;
+const UNMARKED: Color = Color::BLUE;
+const LEGEND_FRAME: Color = Color(0xcc, 0xcc, 0xcc);
+// red, orange, green, purple, brown, pink, olive, cyan
+const PATH_COLORS: [Color; 8] = [
+    Color(0xff, 0x00, 0x00),
+    Color(0xff, 0xa5, 0x00),
+    Color(0x00, 0x80, 0x00),
+    Color(0x80, 0x00, 0x80),
+    Color(0xa5, 0x2a, 0x2a),
+    Color(0xff, 0xc0, 0xcb),
+    Color(0x80, 0x80, 0x00),
+    Color(0x00, 0xff, 0xff),
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LineCap {
+    Butt,
+    Square, // line is extended by half its width (matplotlib's "projecting")
+    Round,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextAnchor {
+    Start,
+    Middle,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Shape {
+    Line { from: (f32, f32), to: (f32, f32), color: Color, width: f32, opacity: f32, cap: LineCap },
+    Curve { from: (f32, f32), control: (f32, f32), to: (f32, f32), color: Color, width: f32, opacity: f32, cap: LineCap }, // quadratic bezier curve
+    Circle { center: (f32, f32), radius: f32, color: Color },
+    Polygon { points: Vec<(f32, f32)>, color: Color }, // filled
+    Text { position: (f32, f32), size: f32, text: String, anchor: TextAnchor }, // position is on the baseline
+    Rect { left: f32, top: f32, width: f32, height: f32, radius: f32, fill: Option<(Color, f32)>, stroke: Option<(Color, f32)> }, // fill with opacity, stroke with width
+}
+
+// Size of the image in inches (like matplotlib's figsize) and resolution of raster images.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ImageSize {
+    pub width: f32,
+    pub height: f32,
+    pub dpi: f32,
+}
+
+impl ImageSize {
+    pub fn points(&self) -> (f32, f32) {
+        (self.width * 72.0, self.height * 72.0)
+    }
+
+    pub fn pixels(&self) -> (u32, u32) {
+        ((self.width * self.dpi).round() as u32, (self.height * self.dpi).round() as u32)
+    }
+}
+
+// Like plot.py: 12 x 12 inch and matplotlib's default resolution.
+impl Default for ImageSize {
+    fn default() -> Self {
+        ImageSize { width: 12.0, height: 12.0, dpi: 100.0 }
+    }
+}
+
+// Size given as "width x height" in inches (e.g. "12x12"), resolution stays default.
+impl FromStr for ImageSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid image size: {} (expected WIDTHxHEIGHT in inches, e.g. 12x12)", s);
+        let (width, height) = s.split_once('x').ok_or_else(invalid)?;
+        let (width, height): (f32, f32) = (width.trim().parse().map_err(|_| invalid())?, height.trim().parse().map_err(|_| invalid())?);
+
+        if !(width > 0.0 && height > 0.0 && width.is_finite() && height.is_finite()) {
+            return Err(invalid());
+        }
+
+        Ok(ImageSize { width, height, ..ImageSize::default() })
+    }
+}
+
+// Rectangle (left, top, right, bottom) in image coordinates.
+#[derive(Clone, Copy)]
+struct Bounds {
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+}
+
+impl Bounds {
+    fn contains(&self, (x, y): (f32, f32)) -> bool {
+        x >= self.left && x <= self.right && y >= self.top && y <= self.bottom
+    }
+
+    // Whether line from p to q crosses the rectangle (Liang-Barsky clipping).
+    fn intersects(&self, p: (f32, f32), q: (f32, f32)) -> bool {
+        let (dx, dy) = (q.0 - p.0, q.1 - p.1);
+        let (mut t0, mut t1) = (0.0f32, 1.0f32);
+
+        for (denominator, numerator) in [(-dx, p.0 - self.left), (dx, self.right - p.0), (-dy, p.1 - self.top), (dy, self.bottom - p.1)] {
+            if denominator == 0.0 {
+                if numerator < 0.0 {
+                    return false;
+                }
+            } else {
+                let t = numerator / denominator;
+                if denominator < 0.0 {
+                    t0 = t0.max(t);
+                } else {
+                    t1 = t1.min(t);
+                }
+            }
+        }
+
+        t0 <= t1
+    }
+}
+
+// Maps data coordinates of the nodes into the axes area (y axis points upwards like in matplotlib).
+struct Transform {
+    axes: Bounds,
+    min: (f32, f32),
+    scale: (f32, f32),
+}
+
+impl Transform {
+    fn new(plot: &Plot, axes: Bounds) -> Self {
+        let range = |values: Vec<f32>| -> (f32, f32) {
+            let (min, max) = values.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+            if !min.is_finite() {
+                return (-1.0, 1.0); // no nodes
+            }
+            // A single value gets some room around it, otherwise there's nothing to scale.
+            let margin = if max > min { (max - min) * MARGIN } else { 1.0 };
+            (min - margin, max + margin)
+        };
+
+        let (x_min, x_max) = range(plot.nodes.iter().map(|node| node.x).collect());
+        let (y_min, y_max) = range(plot.nodes.iter().map(|node| node.y).collect());
+
+        Transform {
+            axes,
+            min: (x_min, y_min),
+            scale: ((axes.right - axes.left) / (x_max - x_min), (axes.bottom - axes.top) / (y_max - y_min)),
+        }
+    }
+
+    fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.axes.left + (x - self.min.0) * self.scale.0, self.axes.bottom - (y - self.min.1) * self.scale.1)
+    }
+}
+
+// Entry of the legend: Sample of a marker or line and its label.
+enum LegendEntry {
+    Marker(Color, String),
+    Line(Color, f32, f32, String), // color, width, opacity, label
+}
+
+pub struct Scene {
+    pub size: ImageSize,
+    pub shapes: Vec<Shape>, // in drawing order (later ones are drawn on top)
+}
+
+impl Scene {
+    // Creates the shapes of a plot. start is the number of the start node (emphasized and named in title), if there's none
+    // the start node of the plot is taken, otherwise the first node (like plot.py does with data files of version 1). Shapes are ordered like matplotlib's zorder of plot.py.
+    pub fn build(plot: &Plot, start: Option<u32>, size: ImageSize) -> Self {
+        let (width, height) = size.points();
+        let axes = Bounds { left: AXES_LEFT * width, top: AXES_TOP * height, right: AXES_RIGHT * width, bottom: AXES_BOTTOM * height };
+        let transform = Transform::new(plot, axes);
+        let font = PlotFont::new();
+        let start = start.or(plot.start).or(plot.nodes.first().map(|node| node.no));
+        let pos = |no: u32| {
+            let node = &plot.nodes[no as usize];
+            transform.apply(node.x, node.y)
+        };
+        let radius = |no: u32| if Some(no) == start { START_NODE_SIZE.sqrt() / 2.0 } else { NODE_SIZE.sqrt() / 2.0 };
+
+        let mut shapes: Vec<Shape> = Vec::new();
+        let text = |position: (f32, f32), size: f32, text: &str| Shape::Text { position, size, text: text.to_string(), anchor: TextAnchor::Middle };
+
+        shapes.push(Shape::Rect { left: 0.0, top: 0.0, width, height, radius: 0.0, fill: Some((Color::WHITE, 1.0)), stroke: None });
+
+        // Title names start node.
+        let title = match start {
+            Some(no) => format!("Dijkstra Graph Plot (Start Node: {})", plot.nodes[no as usize].id),
+            None => String::from("Dijkstra Graph Plot"),
+        };
+        shapes.push(text(((axes.left + axes.right) / 2.0, axes.top - TITLE_PAD), TITLE_SIZE, &title));
+
+        // Alternative paths lie below the edges, paths with higher rank further below (and wider so they stay visible). They
+        // follow the curves of parallel edges, so it's visible which of them a path takes.
+        let mut paths: Vec<_> = plot.paths.iter().collect();
+        paths.sort_by_key(|path| Reverse(path.rank));
+        for path in paths {
+            let color = PATH_COLORS[(path.rank - 1) % PATH_COLORS.len()];
+            let (from, to) = (pos(path.from), pos(path.to));
+            let width = EDGE_WIDTH + 5.0 * path.rank as f32;
+
+            if path.bend == 0.0 {
+                shapes.push(Shape::Line { from, to, color, width, opacity: 0.6, cap: LineCap::Round });
+            } else {
+                let control = Self::control_point(from, to, path.bend);
+                shapes.push(Shape::Curve { from, control, to, color, width, opacity: 0.6, cap: LineCap::Round });
+            }
+        }
+
+        // Edges and their weights: Parallel edges are curved, so they don't overlap. Directed edges end in an arrowhead that
+        // touches the marker of their target node.
+        for edge in plot.edges.iter() {
+            let color = if edge.marked { MARKED } else { UNMARKED };
+            let (from, mut to) = (pos(edge.from), pos(edge.to));
+            let control = Self::control_point(from, to, edge.bend);
+
+            let mut arrowhead = None;
+            if edge.directed && from != to {
+                // Arrow points in direction of the curve at its end.
+                let (dx, dy) = (to.0 - control.0, to.1 - control.1);
+                let length = (dx * dx + dy * dy).sqrt();
+                let direction = (dx / length, dy / length);
+                let tip = (to.0 - direction.0 * radius(edge.to), to.1 - direction.1 * radius(edge.to));
+                let base = (tip.0 - direction.0 * ARROW_LENGTH, tip.1 - direction.1 * ARROW_LENGTH);
+                let normal = (-direction.1 * ARROW_WIDTH / 2.0, direction.0 * ARROW_WIDTH / 2.0);
+
+                // Edge ends at the base of the arrow, its cap would blunt the tip otherwise.
+                to = base;
+                arrowhead = Some(Shape::Polygon { points: vec![tip, (base.0 + normal.0, base.1 + normal.1), (base.0 - normal.0, base.1 - normal.1)], color });
+            }
+
+            if edge.bend == 0.0 {
+                shapes.push(Shape::Line { from, to, color, width: EDGE_WIDTH, opacity: 1.0, cap: LineCap::Square });
+            } else {
+                shapes.push(Shape::Curve { from, control, to, color, width: EDGE_WIDTH, opacity: 1.0, cap: LineCap::Square });
+            }
+            shapes.extend(arrowhead);
+        }
+        for edge in plot.edges.iter() {
+            // Middle of the (curved) edge.
+            let (p, q) = (pos(edge.from), pos(edge.to));
+            let c = Self::control_point(p, q, edge.bend);
+            let middle = (0.25 * p.0 + 0.5 * c.0 + 0.25 * q.0, 0.25 * p.1 + 0.5 * c.1 + 0.25 * q.1);
+            shapes.push(text((middle.0, middle.1 - LABEL_OFFSET), WEIGHT_SIZE, &edge.weight.to_string()));
+        }
+
+        // Nodes (start node larger) and their ids.
+        for node in plot.nodes.iter() {
+            shapes.push(Shape::Circle { center: pos(node.no), radius: radius(node.no), color: if node.marked { MARKED } else { UNMARKED } });
+        }
+        for node in plot.nodes.iter() {
+            let size = if Some(node.no) == start { START_LABEL_SIZE } else { NODE_LABEL_SIZE };
+            let (x, y) = pos(node.no);
+            shapes.push(text((x, y - LABEL_OFFSET), size, &node.id));
+        }
+
+        // Frame of the axes (axis themselves are hidden).
+        shapes.push(Shape::Rect {
+            left: axes.left,
+            top: axes.top,
+            width: axes.right - axes.left,
+            height: axes.bottom - axes.top,
+            radius: 0.0,
+            fill: None,
+            stroke: Some((Color::BLACK, 0.8)),
+        });
+
+        Self::legend(&mut shapes, plot, &transform, &font);
+
+        Scene { size, shapes }
+    }
+
+    // Control point of a quadratic curve from p to q: Perpendicular to the middle of the line, bend spacings to its left.
+    fn control_point(p: (f32, f32), q: (f32, f32), bend: f32) -> (f32, f32) {
+        let (dx, dy) = (q.0 - p.0, q.1 - p.1);
+        ((p.0 + q.0) / 2.0 + BEND * bend * dy, (p.1 + q.1) / 2.0 - BEND * bend * dx)
+    }
+
+    // Legend with markers and lines of the plot. Like matplotlib's loc="best" it's placed where it covers least of the graph.
+    fn legend(shapes: &mut Vec<Shape>, plot: &Plot, transform: &Transform, font: &PlotFont) {
+        let mut entries = vec![
+            LegendEntry::Marker(UNMARKED, String::from("Unmarked Node")),
+            LegendEntry::Marker(MARKED, String::from("Marked Node")),
+            LegendEntry::Line(UNMARKED, EDGE_WIDTH, 1.0, String::from("Unmarked Edge")),
+            LegendEntry::Line(MARKED, EDGE_WIDTH, 1.0, String::from("Marked Edge")),
+        ];
+
+        let mut ranks: Vec<(usize, i64)> = plot.paths.iter().map(|path| (path.rank, path.cost)).collect();
+        ranks.sort();
+        ranks.dedup();
+        for (rank, cost) in ranks {
+            let color = PATH_COLORS[(rank - 1) % PATH_COLORS.len()];
+            entries.push(LegendEntry::Line(color, 8.0, 0.6, format!("Path #{} (costs: {})", rank, cost)));
+        }
+
+        // Distances in font size (matplotlib's defaults).
+        let em = LEGEND_SIZE;
+        let (border_pad, label_spacing, handle_length, handle_pad, axes_pad) = (0.4 * em, 0.5 * em, 2.0 * em, 0.8 * em, 0.5 * em);
+
+        let label_width = entries
+            .iter()
+            .map(|entry| match entry {
+                LegendEntry::Marker(_, label) | LegendEntry::Line(_, _, _, label) => font.text_width(label, em),
+            })
+            .fold(0.0, f32::max);
+        let width = 2.0 * border_pad + handle_length + handle_pad + label_width;
+        let height = 2.0 * border_pad + entries.len() as f32 * em + (entries.len() - 1) as f32 * label_spacing;
+
+        // Candidates in matplotlib's order: upper right, upper left, lower left, lower right, center left, center right,
+        // lower center, upper center, center.
+        let axes = transform.axes;
+        let (left, right) = (axes.left + axes_pad, axes.right - axes_pad - width);
+        let (top, bottom) = (axes.top + axes_pad, axes.bottom - axes_pad - height);
+        let (center_x, center_y) = ((axes.left + axes.right - width) / 2.0, (axes.top + axes.bottom - height) / 2.0);
+        let candidates = [(right, top), (left, top), (left, bottom), (right, bottom), (left, center_y), (right, center_y), (center_x, bottom), (center_x, top), (center_x, center_y)];
+
+        let points: Vec<(f32, f32)> = plot.nodes.iter().map(|node| transform.apply(node.x, node.y)).collect();
+        let badness = |&(x, y): &(f32, f32)| {
+            let bounds = Bounds { left: x, top: y, right: x + width, bottom: y + height };
+            let nodes = points.iter().filter(|&&p| bounds.contains(p)).count();
+            let edges = plot.edges.iter().filter(|edge| bounds.intersects(points[edge.from as usize], points[edge.to as usize])).count();
+            nodes + edges
+        };
+        // min_by_key returns the first of equally good candidates.
+        let (x, y) = candidates.iter().copied().min_by_key(badness).unwrap_or((right, top));
+
+        shapes.push(Shape::Rect { left: x, top: y, width, height, radius: 0.2 * em, fill: Some((Color::WHITE, 0.8)), stroke: Some((LEGEND_FRAME, 1.0)) });
+
+        for (i, entry) in entries.iter().enumerate() {
+            let middle = y + border_pad + i as f32 * (em + label_spacing) + em / 2.0;
+            let handle = (x + border_pad, x + border_pad + handle_length);
+
+            let label = match entry {
+                LegendEntry::Marker(color, label) => {
+                    shapes.push(Shape::Circle { center: ((handle.0 + handle.1) / 2.0, middle), radius: 7.5, color: *color });
+                    label
+                }
+                LegendEntry::Line(color, width, opacity, label) => {
+                    shapes.push(Shape::Line { from: (handle.0, middle), to: (handle.1, middle), color: *color, width: *width, opacity: *opacity, cap: LineCap::Butt });
+                    label
+                }
+            };
+
+            shapes.push(Shape::Text { position: (handle.1 + handle_pad, middle + font.middle(em)), size: em, text: label.clone(), anchor: TextAnchor::Start });
+        }
+    }
+}
@@ -0,0 +1,165 @@
+/*
+    Draws the shapes of a plot (see PlotScene) into a PNG image: Anti-aliased in pure Rust, text is drawn with the
+    embedded font, so neither python nor any installed font is needed (e.g. on CI and headless servers). The number of
+    pixels results from size and resolution of the scene (12 x 12 inch at 100 dpi gives 1200 x 1200 pixels like plot.py).
+ */
+
+// Third crates.
+use tiny_skia::{FillRule, Paint, PathBuilder, Pixmap, Stroke, Transform};
+
+// Own objects.
+use crate::GraphOutput::GraphOutput;
+use crate::PlotFont::PlotFont;
+use crate::PlotScene::{Color, LineCap, Scene, Shape, TextAnchor};
+
+// Standard library.
+use std::io::{self, BufWriter};
+
+pub struct PngRenderer;
+
+impl PngRenderer {
+    // Writes image as 8 bit RGB with its resolution (so viewers and printers know its physical size).
+    pub fn write(file_name: &str, scene: &Scene) -> io::Result<()> {
+        let pixmap = Self::render(scene)?;
+        let rgb: Vec<u8> = pixmap.data().chunks_exact(4).flat_map(|pixel| [pixel[0], pixel[1], pixel[2]]).collect();
+
+        let file = GraphOutput::create_file(file_name)?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), pixmap.width(), pixmap.height());
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let pixels_per_meter = (scene.size.dpi / 0.0254).round() as u32;
+        encoder.set_pixel_dims(Some(png::PixelDimensions { xppu: pixels_per_meter, yppu: pixels_per_meter, unit: png::Unit::Meter }));
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&rgb).map_err(io::Error::other)
+    }
+
+    // Draws all shapes in their order. Shapes are given in points, so they are scaled by resolution.
+    pub fn render(scene: &Scene) -> io::Result<Pixmap> {
+        let (width, height) = scene.size.pixels();
+        let Some(mut pixmap) = Pixmap::new(width, height) else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid image size: {} x {} pixels", width, height)));
+        };
+
+        let scale = scene.size.dpi / 72.0;
+        let transform = Transform::from_scale(scale, scale);
+        let font = PlotFont::new();
+
+        for shape in scene.shapes.iter() {
+            match shape {
+                Shape::Line { from, to, color, width, opacity, cap } => {
+                    let mut path = PathBuilder::new();
+                    path.move_to(from.0, from.1);
+                    path.line_to(to.0, to.1);
+                    if let Some(path) = path.finish() {
+                        pixmap.stroke_path(&path, &Self::paint(*color, *opacity), &Self::stroke(*width, *cap), transform, None);
+                    }
+                }
+                Shape::Curve { from, control, to, color, width, opacity, cap } => {
+                    let mut path = PathBuilder::new();
+                    path.move_to(from.0, from.1);
+                    path.quad_to(control.0, control.1, to.0, to.1);
+                    if let Some(path) = path.finish() {
+                        pixmap.stroke_path(&path, &Self::paint(*color, *opacity), &Self::stroke(*width, *cap), transform, None);
+                    }
+                }
+                Shape::Polygon { points, color } => {
+                    let mut path = PathBuilder::new();
+                    for (i, p) in points.iter().enumerate() {
+                        if i == 0 {
+                            path.move_to(p.0, p.1);
+                        } else {
+                            path.line_to(p.0, p.1);
+                        }
+                    }
+                    path.close();
+                    if let Some(path) = path.finish() {
+                        pixmap.fill_path(&path, &Self::paint(*color, 1.0), FillRule::Winding, transform, None);
+                    }
+                }
+                Shape::Circle { center, radius, color } => {
+                    if let Some(path) = PathBuilder::from_circle(center.0, center.1, *radius) {
+                        pixmap.fill_path(&path, &Self::paint(*color, 1.0), FillRule::Winding, transform, None);
+                    }
+                }
+                Shape::Text { position, size, text, anchor } => {
+                    let x = match anchor {
+                        TextAnchor::Start => position.0,
+                        TextAnchor::Middle => position.0 - font.text_width(text, *size) / 2.0,
+                    };
+
+                    // Glyph outlines consist of closed contours: A new one starts where the previous curve didn't end.
+                    let mut path = PathBuilder::new();
+                    let mut last: Option<(f32, f32)> = None;
+                    for curve in font.outline(text, *size, (x, position.1)) {
+                        if last != Some(curve[0]) {
+                            path.move_to(curve[0].0, curve[0].1);
+                        }
+                        match curve[..] {
+                            [_, p] => path.line_to(p.0, p.1),
+                            [_, c, p] => path.quad_to(c.0, c.1, p.0, p.1),
+                            [_, c1, c2, p] => path.cubic_to(c1.0, c1.1, c2.0, c2.1, p.0, p.1),
+                            _ => {}
+                        }
+                        last = curve.last().copied();
+                    }
+                    if let Some(path) = path.finish() {
+                        pixmap.fill_path(&path, &Self::paint(Color::BLACK, 1.0), FillRule::Winding, transform, None);
+                    }
+                }
+                Shape::Rect { left, top, width, height, radius, fill, stroke } => {
+                    let Some(path) = Self::rounded_rect(*left, *top, *width, *height, *radius) else {
+                        continue;
+                    };
+                    if let Some((color, opacity)) = fill {
+                        pixmap.fill_path(&path, &Self::paint(*color, *opacity), FillRule::Winding, transform, None);
+                    }
+                    if let Some((color, width)) = stroke {
+                        let stroke = Stroke { width: *width, ..Stroke::default() };
+                        pixmap.stroke_path(&path, &Self::paint(*color, 1.0), &stroke, transform, None);
+                    }
+                }
+            }
+        }
+
+        Ok(pixmap)
+    }
+
+    fn paint(color: Color, opacity: f32) -> Paint<'static> {
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(color.0, color.1, color.2, (opacity.clamp(0.0, 1.0) * 255.0).round() as u8);
+        paint.anti_alias = true;
+        paint
+    }
+
+    fn stroke(width: f32, cap: LineCap) -> Stroke {
+        Stroke {
+            width,
+            line_cap: match cap {
+                LineCap::Butt => tiny_skia::LineCap::Butt,
+                LineCap::Square => tiny_skia::LineCap::Square,
+                LineCap::Round => tiny_skia::LineCap::Round,
+            },
+            ..Stroke::default()
+        }
+    }
+
+    // Rectangle with corners rounded by quadratic curves (radius 0 gives sharp corners).
+    fn rounded_rect(left: f32, top: f32, width: f32, height: f32, radius: f32) -> Option<tiny_skia::Path> {
+        let (right, bottom) = (left + width, top + height);
+        let r = radius.min(width / 2.0).min(height / 2.0).max(0.0);
+
+        let mut path = PathBuilder::new();
+        path.move_to(left + r, top);
+        path.line_to(right - r, top);
+        path.quad_to(right, top, right, top + r);
+        path.line_to(right, bottom - r);
+        path.quad_to(right, bottom, right - r, bottom);
+        path.line_to(left + r, bottom);
+        path.quad_to(left, bottom, left, bottom - r);
+        path.line_to(left, top + r);
+        path.quad_to(left, top, left + r, top);
+        path.close();
+        path.finish()
+    }
+}
@@ -0,0 +1,54 @@
+pub mod binary_heap;
+pub mod dial_queue;
+pub mod fibonacci_heap;
+pub mod pairing_heap;
+pub mod radix_heap;
+
+pub use crate::PriorityQueue::binary_heap::BinaryHeap;
+pub use crate::PriorityQueue::dial_queue::DialQueue;
+pub use crate::PriorityQueue::fibonacci_heap::FibonacciHeap;
+pub use crate::PriorityQueue::pairing_heap::PairingHeap;
+pub use crate::PriorityQueue::radix_heap::RadixHeap;
+
+use std::str::FromStr;
+
+// Min-priority queue over items 0..capacity (node indices) as it is needed by dijkstra algorithm. Keys are path costs, so
+// they are never negative.
+pub trait PriorityQueue {
+    // Creates an empty queue for items 0..capacity. max_weight is the largest edge weight, so that
+    // monotone queues know how far keys can be ahead of the last popped key.
+    fn new(capacity: usize, max_weight: i64) -> Self where Self: Sized;
+
+    // Inserts item with given key or decreases its key if item is already queued with a larger key.
+    fn push(&mut self, item: usize, key: i64);
+
+    // Removes item with minimum key and returns it together with its key.
+    fn pop(&mut self) -> Option<(usize, i64)>;
+
+    fn is_empty(&self) -> bool;
+}
+
+// Available priority queue implementations (can be selected by command line).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum QueueKind {
+    Binary,
+    Pairing,
+    Fibonacci,
+    Radix,
+    Dial,
+}
+
+impl FromStr for QueueKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "binary" => Ok(QueueKind::Binary),
+            "pairing" => Ok(QueueKind::Pairing),
+            "fibonacci" => Ok(QueueKind::Fibonacci),
+            "radix" => Ok(QueueKind::Radix),
+            "dial" => Ok(QueueKind::Dial),
+            _ => Err(format!("unknown priority queue: {} (expected binary, pairing, fibonacci, radix or dial)", s)),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::PriorityQueue::PriorityQueue;
+
+// Marks items that are currently not stored in the heap.
+const NONE: usize = usize::MAX;
+
+// Indexed binary min-heap: Positions of all items are tracked so decrease-key runs in O(log(n)) without rebuilding the heap.
+pub struct BinaryHeap {
+    heap: Vec<usize>, // items, heap[0] has minimum key
+    pos: Vec<usize>,  // pos[item] := index of item in heap (or NONE)
+    keys: Vec<i64>,
+}
+
+impl BinaryHeap {
+    fn less(&self, a: usize, b: usize) -> bool {
+        self.keys[self.heap[a]] < self.keys[self.heap[b]]
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.pos[self.heap[a]] = a;
+        self.pos[self.heap[b]] = b;
+    }
+
+    // Moves entry at index i upwards until heap property is restored. Complexity: O(log(n))
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.less(i, parent) {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    // Moves entry at index i downwards until heap property is restored. Complexity: O(log(n))
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let (left, right) = (2 * i + 1, 2 * i + 2);
+            let mut smallest = i;
+
+            if left < self.heap.len() && self.less(left, smallest) {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.less(right, smallest) {
+                smallest = right;
+            }
+            if smallest == i {
+                break;
+            }
+
+            self.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+impl PriorityQueue for BinaryHeap {
+    fn new(capacity: usize, _max_weight: i64) -> Self {
+        BinaryHeap {
+            heap: Vec::with_capacity(capacity),
+            pos: vec![NONE; capacity],
+            keys: vec![i64::MAX; capacity],
+        }
+    }
+
+    fn push(&mut self, item: usize, key: i64) {
+        if self.pos[item] == NONE {
+            self.keys[item] = key;
+            self.heap.push(item);
+            self.pos[item] = self.heap.len() - 1;
+            self.sift_up(self.heap.len() - 1);
+        } else if key < self.keys[item] {
+            // decrease-key: entry can only move upwards
+            self.keys[item] = key;
+            self.sift_up(self.pos[item]);
+        }
+    }
+
+    fn pop(&mut self) -> Option<(usize, i64)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+
+        let item = self.heap.pop()?;
+        self.pos[item] = NONE;
+
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+
+        Some((item, self.keys[item]))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
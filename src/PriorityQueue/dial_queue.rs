@@ -0,0 +1,73 @@
+use crate::PriorityQueue::PriorityQueue;
+
+// Dial's bucket queue: Circular array of C+1 buckets with C := largest edge weight. Since all queued keys lie within
+// [last popped key, last popped key + C], key mod (C+1) identifies bucket uniquely. push in O(1), pop in amortized O(C).
+// Only useful for small integer weights because memory grows with C (see Dijkstra::MAX_DIAL_WEIGHT).
+pub struct DialQueue {
+    buckets: Vec<Vec<usize>>,
+    keys: Vec<i64>, // current key of each queued item
+    queued: Vec<bool>,
+    current: i64, // key of bucket that is scanned at the moment
+    len: usize,   // number of queued items (without outdated entries)
+}
+
+impl DialQueue {
+    fn bucket(&self, key: i64) -> usize {
+        key as usize % self.buckets.len()
+    }
+}
+
+impl PriorityQueue for DialQueue {
+    fn new(capacity: usize, max_weight: i64) -> Self {
+        DialQueue {
+            buckets: vec![Vec::new(); max_weight as usize + 1],
+            keys: vec![i64::MAX; capacity],
+            queued: vec![false; capacity],
+            current: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, item: usize, key: i64) {
+        debug_assert!(key >= self.current, "dial queue requires monotone keys");
+
+        if self.queued[item] && key >= self.keys[item] {
+            return;
+        }
+        if !self.queued[item] {
+            self.queued[item] = true;
+            self.len += 1;
+        }
+
+        self.keys[item] = key;
+        let bucket = self.bucket(key);
+        self.buckets[bucket].push(item);
+    }
+
+    fn pop(&mut self) -> Option<(usize, i64)> {
+        while self.len > 0 {
+            let bucket = self.bucket(self.current);
+
+            while let Some(item) = self.buckets[bucket].pop() {
+                // Skip entries that were superseded by a smaller key or already popped.
+                if self.queued[item] && self.keys[item] == self.current {
+                    self.queued[item] = false;
+                    self.len -= 1;
+                    return Some((item, self.current));
+                }
+            }
+
+            // Keys don't exceed i64::MAX, so there's nothing left behind the last key (only outdated entries).
+            match self.current.checked_add(1) {
+                Some(next) => self.current = next,
+                None => break,
+            }
+        }
+
+        None
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
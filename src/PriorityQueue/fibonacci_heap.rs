@@ -0,0 +1,223 @@
+use crate::PriorityQueue::PriorityQueue;
+
+// Marks missing links.
+const NONE: usize = usize::MAX;
+
+// Node of the heap. Each item owns exactly one node, so item indices are used as node handles.
+#[derive(Clone)]
+struct FibonacciNode {
+    key: i64,
+    parent: usize,
+    child: usize, // any child (children form a circular doubly linked list)
+    left: usize,
+    right: usize,
+    degree: usize,
+    marked: bool, // node lost a child since it became child of its current parent
+    queued: bool,
+}
+
+// Fibonacci heap: insert and decrease-key in amortized O(1), pop in amortized O(log(n)).
+pub struct FibonacciHeap {
+    nodes: Vec<FibonacciNode>,
+    min: usize, // root with minimum key (entry point into circular root list)
+    len: usize,
+}
+
+impl FibonacciHeap {
+    // Inserts single node x into the circular list right of node at.
+    fn splice(&mut self, at: usize, x: usize) {
+        let right = self.nodes[at].right;
+        self.nodes[x].left = at;
+        self.nodes[x].right = right;
+        self.nodes[right].left = x;
+        self.nodes[at].right = x;
+    }
+
+    // Removes node x from the circular list it belongs to.
+    fn unlink(&mut self, x: usize) {
+        let (left, right) = (self.nodes[x].left, self.nodes[x].right);
+        self.nodes[left].right = right;
+        self.nodes[right].left = left;
+        self.nodes[x].left = x;
+        self.nodes[x].right = x;
+    }
+
+    // Adds node x to root list and updates minimum.
+    fn add_root(&mut self, x: usize) {
+        self.nodes[x].parent = NONE;
+        self.nodes[x].marked = false;
+
+        if self.min == NONE {
+            self.nodes[x].left = x;
+            self.nodes[x].right = x;
+            self.min = x;
+        } else {
+            self.splice(self.min, x);
+            if self.nodes[x].key < self.nodes[self.min].key {
+                self.min = x;
+            }
+        }
+    }
+
+    // Makes root y a child of root x.
+    fn link(&mut self, y: usize, x: usize) {
+        self.unlink(y);
+        self.nodes[y].parent = x;
+        self.nodes[y].marked = false;
+
+        let child = self.nodes[x].child;
+        if child == NONE {
+            self.nodes[x].child = y;
+        } else {
+            self.splice(child, y);
+        }
+        self.nodes[x].degree += 1;
+    }
+
+    // Moves node x from child list of its parent to root list.
+    fn cut(&mut self, x: usize) {
+        let parent = self.nodes[x].parent;
+
+        if self.nodes[parent].child == x {
+            let right = self.nodes[x].right;
+            self.nodes[parent].child = if right == x { NONE } else { right };
+        }
+        self.unlink(x);
+        self.nodes[parent].degree -= 1;
+
+        self.add_root(x);
+    }
+
+    // Cuts marked ancestors until an unmarked one (or a root) is found.
+    fn cascading_cut(&mut self, mut y: usize) {
+        while self.nodes[y].parent != NONE {
+            if !self.nodes[y].marked {
+                self.nodes[y].marked = true;
+                return;
+            }
+
+            let parent = self.nodes[y].parent;
+            self.cut(y);
+            y = parent;
+        }
+    }
+
+    // Links roots of equal degree until all roots have distinct degrees. Complexity: amortized O(log(n))
+    fn consolidate(&mut self) {
+        // Collect roots first because links change the root list while iterating.
+        let mut roots: Vec<usize> = Vec::new();
+        let start = self.min;
+        let mut current = start;
+        loop {
+            roots.push(current);
+            current = self.nodes[current].right;
+            if current == start {
+                break;
+            }
+        }
+
+        let mut by_degree: Vec<usize> = Vec::new();
+
+        for mut x in roots {
+            let mut degree = self.nodes[x].degree;
+
+            loop {
+                if degree >= by_degree.len() {
+                    by_degree.resize(degree + 1, NONE);
+                }
+
+                let y = by_degree[degree];
+                if y == NONE {
+                    break;
+                }
+
+                let (parent, child) = if self.nodes[y].key < self.nodes[x].key { (y, x) } else { (x, y) };
+                self.link(child, parent);
+                x = parent;
+
+                by_degree[degree] = NONE;
+                degree += 1;
+            }
+
+            by_degree[degree] = x;
+        }
+
+        // Find new minimum among remaining roots.
+        self.min = NONE;
+        for root in by_degree.into_iter().filter(|&r| r != NONE) {
+            if self.min == NONE || self.nodes[root].key < self.nodes[self.min].key {
+                self.min = root;
+            }
+        }
+    }
+}
+
+impl PriorityQueue for FibonacciHeap {
+    fn new(capacity: usize, _max_weight: i64) -> Self {
+        let empty = FibonacciNode { key: i64::MAX, parent: NONE, child: NONE, left: NONE, right: NONE, degree: 0, marked: false, queued: false };
+
+        FibonacciHeap {
+            nodes: vec![empty; capacity],
+            min: NONE,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, item: usize, key: i64) {
+        if !self.nodes[item].queued {
+            self.nodes[item] = FibonacciNode { key, parent: NONE, child: NONE, left: item, right: item, degree: 0, marked: false, queued: true };
+            self.add_root(item);
+            self.len += 1;
+        } else if key < self.nodes[item].key {
+            // decrease-key: cut node from its parent if heap order is violated
+            self.nodes[item].key = key;
+
+            let parent = self.nodes[item].parent;
+            if parent != NONE && key < self.nodes[parent].key {
+                self.cut(item);
+                self.cascading_cut(parent);
+            }
+
+            if key < self.nodes[self.min].key {
+                self.min = item;
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<(usize, i64)> {
+        if self.min == NONE {
+            return None;
+        }
+
+        let min = self.min;
+
+        // Children of minimum become roots.
+        while self.nodes[min].child != NONE {
+            let child = self.nodes[min].child;
+            let right = self.nodes[child].right;
+            self.nodes[min].child = if right == child { NONE } else { right };
+            self.unlink(child);
+            self.add_root(child);
+        }
+
+        // Remove minimum from root list.
+        let right = self.nodes[min].right;
+        self.unlink(min);
+        self.nodes[min].queued = false;
+        self.nodes[min].degree = 0;
+        self.len -= 1;
+
+        if right == min {
+            self.min = NONE;
+        } else {
+            self.min = right;
+            self.consolidate();
+        }
+
+        Some((min, self.nodes[min].key))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
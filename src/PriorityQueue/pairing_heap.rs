@@ -0,0 +1,139 @@
+use crate::PriorityQueue::PriorityQueue;
+
+// Marks missing links.
+const NONE: usize = usize::MAX;
+
+// Node of the heap. Each item owns exactly one node, so item indices are used as node handles.
+#[derive(Clone)]
+struct PairingNode {
+    key: i64,
+    child: usize,   // leftmost child
+    sibling: usize, // right sibling
+    prev: usize,    // left sibling or parent (if node is leftmost child)
+    queued: bool,
+}
+
+// Pairing heap: insert and meld in O(1), decrease-key in amortized o(log(n)), pop in amortized O(log(n)).
+pub struct PairingHeap {
+    nodes: Vec<PairingNode>,
+    root: usize,
+    len: usize,
+}
+
+impl PairingHeap {
+    // Links two heap-ordered trees and returns the new root. Both a and b must be roots without siblings.
+    fn meld(&mut self, a: usize, b: usize) -> usize {
+        if a == NONE {
+            return b;
+        }
+        if b == NONE {
+            return a;
+        }
+
+        let (parent, child) = if self.nodes[b].key < self.nodes[a].key { (b, a) } else { (a, b) };
+
+        // child becomes leftmost child of parent
+        let first = self.nodes[parent].child;
+        self.nodes[child].sibling = first;
+        self.nodes[child].prev = parent;
+        if first != NONE {
+            self.nodes[first].prev = child;
+        }
+        self.nodes[parent].child = child;
+
+        parent
+    }
+
+    // Removes subtree rooted at x from its parent/siblings.
+    fn cut(&mut self, x: usize) {
+        let prev = self.nodes[x].prev;
+        let sibling = self.nodes[x].sibling;
+
+        if prev != NONE {
+            if self.nodes[prev].child == x {
+                self.nodes[prev].child = sibling; // x was leftmost child
+            } else {
+                self.nodes[prev].sibling = sibling;
+            }
+        }
+        if sibling != NONE {
+            self.nodes[sibling].prev = prev;
+        }
+
+        self.nodes[x].prev = NONE;
+        self.nodes[x].sibling = NONE;
+    }
+
+    // Two-pass pairing: Melds siblings pairwise from left to right, then the results from right to left.
+    fn merge_pairs(&mut self, first: usize) -> usize {
+        let mut pairs: Vec<usize> = Vec::new();
+        let mut current = first;
+
+        while current != NONE {
+            let a = current;
+            let b = self.nodes[a].sibling;
+            current = if b != NONE { self.nodes[b].sibling } else { NONE };
+
+            self.nodes[a].sibling = NONE;
+            self.nodes[a].prev = NONE;
+            if b != NONE {
+                self.nodes[b].sibling = NONE;
+                self.nodes[b].prev = NONE;
+            }
+
+            pairs.push(self.meld(a, b));
+        }
+
+        let mut root = NONE;
+        while let Some(tree) = pairs.pop() {
+            root = self.meld(tree, root);
+        }
+
+        root
+    }
+}
+
+impl PriorityQueue for PairingHeap {
+    fn new(capacity: usize, _max_weight: i64) -> Self {
+        PairingHeap {
+            nodes: vec![PairingNode { key: i64::MAX, child: NONE, sibling: NONE, prev: NONE, queued: false }; capacity],
+            root: NONE,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, item: usize, key: i64) {
+        if !self.nodes[item].queued {
+            self.nodes[item] = PairingNode { key, child: NONE, sibling: NONE, prev: NONE, queued: true };
+            self.root = self.meld(self.root, item);
+            self.len += 1;
+        } else if key < self.nodes[item].key {
+            // decrease-key: cut subtree and meld it with root again
+            self.nodes[item].key = key;
+            if item != self.root {
+                self.cut(item);
+                self.root = self.meld(self.root, item);
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<(usize, i64)> {
+        if self.root == NONE {
+            return None;
+        }
+
+        let min = self.root;
+        let first_child = self.nodes[min].child;
+
+        self.nodes[min].child = NONE;
+        self.nodes[min].queued = false;
+        self.root = self.merge_pairs(first_child);
+        self.len -= 1;
+
+        Some((min, self.nodes[min].key))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
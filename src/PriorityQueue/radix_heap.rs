@@ -0,0 +1,81 @@
+use crate::PriorityQueue::PriorityQueue;
+
+// Number of buckets: bucket 0 holds keys equal to last popped key, bucket i holds keys whose highest bit differing from it is bit i-1.
+const BUCKETS: usize = i64::BITS as usize + 1;
+
+// Radix heap: Monotone queue for integer keys (popped keys never decrease, which is true for dijkstra algorithm).
+// push in O(1), pop in amortized O(log(C)) with C := largest key. Decreased keys are pushed again, outdated entries are skipped.
+pub struct RadixHeap {
+    buckets: Vec<Vec<(i64, usize)>>,
+    keys: Vec<i64>, // current key of each queued item
+    queued: Vec<bool>,
+    last: i64, // last popped key
+    len: usize, // number of queued items (without outdated entries)
+}
+
+impl RadixHeap {
+    fn bucket(&self, key: i64) -> usize {
+        (i64::BITS - (key ^ self.last).leading_zeros()) as usize
+    }
+}
+
+impl PriorityQueue for RadixHeap {
+    fn new(capacity: usize, _max_weight: i64) -> Self {
+        RadixHeap {
+            buckets: vec![Vec::new(); BUCKETS],
+            keys: vec![i64::MAX; capacity],
+            queued: vec![false; capacity],
+            last: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, item: usize, key: i64) {
+        debug_assert!(key >= self.last, "radix heap requires monotone keys");
+
+        if self.queued[item] && key >= self.keys[item] {
+            return;
+        }
+        if !self.queued[item] {
+            self.queued[item] = true;
+            self.len += 1;
+        }
+
+        self.keys[item] = key;
+        let bucket = self.bucket(key);
+        self.buckets[bucket].push((key, item));
+    }
+
+    fn pop(&mut self) -> Option<(usize, i64)> {
+        loop {
+            if self.len == 0 {
+                return None;
+            }
+
+            if self.buckets[0].is_empty() {
+                // Find first non-empty bucket, its minimum becomes new last key and its entries are redistributed into lower buckets.
+                let i = (1..BUCKETS).find(|&i| !self.buckets[i].is_empty())?;
+                let entries = std::mem::take(&mut self.buckets[i]);
+
+                self.last = entries.iter().map(|&(key, _)| key).min()?;
+                for (key, item) in entries {
+                    let bucket = self.bucket(key);
+                    self.buckets[bucket].push((key, item));
+                }
+            }
+
+            let (key, item) = self.buckets[0].pop()?;
+
+            // Skip entries that were superseded by a smaller key or already popped.
+            if self.queued[item] && self.keys[item] == key {
+                self.queued[item] = false;
+                self.len -= 1;
+                return Some((item, key));
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
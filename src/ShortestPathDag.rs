@@ -0,0 +1,260 @@
+// Own objects.
+use crate::Dijkstra::{Dijkstra, DijkstraPath, Restrictions, INFINITE_COSTS};
+use crate::Graph::{EdgeIndex, Graph, IgraphObject, NodeIndex};
+use crate::PriorityQueue::BinaryHeap;
+
+// Node of a shortest-path DAG: All predecessors over which the node is reached with minimal costs.
+pub struct DagEntry {
+    pub owner: NodeIndex,
+    pub preds: Vec<(NodeIndex, EdgeIndex)>, // predecessor and edge leading from it
+    pub c: i64, // INFINITE_COSTS if node can't be reached
+    pub count: u64, // number of distinct shortest paths from start (saturates at u64::MAX)
+}
+
+// Shortest-path DAG: Union of all cheapest paths from start node. Unlike dijkstra result (one prev per node) ties are kept.
+pub struct ShortestPathDag {
+    pub entries: Vec<DagEntry>, // indexed like graph.nodes()
+    order: Vec<NodeIndex>, // reachable nodes in topological order (predecessors always come first)
+    edges: Vec<bool>, // indexed like graph.edges(): edge is used by the DAG
+}
+
+impl ShortestPathDag {
+    // Runs dijkstra algorithm and collects each connection u -> v with costs(u) + weight == costs(v) as DAG edge. Only
+    // zero-weight edges can close a cycle of such connections: Inside a strongly connected component of them, edges only
+    // count in the order in which nodes were settled, so DAG stays acyclic. Fails if the graph has negative weights.
+    // Complexity: O((V + E) * log(V))
+    pub fn build(graph: &Graph, start: NodeIndex) -> Result<Self, String> {
+        Dijkstra::check_weights(graph)?;
+
+        let mut entries: Vec<DagEntry> = graph
+            .node_indices()
+            .map(|v| DagEntry { owner: v, preds: Vec::new(), c: INFINITE_COSTS, count: 0 })
+            .collect();
+        let mut edges: Vec<bool> = vec![false; graph.edge_len()];
+
+        if start.index() >= graph.node_len() {
+            return Ok(ShortestPathDag { entries, order: Vec::new(), edges });
+        }
+
+        let tree = Dijkstra::search::<BinaryHeap>(graph, start, None, Dijkstra::max_weight(graph), &Restrictions::default());
+
+        // Position of each node in settle order.
+        let mut rank: Vec<usize> = vec![usize::MAX; graph.node_len()];
+        for (i, &u) in tree.order.iter().enumerate() {
+            rank[u.index()] = i;
+            entries[u.index()].c = tree.dist[u.index()].unwrap_or(INFINITE_COSTS);
+        }
+
+        // All connections on cheapest paths (u, v, edge, weight).
+        let mut tight: Vec<(NodeIndex, NodeIndex, EdgeIndex, i64)> = Vec::new();
+        for &u in tree.order.iter() {
+            for &(v, e) in graph.neighbors(u).iter() {
+                let weight = graph.edge(e).weight();
+                let cost = tree.dist[u.index()].and_then(|dist| Dijkstra::add_costs(dist, weight));
+                if cost.is_some() && cost == tree.dist[v.index()] {
+                    tight.push((u, v, e, weight));
+                }
+            }
+        }
+
+        let zero_weight: Vec<(usize, usize)> = tight.iter().filter(|arc| arc.3 == 0).map(|&(u, v, _, _)| (u.index(), v.index())).collect();
+        let component = Self::components(graph.node_len(), &zero_weight);
+
+        for &(u, v, e, _) in tight.iter() {
+            if component[u.index()] != component[v.index()] || rank[u.index()] < rank[v.index()] {
+                entries[v.index()].preds.push((u, e));
+                edges[e.index()] = true;
+            }
+        }
+
+        // Topological order (Kahn): A node follows once all of its predecessors are placed. Nodes of equal costs that are
+        // connected by zero-weight edges may be settled in any order, so settle order isn't topological by itself.
+        let mut successors: Vec<Vec<NodeIndex>> = vec![Vec::new(); graph.node_len()];
+        let mut missing: Vec<usize> = entries.iter().map(|entry| entry.preds.len()).collect();
+        for entry in entries.iter() {
+            for &(u, _) in entry.preds.iter() {
+                successors[u.index()].push(entry.owner);
+            }
+        }
+
+        let mut order: Vec<NodeIndex> = vec![start];
+        let mut i = 0;
+        while let Some(&u) = order.get(i) {
+            for &v in successors[u.index()].iter() {
+                missing[v.index()] -= 1;
+                if missing[v.index()] == 0 {
+                    order.push(v);
+                }
+            }
+            i += 1;
+        }
+
+        // Count paths in topological order: Each path to v ends with one of its DAG edges.
+        entries[start.index()].count = 1;
+        for &v in order.iter().skip(1) {
+            let count = entries[v.index()].preds.iter().fold(0u64, |sum, &(u, _)| sum.saturating_add(entries[u.index()].count));
+            entries[v.index()].count = count;
+        }
+
+        Ok(ShortestPathDag { entries, order, edges })
+    }
+
+    // Strongly connected components (Kosaraju) of the graph with given arcs: Component number for each node.
+    fn components(node_len: usize, arcs: &[(usize, usize)]) -> Vec<usize> {
+        let mut forward: Vec<Vec<usize>> = vec![Vec::new(); node_len];
+        let mut backward: Vec<Vec<usize>> = vec![Vec::new(); node_len];
+        for &(u, v) in arcs.iter() {
+            forward[u].push(v);
+            backward[v].push(u);
+        }
+
+        // Nodes in order of finished depth-first search (stack holds node and index of next successor to visit).
+        let mut visited: Vec<bool> = vec![false; node_len];
+        let mut finished: Vec<usize> = Vec::with_capacity(node_len);
+        for root in 0..node_len {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+
+            let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+            while let Some(&mut (u, ref mut next)) = stack.last_mut() {
+                if let Some(&v) = forward[u].get(*next) {
+                    *next += 1;
+                    if !visited[v] {
+                        visited[v] = true;
+                        stack.push((v, 0));
+                    }
+                } else {
+                    finished.push(u);
+                    stack.pop();
+                }
+            }
+        }
+
+        // Searching backwards in reverse finish order, each search collects exactly one component.
+        let mut component: Vec<usize> = vec![usize::MAX; node_len];
+        let mut count = 0;
+        for &root in finished.iter().rev() {
+            if component[root] != usize::MAX {
+                continue;
+            }
+
+            component[root] = count;
+            let mut stack: Vec<usize> = vec![root];
+            while let Some(u) = stack.pop() {
+                for &v in backward[u].iter() {
+                    if component[v] == usize::MAX {
+                        component[v] = count;
+                        stack.push(v);
+                    }
+                }
+            }
+            count += 1;
+        }
+
+        component
+    }
+
+    fn entry(&self, node: NodeIndex) -> Option<&DagEntry> {
+        self.entries.get(node.index())
+    }
+
+    // Number of distinct shortest paths from start to node.
+    pub fn count(&self, node: NodeIndex) -> u64 {
+        self.entry(node).map_or(0, |entry| entry.count)
+    }
+
+    // Sub-DAG that only contains nodes and edges lying on a shortest path to dest.
+    pub fn restricted_to(&self, dest: NodeIndex) -> ShortestPathDag {
+        let mut keep: Vec<bool> = vec![false; self.entries.len()];
+        if self.entry(dest).is_some_and(|entry| entry.c != INFINITE_COSTS) {
+            keep[dest.index()] = true;
+        }
+
+        // Walk backwards (reverse topological order) so every kept node passes the mark on to its predecessors.
+        for &v in self.order.iter().rev() {
+            if keep[v.index()] {
+                for &(u, _) in self.entries[v.index()].preds.iter() {
+                    keep[u.index()] = true;
+                }
+            }
+        }
+
+        let entries: Vec<DagEntry> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| DagEntry {
+                owner: entry.owner,
+                preds: if keep[i] { entry.preds.clone() } else { Vec::new() },
+                c: if keep[i] { entry.c } else { INFINITE_COSTS },
+                count: if keep[i] { entry.count } else { 0 },
+            })
+            .collect();
+        let order = self.order.iter().copied().filter(|&v| keep[v.index()]).collect();
+
+        let mut edges: Vec<bool> = vec![false; self.edges.len()];
+        for entry in entries.iter() {
+            for &(_, e) in entry.preds.iter() {
+                edges[e.index()] = true;
+            }
+        }
+
+        ShortestPathDag { entries, order, edges }
+    }
+
+    pub fn contains_node(&self, node: NodeIndex) -> bool {
+        self.entry(node).is_some_and(|entry| entry.c != INFINITE_COSTS)
+    }
+
+    pub fn contains_edge(&self, edge: EdgeIndex) -> bool {
+        self.edges.get(edge.index()).copied().unwrap_or(false)
+    }
+
+    // Enumerates up to limit distinct shortest paths from start to dest (there can be exponentially many of them).
+    pub fn paths_to(&self, dest: NodeIndex, limit: usize) -> Vec<DijkstraPath> {
+        let mut paths: Vec<DijkstraPath> = Vec::new();
+
+        let Some(cost) = self.entry(dest).map(|entry| entry.c).filter(|&c| c != INFINITE_COSTS) else {
+            return paths;
+        };
+
+        // Depth-first search backwards from dest: stack holds (node, index of next predecessor to try).
+        let mut stack: Vec<(NodeIndex, usize)> = vec![(dest, 0)];
+        let mut edges: Vec<EdgeIndex> = Vec::new();
+
+        while let Some(&mut (v, ref mut next)) = stack.last_mut() {
+            if paths.len() >= limit {
+                break;
+            }
+
+            if self.entries[v.index()].preds.is_empty() {
+                // Reached start node: stack contains a complete path (backwards).
+                let nodes = stack.iter().rev().map(|&(u, _)| u).collect();
+                let path_edges = edges.iter().rev().copied().collect();
+                paths.push(DijkstraPath::Reachable { nodes, edges: path_edges, cost });
+
+                stack.pop();
+                edges.pop();
+            } else if let Some(&(u, edge)) = self.entries[v.index()].preds.get(*next) {
+                *next += 1;
+                stack.push((u, 0));
+                edges.push(edge);
+            } else {
+                stack.pop();
+                edges.pop();
+            }
+        }
+
+        paths
+    }
+
+    // Prints number of shortest paths for each reachable node into console.
+    pub fn print_counts(&self, graph: &Graph) {
+        for &v in self.order.iter() {
+            let entry = &self.entries[v.index()];
+            println!("{}: costs {}, {} shortest path(s), {} predecessor(s)", graph.node(entry.owner).get_id(), entry.c, entry.count, entry.preds.len());
+        }
+    }
+}
@@ -0,0 +1,139 @@
+/*
+    Writes the shapes of a plot (see PlotScene) as SVG image. Lengths are kept in points, the viewer scales them.
+ */
+
+// Own objects.
+use crate::GraphOutput::GraphOutput;
+use crate::PlotScene::{LineCap, Scene, Shape, TextAnchor};
+
+// Standard library.
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+// Font of plot.py (matplotlib) and the embedded font of PNG images, with fallbacks if the viewer doesn't have it.
+const FONT: &str = "DejaVu Sans, Bitstream Vera Sans, Arial, Helvetica, sans-serif";
+
+pub struct SvgRenderer;
+
+impl SvgRenderer {
+    pub fn write(file_name: &str, scene: &Scene) -> io::Result<()> {
+        let mut file = GraphOutput::create_file(file_name)?;
+        file.write_all(Self::render(scene).as_bytes())
+    }
+
+    // Creates SVG document with one element per shape.
+    pub fn render(scene: &Scene) -> String {
+        let (width, height) = scene.size.points();
+
+        let mut svg = String::new();
+        // (Writing into a String can't fail.)
+        let _ = writeln!(svg, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}pt" height="{h}pt" viewBox="0 0 {w} {h}" font-family="{f}">"#,
+            w = width,
+            h = height,
+            f = FONT
+        );
+
+        for shape in scene.shapes.iter() {
+            match shape {
+                Shape::Line { from, to, color, width, opacity, cap } => {
+                    let _ = write!(
+                        svg,
+                        r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{}" stroke-linecap="{}""#,
+                        from.0,
+                        from.1,
+                        to.0,
+                        to.1,
+                        color.hex(),
+                        width,
+                        Self::cap(*cap)
+                    );
+                    if *opacity < 1.0 {
+                        let _ = write!(svg, r#" stroke-opacity="{}""#, opacity);
+                    }
+                    let _ = writeln!(svg, "/>");
+                }
+                Shape::Curve { from, control, to, color, width, opacity, cap } => {
+                    let _ = write!(
+                        svg,
+                        r#"<path d="M{:.2} {:.2} Q{:.2} {:.2} {:.2} {:.2}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="{}""#,
+                        from.0,
+                        from.1,
+                        control.0,
+                        control.1,
+                        to.0,
+                        to.1,
+                        color.hex(),
+                        width,
+                        Self::cap(*cap)
+                    );
+                    if *opacity < 1.0 {
+                        let _ = write!(svg, r#" stroke-opacity="{}""#, opacity);
+                    }
+                    let _ = writeln!(svg, "/>");
+                }
+                Shape::Circle { center, radius, color } => {
+                    let _ = writeln!(svg, r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}"/>"#, center.0, center.1, radius, color.hex());
+                }
+                Shape::Polygon { points, color } => {
+                    let points: Vec<String> = points.iter().map(|p| format!("{:.2},{:.2}", p.0, p.1)).collect();
+                    let _ = writeln!(svg, r#"<polygon points="{}" fill="{}"/>"#, points.join(" "), color.hex());
+                }
+                Shape::Text { position, size, text, anchor } => {
+                    let anchor = match anchor {
+                        TextAnchor::Start => "start",
+                        TextAnchor::Middle => "middle",
+                    };
+                    let _ = writeln!(
+                        svg,
+                        r#"<text x="{:.2}" y="{:.2}" font-size="{}" text-anchor="{}">{}</text>"#,
+                        position.0,
+                        position.1,
+                        size,
+                        anchor,
+                        Self::escape(text)
+                    );
+                }
+                Shape::Rect { left, top, width, height, radius, fill, stroke } => {
+                    let _ = write!(svg, r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}""#, left, top, width, height);
+                    if *radius > 0.0 {
+                        let _ = write!(svg, r#" rx="{:.2}""#, radius);
+                    }
+                    match fill {
+                        Some((color, opacity)) if *opacity < 1.0 => {
+                            let _ = write!(svg, r#" fill="{}" fill-opacity="{}""#, color.hex(), opacity);
+                        }
+                        Some((color, _)) => {
+                            let _ = write!(svg, r#" fill="{}""#, color.hex());
+                        }
+                        None => {
+                            let _ = write!(svg, r#" fill="none""#);
+                        }
+                    }
+                    if let Some((color, width)) = stroke {
+                        let _ = write!(svg, r#" stroke="{}" stroke-width="{}""#, color.hex(), width);
+                    }
+                    let _ = writeln!(svg, "/>");
+                }
+            }
+        }
+
+        let _ = writeln!(svg, "</svg>");
+        svg
+    }
+
+    fn cap(cap: LineCap) -> &'static str {
+        match cap {
+            LineCap::Butt => "butt",
+            LineCap::Square => "square",
+            LineCap::Round => "round",
+        }
+    }
+
+    // Node ids may contain characters that have a meaning in XML.
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+    }
+}
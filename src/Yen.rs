@@ -0,0 +1,130 @@
+// Own objects.
+use crate::Dijkstra::{Dijkstra, DijkstraPath, Restrictions};
+use crate::Graph::{EdgeIndex, Graph, IgraphObject, NodeIndex};
+use crate::PriorityQueue::BinaryHeap;
+
+// Loopless path (edges[i] connects nodes[i] and nodes[i + 1]).
+#[derive(Clone, PartialEq)]
+struct IndexPath {
+    nodes: Vec<NodeIndex>,
+    edges: Vec<EdgeIndex>,
+    cost: i64,
+}
+
+pub struct Yen;
+
+impl Yen {
+    // Searches up to k cheapest loopless paths from start to dest (Yen's algorithm), ordered by costs (rank 1 first).
+    // Each further path deviates from a previous one at some spur node: The part up to the spur node (root path) is kept
+    // and the rest is found by dijkstra algorithm while root path nodes and already used continuations are removed. Fails
+    // if the graph has negative weights.
+    pub fn run(graph: &Graph, start: NodeIndex, dest: NodeIndex, k: usize) -> Result<Vec<DijkstraPath>, String> {
+        Dijkstra::check_weights(graph)?;
+        let max_weight = Dijkstra::max_weight(graph);
+
+        if start.index() >= graph.node_len() || dest.index() >= graph.node_len() {
+            return Ok(Vec::new());
+        }
+        let (s, t) = (start, dest);
+
+        // Cheapest path from given node to t with given restrictions.
+        let spur_path = |from: NodeIndex, restrictions: &Restrictions| -> Option<IndexPath> {
+            let tree = Dijkstra::search::<BinaryHeap>(graph, from, Some(t), max_weight, restrictions);
+            let cost = tree.dist[t.index()]?;
+
+            let mut path = IndexPath { nodes: vec![t], edges: Vec::new(), cost };
+            let mut v = t;
+            while let Some((u, e)) = tree.prev[v.index()] {
+                path.nodes.push(u);
+                path.edges.push(e);
+                v = u;
+            }
+            path.nodes.reverse();
+            path.edges.reverse();
+
+            Some(path)
+        };
+
+        // Accepted paths (A) and candidates (B).
+        let mut accepted: Vec<IndexPath> = Vec::new();
+        let mut candidates: Vec<IndexPath> = Vec::new();
+
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+        match spur_path(s, &Restrictions::default()) {
+            Some(path) => accepted.push(path),
+            None => return Ok(Vec::new()),
+        }
+
+        while accepted.len() < k {
+            let last = accepted[accepted.len() - 1].clone();
+
+            for i in 0..last.edges.len() {
+                let spur_node = last.nodes[i];
+                let root_nodes = &last.nodes[..=i];
+                let root_edges = &last.edges[..i];
+
+                let mut restrictions = Restrictions {
+                    nodes: vec![false; graph.node_len()],
+                    edges: vec![false; graph.edge_len()],
+                };
+
+                // Continuations of accepted paths that share this root path must not be found again.
+                for path in accepted.iter() {
+                    if path.edges.len() > i && path.nodes[..=i] == *root_nodes && path.edges[..i] == *root_edges {
+                        restrictions.edges[path.edges[i].index()] = true;
+                    }
+                }
+
+                // Path must stay loopless: Root path nodes (except spur node) are removed.
+                for &v in root_nodes[..i].iter() {
+                    restrictions.nodes[v.index()] = true;
+                }
+
+                if let Some(spur) = spur_path(spur_node, &restrictions) {
+                    // Root path is part of an accepted path, so its costs can be represented.
+                    let root_cost: i64 = root_edges.iter().map(|&e| graph.edge(e).weight()).sum();
+                    let Some(cost) = Dijkstra::add_costs(root_cost, spur.cost) else {
+                        continue; // costs of the whole path can't be represented
+                    };
+
+                    let mut nodes = root_nodes[..i].to_vec();
+                    nodes.extend_from_slice(&spur.nodes);
+                    let mut edges = root_edges.to_vec();
+                    edges.extend_from_slice(&spur.edges);
+
+                    let candidate = IndexPath { nodes, edges, cost };
+                    if !candidates.contains(&candidate) && !accepted.contains(&candidate) {
+                        candidates.push(candidate);
+                    }
+                }
+            }
+
+            // Cheapest candidate (fewest edges on equal costs) becomes next path.
+            let Some(best) = candidates.iter().enumerate().min_by_key(|(_, p)| (p.cost, p.edges.len())).map(|(i, _)| i) else {
+                break;
+            };
+            accepted.push(candidates.swap_remove(best));
+        }
+
+        Ok(accepted
+            .into_iter()
+            .map(|path| DijkstraPath::Reachable { nodes: path.nodes, edges: path.edges, cost: path.cost })
+            .collect())
+    }
+
+    // Prints ranked paths into console.
+    pub fn print_paths(graph: &Graph, start: NodeIndex, dest: NodeIndex, paths: &[DijkstraPath]) {
+        println!("{} shortest loopless path(s) from {} to {}:", paths.len(), graph.node(start).get_id(), graph.node(dest).get_id());
+
+        for (rank, path) in paths.iter().enumerate() {
+            if let DijkstraPath::Reachable { nodes, edges, cost } = path {
+                let node_ids: Vec<&str> = nodes.iter().map(|&n| graph.node(n).get_id()).collect();
+                let edge_ids: Vec<&str> = edges.iter().map(|&e| graph.edge(e).get_id()).collect();
+
+                println!("#{} (costs: {}): {} [{}]", rank + 1, cost, node_ids.join(" -> "), edge_ids.join(", "));
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Shortest paths on GraphML graphs and their preparation for plotting.
+//!
+//! The usual workflow consists of four steps, each of them available as a function of this crate:
+//!
+//! 1. [`load_graph`] reads a GraphML file into a [`GraphDocument`](crate::GraphDocument::GraphDocument), which contains
+//!    the [`Graph`](crate::Graph::Graph) and [diagnostics](crate::Diagnostic::Diagnostic) about skipped elements. Nodes and edges are addressed by
+//!    [`NodeIndex`](crate::Graph::NodeIndex) and [`EdgeIndex`](crate::Graph::EdgeIndex).
+//! 2. [`shortest_paths`] computes cheapest paths from a start node to all nodes, [`shortest_path`] the cheapest path to a
+//!    single destination.
+//! 3. [`layout`] positions the nodes in a plane (force-directed, with the start node as center).
+//! 4. [`write_output`] writes graph, positions and marked nodes/edges into a data file that `plot.py` understands
+//!    (and [`read_output`] reads again), [`render_image`] draws them as SVG or PNG image right away.
+//!    [`write_graphml`] writes the graph with the results as GraphML file instead (e.g. for yEd or Gephi).
+//!
+//! ```no_run
+//! use dijkstra_plot::GraphOutput::Highlight;
+//!
+//! let graph = dijkstra_plot::load_graph("testgraph.xml").unwrap().graph;
+//! let start = graph.node_index("A").unwrap();
+//!
+//! let result = dijkstra_plot::shortest_paths(&graph, start).unwrap();
+//! let positions = dijkstra_plot::layout(&graph, start);
+//! dijkstra_plot::write_output("Graph.dat", &graph, &positions, &Highlight::Tree(&result)).unwrap();
+//! ```
+//!
+//! The modules give access to all other algorithms (A*, Bellman-Ford, all-pairs shortest paths, Yen's k shortest paths,
+//! shortest-path DAG), to metrics of a graph ([`GraphStats`](crate::GraphStats::GraphStats)) and to the individual
+//! priority queues.
+
+pub mod AllPairs;
+pub mod AStar;
+pub mod BellmanFord;
+pub mod BidirectionalDijkstra;
+pub mod Diagnostic;
+pub mod Dijkstra;
+pub mod Graph;
+pub mod GraphDocument;
+pub mod GraphMLWriter;
+pub mod GraphOutput;
+pub mod GraphPositioning;
+pub mod GraphStats;
+pub mod KeyCollection;
+pub mod PlotData;
+pub mod PlotFont;
+pub mod PlotScene;
+pub mod PngRenderer;
+pub mod PriorityQueue;
+pub mod ShortestPathDag;
+pub mod SvgRenderer;
+pub mod Yen;
+
+// Own objects.
+use crate::Dijkstra::{DijkstraHeapEle, DijkstraPath};
+use crate::Graph::NodeIndex;
+use crate::GraphOutput::Highlight;
+use crate::GraphPositioning::NodePos;
+use crate::PlotScene::ImageSize;
+
+/// Reads and parses the GraphML file at `path`.
+///
+/// Nodes and edges that can't be read are skipped and reported in
+/// [`GraphDocument::diagnostics`](crate::GraphDocument::GraphDocument::diagnostics). Fails only if the file can't be read,
+/// isn't valid XML or has no `<graph>` element.
+pub fn load_graph(path: &str) -> Result<GraphDocument::GraphDocument, Diagnostic::Diagnostic> {
+    GraphDocument::GraphDocument::load(path)
+}
+
+/// Costs and predecessors of the cheapest paths from `start` to all nodes (dijkstra algorithm with binary heap).
+///
+/// Nodes that can't be reached are listed last with costs [`Dijkstra::INFINITE_COSTS`]. Fails if the graph has negative
+/// edge weights, see [`BellmanFord`](crate::BellmanFord) for those.
+pub fn shortest_paths(graph: &Graph::Graph, start: NodeIndex) -> Result<Vec<DijkstraHeapEle>, String> {
+    Dijkstra::Dijkstra::run(graph, start)
+}
+
+/// Cheapest path from `start` to `dest` (bidirectional dijkstra algorithm, so only part of the graph is searched).
+///
+/// Fails if the graph has negative edge weights.
+pub fn shortest_path(graph: &Graph::Graph, start: NodeIndex, dest: NodeIndex) -> Result<DijkstraPath, String> {
+    Ok(BidirectionalDijkstra::BidirectionalDijkstra::run(graph, start, dest)?.path)
+}
+
+/// Positions of all nodes in a plane, computed by a force-directed layout around `start`.
+pub fn layout(graph: &Graph::Graph, start: NodeIndex) -> Vec<NodePos> {
+    GraphPositioning::GraphOptimization::run(graph, start)
+}
+
+/// Writes graph and node positions into the data file `file_name`, marking nodes and edges according to `highlight`.
+///
+/// The file has format version 2 (see [`PlotData`](crate::PlotData)), costs, start and destination node are taken from
+/// `highlight`. [`GraphOutput::GraphOutput::write2File`] writes the old format version 1.
+pub fn write_output(file_name: &str, graph: &Graph::Graph, positions: &[NodePos], highlight: &Highlight) -> std::io::Result<()> {
+    PlotData::PlotData::write(file_name, &GraphOutput::GraphOutput::plot(graph, positions, highlight, None))
+}
+
+/// Reads a data file written by [`write_output`] (or the application), e.g. to draw it with
+/// [`Scene`](crate::PlotScene::Scene).
+pub fn read_output(file_name: &str) -> Result<GraphOutput::Plot, PlotData::PlotDataError> {
+    PlotData::PlotData::read(file_name)
+}
+
+/// Writes graph as GraphML file `file_name` with all its keys, adding the results given in `annotations` as generated keys.
+///
+/// Distances, predecessors, the path to a destination and node positions are written as data of keys named
+/// [`GraphMLWriter::DISTANCE`] and so on. [`load_graph`] reads the file into the same graph again (including the
+/// generated keys).
+pub fn write_graphml(file_name: &str, graph: &Graph::Graph, annotations: &GraphMLWriter::Annotations) -> std::io::Result<()> {
+    GraphMLWriter::GraphMLWriter::write(file_name, graph, annotations)
+}
+
+/// Draws graph at the given positions as image `file_name` (PNG if the name ends with `.png`, SVG otherwise), marking nodes
+/// and edges according to `highlight`.
+///
+/// The `start` node is emphasized and named in the title. The image looks like the one `plot.py` creates from the data
+/// file of [`write_output`]; `size` gives its dimensions in inches and the resolution of PNG images.
+pub fn render_image(file_name: &str, graph: &Graph::Graph, positions: &[NodePos], highlight: &Highlight, start: NodeIndex, size: ImageSize) -> std::io::Result<()> {
+    let plot = GraphOutput::GraphOutput::plot(graph, positions, highlight, None);
+    let scene = PlotScene::Scene::build(&plot, Some(start.index() as u32), size);
+
+    if file_name.to_lowercase().ends_with(".png") {
+        PngRenderer::PngRenderer::write(file_name, &scene)
+    } else {
+        SvgRenderer::SvgRenderer::write(file_name, &scene)
+    }
+}
@@ -1,289 +1,466 @@
-mod Graph;
-mod Dijkstra;
-mod GraphML;
-mod GraphOutput;
-mod GraphPositioning;
-mod KeyCollection;
+mod CommandLine;
 
 // Standard library.
-use std::io::Write; // used for command line output
 use std::env; // environment - to get current path
-use std::fs; // file system manipulation
-
-use minidom::Element; // xml parser
+use std::process::ExitCode;
+use std::time::Instant; // measure runtime of algorithms
 
 // Own objects.
-use crate::Graph::node::Node;
-use crate::Graph::edge::Edge;
-use crate::Graph::graph_type::graph_enum::GraphType;
-use crate::Graph::{IgraphObject, Key};
-//use crate::GraphML::key_for::KeyFor::Node;
-use crate::KeyCollection::{collect_keys_for, AllScope, EdgeScope, NodeScope};
-
-const NS: &str = "http://graphml.graphdrawing.org/xmlns";
-
-// Macro to easily collect error messages in a vector.
-/* Explanation:
- * :expr - any expression (e.g. edge.attr("id")
- * :literal - string or number (e.g. "id")
- * :ident - identifier (not a string or an expression but a variable like bool)
- */
-macro_rules! get_attr {
-    ($attr:expr, $errtype:literal, $name:literal, $index:expr, $description:literal, $errors:expr, $invalid:ident) => {{
-        if $attr.is_none() {
-            $errors.push(format!(
-                "{}: {} ({}): {}",
-                $errtype, $name, $index, $description
-            ));
-            $invalid = true;
-        }
-        "" // dummy string return value so it can be used in expression context
-    }};
+use dijkstra_plot::{AllPairs, AStar, BellmanFord, BidirectionalDijkstra, Dijkstra, GraphMLWriter, GraphOutput, GraphPositioning, GraphStats, PngRenderer, ShortestPathDag, SvgRenderer, Yen};
+use dijkstra_plot::Graph::{EdgeIndex, Graph, IgraphObject, NodeIndex};
+use dijkstra_plot::GraphDocument::{GraphDocument, ReadOptions};
+use dijkstra_plot::GraphMLWriter::Annotations;
+use dijkstra_plot::PriorityQueue::QueueKind;
+use dijkstra_plot::Dijkstra::DijkstraPath;
+use dijkstra_plot::AllPairs::{AllPairsAlgorithm, AllPairsResult};
+use dijkstra_plot::AStar::HeuristicKind;
+use dijkstra_plot::BellmanFord::BellmanFordResult;
+use dijkstra_plot::AStar::heuristic::Zero;
+use dijkstra_plot::GraphOutput::Highlight;
+use dijkstra_plot::PlotData::PlotData;
+use dijkstra_plot::PlotScene::{ImageSize, Scene};
+use dijkstra_plot::Diagnostic::{DiagnosticCode, Severity};
+use CommandLine::{Arguments, Command, ConvertFormat, ImageFormat, Invocation};
+
+// Exit codes of the application (0 means success), so scripts like run.sh can tell what went wrong:
+//   2 usage error: missing or invalid parameters (e.g. unknown start node)
+//   3 I/O error: input file can't be read or an output file can't be written
+//   4 parse error: input isn't XML or contains no graph
+//   5 validation error: --strict was given (or command is validate) and elements of the file were rejected
+//   6 unreachable destination: there's no path from start to destination node (output is written anyway)
+#[derive(Clone, Copy, Debug)]
+enum Failure {
+    Usage = 2,
+    Io = 3,
+    Parse = 4,
+    Validation = 5,
+    Unreachable = 6,
 }
 
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
 
-// Helper functions
-fn findNode<'a>(nodes: &'a [Node], id: &str) -> Option<&'a Node> {
-    nodes.iter().find(|node| node.get_id() == id)
+    let arguments = match Arguments::parse(&args) {
+        Ok(Invocation::Run(arguments)) => arguments,
+        Ok(Invocation::Help(text)) => {
+            println!("{}", text);
+            return ExitCode::SUCCESS;
+        }
+        Ok(Invocation::Version) => {
+            println!("dijkstra-plot {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::from(Failure::Usage as u8);
+        }
+    };
+
+    let result = match arguments.command {
+        Command::Route | Command::Render => route(&arguments),
+        Command::Layout => layout(&arguments),
+        Command::Convert => convert(&arguments),
+        Command::Validate => validate(&arguments),
+        Command::Stats => stats(&arguments),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => ExitCode::from(failure as u8),
+    }
 }
 
+// Reads the GraphML file given by --input (problems within the file are left in the document).
+fn load(arguments: &Arguments) -> Result<(&str, GraphDocument), Failure> {
+    let input = arguments.get("input").unwrap_or_default();
+    let options = ReadOptions {
+        weight_key: arguments.get("weight-key").map(str::to_string),
+        unit_weight: arguments.value("unit-weight").map_err(usage_failure)?.unwrap_or(1),
+    };
 
-// Parsing functions
-fn parseKey() -> Option<Key> {
-    None // TODO: Has to be implemented yet! See KeyCollection.rs !
-    /*
-        use minidom::Element;
-    
-    // Angenommen, das hier ist dein Wurzel-Element:
-    let root: Element = parse_graphml()?;
-    
-    // Sammle alle <key>-Elemente
-    let keys: Vec<Element> = root
-        .children()
-        .filter(|e| e.name() == "key")
-        .cloned()
-        .collect();
-    
-    // Jetzt je nach Scope aufrufen
-    let node_keys = collect_keys_for::<NodeScope>(&keys);
-    let edge_keys = collect_keys_for::<EdgeScope>(&keys);
-    let graph_keys = collect_keys_for::<GraphScope>(&keys);
-
-     */
+    // Give some fundamental information just to exclude common mistakes.
+    if let Ok(dir) = std::env::current_dir() {
+        println!("Current dir: {}", dir.display());
+    }
+    println!("Trying to read: '{}'", input);
+
+    // Read in the file and parse it as GraphML.
+    match GraphDocument::load_with(input, &options) {
+        Ok(document) => Ok((input, document)),
+        Err(diagnostic) => {
+            eprintln!("{}", diagnostic.render(input));
+            Err(if diagnostic.code == DiagnosticCode::Io { Failure::Io } else { Failure::Parse })
+        }
+    }
 }
 
-fn assign_key_for_node(graph_keys: &[Key], node_keys: &[Key]) -> Vec<Key> {
-    let mut keys = Vec::new();
-    keys.extend_from_slice(graph_keys);
-    keys.extend_from_slice(node_keys);
-    keys
-}
+// Reads the graph and prints all problems found in the file. With --strict any rejected element is a failure.
+fn read_graph(arguments: &Arguments) -> Result<Graph, Failure> {
+    let (input, document) = load(arguments)?;
 
-fn assign_key_for_edge(graph_keys: &[Key], edge_keys: &[Key]) -> Vec<Key> {
-    let mut keys = Vec::new();
-    keys.extend_from_slice(edge_keys);
-    keys.extend_from_slice(graph_keys);
-    keys
-}
+    // Elements that were rejected (or values that were ignored) are reported as warnings.
+    let rejected = document.diagnostics_at_least(Severity::Warning).count();
 
-fn assign_key_for_graph(graph_keys: &[Key]) -> Vec<Key> {
-    let mut keys = Vec::new();
-    keys.extend_from_slice(graph_keys);
-    keys
+    let GraphDocument { graph, diagnostics } = document;
+
+    // Print out all problems found in the file (like compiler messages, with the line they refer to):
+    for diagnostic in diagnostics.iter() {
+        eprintln!("{}\n", diagnostic.render(input));
+    }
+
+    // Best effort is default: Rejected elements are left out and the rest of the graph is used.
+    if arguments.flag("strict") && rejected > 0 {
+        eprintln!("Strict mode: {} problem(s) found in {}, nothing is computed.", rejected, input);
+        return Err(Failure::Validation);
+    }
+
+    Ok(graph)
 }
 
-fn parseNode(node: &Element, index: usize, errors: &mut Vec<String>, graph_keys: &[Key], node_keys: &[Key]) -> Option<Node> {
-    // Read and convert attribute once
-    let id_raw: Option<&str> = node.attr("id");
-    
-    if id_raw.is_none() {
-        errors.push(format!("Missing or invalid id for node at index {}", index));
-        None
+// Runs the shortest-path algorithms selected by the options and writes the result as plot data (route) or draws it (render).
+fn route(arguments: &Arguments) -> Result<(), Failure> {
+    let graph = read_graph(arguments)?;
+    let output = arguments.get("output").unwrap_or_default();
+
+    // Image options are checked before any computation starts (route has none).
+    let (format, size) = if arguments.command == Command::Render {
+        let format = arguments.value("format").map_err(usage_failure)?.unwrap_or_else(|| ImageFormat::of_file(output));
+        let mut size: ImageSize = arguments.value("size").map_err(usage_failure)?.unwrap_or_default();
+        size.dpi = arguments.value("dpi").map_err(usage_failure)?.unwrap_or(size.dpi);
+        if !(size.dpi > 0.0 && size.dpi.is_finite()) {
+            return Err(usage_failure(format!("invalid resolution: {} (expected pixels per inch, e.g. 100)", size.dpi)));
+        }
+        (format, size)
     } else {
-        Some(Node::new(id_raw.unwrap().to_string(), assign_key_for_node(graph_keys, node_keys), index as u32))
+        (ImageFormat::Svg, ImageSize::default())
+    };
+
+    // All-pairs shortest paths don't need a start node.
+    if let Some(algorithm) = arguments.value::<AllPairsAlgorithm>("all-pairs").map_err(usage_failure)? {
+        let algorithm = AllPairs::AllPairs::resolve(&graph, algorithm);
+
+        let timer = Instant::now();
+        let all_pairs_result = AllPairs::AllPairs::run(&graph, algorithm);
+        println!("All-pairs shortest paths ({:?}) finished after {:?}", algorithm, timer.elapsed());
+
+        match all_pairs_result {
+            AllPairsResult::Distances(matrix) => {
+                GraphOutput::GraphOutput::write_matrix_csv(arguments.get("matrix").unwrap_or_default(), &graph, &matrix).map_err(io_failure)?;
+                if let Some(heatmap_file) = arguments.get("heatmap") {
+                    GraphOutput::GraphOutput::write_heatmap(heatmap_file, &graph, &matrix).map_err(io_failure)?;
+                }
+            }
+            AllPairsResult::NegativeCycle(cycle) => BellmanFord::BellmanFord::print_cycle(&graph, &cycle),
+        }
+
+        // Without start node there's nothing more to do.
+        if arguments.get("start").is_none() {
+            return Ok(());
+        }
     }
 
-    // TODO: Here the get_attr! Macro must be implemented to make visible if an information is missing or invalid !
-}
+    let start_id: Option<&str> = arguments.get("start");
+    let start: Option<NodeIndex> = start_id.and_then(|s| graph.node_index(s));
+    // An empty destination (e.g. run.sh without input) means dijkstra is performed for complete graph.
+    let dest_id: Option<&str> = arguments.get("dest").filter(|d| !d.is_empty());
+    let dest: Option<NodeIndex> = dest_id.and_then(|d| graph.node_index(d));
 
-fn parseEdge<'a>(edge: &Element, nodes: &'a [Node], index: usize, errors: &mut Vec<String>, graph_keys: &[Key], edge_keys: &[Key]) -> Option<Edge<'a>> {
-    // TODO: parseEdge does not yet support the read-in of the default nodes for keys within the edge nodes.
+    if start.is_none() {
+        match start_id {
+            Some(id) => eprintln!("Start node {} does not exist!", id),
+            None => eprintln!("Missing start node (--start)"),
+        }
+        return Err(Failure::Usage);
+    }
 
-    // Read and convert attributes once
-    let id_raw = edge.attr("id");
-    let kind_raw = edge.attr("directed");
-    let source_raw = edge.attr("source");
-    let target_raw = edge.attr("target");
-    let weight_raw = edge.attr("weight");
+    if let Some(id) = dest_id {
+        if dest.is_none() {
+            eprintln!("Destination node {} does not exist!", id);
+            return Err(Failure::Usage);
+        }
+    }
 
-    // Extract parsed values
-    let kind = kind_raw.and_then(|k| k.parse::<GraphType>().ok());
-    let source = source_raw.and_then(|k| findNode(nodes, k));
-    let target = target_raw.and_then(|k| findNode(nodes, k));
-    let weight = weight_raw.and_then(|w| w.parse::<u32>().ok());
+    // Edges of a negative cycle (if there's one, no cheapest paths exist).
+    let mut negative_cycle: Option<Vec<EdgeIndex>> = None;
+    // Alternative paths to destination ordered by rank.
+    let mut ranked_paths: Option<Vec<DijkstraPath>> = None;
+    // All equal-cost shortest paths (if requested).
+    let mut dag: Option<ShortestPathDag::ShortestPathDag> = None;
+
+    let (result, path) = if Dijkstra::Dijkstra::has_negative_weights(&graph) {
+        // Dijkstra algorithm (and A*) can't handle negative weights, Bellman-Ford is chosen instead. Options of the other
+        // algorithms would be ignored then, so they are refused instead.
+        let ignored: Vec<&str> = ["k-shortest", "astar", "ties", "enumerate", "queue"]
+            .into_iter()
+            .filter(|&name| arguments.get(name).is_some() || arguments.flag(name))
+            .collect();
+        if !ignored.is_empty() {
+            let options: Vec<String> = ignored.iter().map(|name| format!("--{}", name)).collect();
+            eprintln!("Graph contains negative weights which only Bellman-Ford can handle: {} can't be used.", options.join(", "));
+            return Err(Failure::Usage);
+        }
 
-    let mut has_error = false;
+        println!("Graph contains negative weights: Bellman-Ford is used instead of Dijkstra.");
 
-    // Use id for context, or fallback to index if missing
-    let id_for_error = id_raw.unwrap_or("<missing>");
+        let timer = Instant::now();
+        let bellman_ford_result = BellmanFord::BellmanFord::run(&graph, start.unwrap());
+        println!("Bellman-Ford finished after {:?}", timer.elapsed());
 
-    if id_raw.is_none() {
-        errors.push(format!("Missing id for edge at index {}", index));
-        has_error = true;
-    }
-    if kind.is_none() {
-        errors.push(format!("Invalid or missing 'directed' attribute in edge {}", id_for_error));
-        has_error = true;
-    }
-    if source.is_none() {
-        errors.push(format!("Invalid or missing 'source' node in edge {}", id_for_error));
-        has_error = true;
-    }
-    if target.is_none() {
-        errors.push(format!("Invalid or missing 'target' node in edge {}", id_for_error));
-        has_error = true;
-    }
-    if weight.is_none() {
-        errors.push(format!("Invalid or missing 'weight' value in edge {}", id_for_error));
-        has_error = true;
-    }
+        match bellman_ford_result {
+            BellmanFordResult::Paths(result) => {
+                let path = dest.map(|d| Dijkstra::Dijkstra::path_to(&graph, &result, d));
+                (Some(result), path)
+            }
+            BellmanFordResult::NegativeCycle(cycle) => {
+                BellmanFord::BellmanFord::print_cycle(&graph, &cycle);
+                negative_cycle = Some(cycle);
+                (None, None)
+            }
+        }
+    } else if let Some(k) = arguments.value::<usize>("k-shortest").map_err(usage_failure)? {
+        // Yen's algorithm needs a destination to which alternatives are searched.
+        let Some(d) = dest else {
+            eprintln!("K shortest paths require a destination node!");
+            return Err(Failure::Usage);
+        };
+
+        let timer = Instant::now();
+        let paths = Yen::Yen::run(&graph, start.unwrap(), d, k).map_err(usage_failure)?;
+        println!("Yen's algorithm finished after {:?}", timer.elapsed());
+        Yen::Yen::print_paths(&graph, start.unwrap(), d, &paths);
+
+        let best = paths.first().cloned().unwrap_or(DijkstraPath::Unreachable);
+        ranked_paths = Some(paths);
+
+        (None, Some(best))
+    } else if arguments.flag("ties") || arguments.get("enumerate").is_some() {
+        let limit = arguments.value::<usize>("enumerate").map_err(usage_failure)?;
+
+        let timer = Instant::now();
+        let full_dag = ShortestPathDag::ShortestPathDag::build(&graph, start.unwrap()).map_err(usage_failure)?;
+        println!("Shortest-path DAG finished after {:?}", timer.elapsed());
+
+        // With a destination only the part of the DAG leading to it is of interest.
+        let shortest_path_dag = match dest {
+            Some(d) => {
+                let restricted = full_dag.restricted_to(d);
+                println!("{} shortest path(s) from {} to {}", restricted.count(d), graph.node(start.unwrap()).get_id(), graph.node(d).get_id());
+                restricted
+            }
+            None => {
+                full_dag.print_counts(&graph);
+                full_dag
+            }
+        };
+
+        if let Some(limit) = limit {
+            let Some(d) = dest else {
+                eprintln!("Enumerating shortest paths requires a destination node!");
+                return Err(Failure::Usage);
+            };
 
-    if has_error {
-        None
+            let paths = shortest_path_dag.paths_to(d, limit);
+            Yen::Yen::print_paths(&graph, start.unwrap(), d, &paths);
+            ranked_paths = Some(paths);
+        }
+
+        dag = Some(shortest_path_dag);
+        (None, None)
+    } else if let Some(kind) = arguments.value::<HeuristicKind>("astar").map_err(usage_failure)? {
+        // A* needs a destination to estimate remaining costs to.
+        let Some(d) = dest else {
+            eprintln!("A* search requires a destination node!");
+            return Err(Failure::Usage);
+        };
+
+        let scale: f64 = arguments.value("heuristic-scale").map_err(usage_failure)?.unwrap_or(1.0);
+        let (default_x, default_y) = kind.default_keys();
+        let keys = (arguments.get("x-key").unwrap_or(default_x), arguments.get("y-key").unwrap_or(default_y));
+
+        let timer = Instant::now();
+        let astar_result = AStar::AStar::run(&graph, start.unwrap(), d, kind.create(scale).as_ref(), keys).map_err(usage_failure)?;
+        println!("A* ({:?} heuristic) finished after {:?}", kind, timer.elapsed());
+
+        // Plain dijkstra algorithm (stopped at destination) is A* without any estimation.
+        let dijkstra_result = AStar::AStar::run(&graph, start.unwrap(), d, &Zero, keys).map_err(usage_failure)?;
+        println!("Expanded nodes: {} (A*) vs. {} (Dijkstra)", astar_result.expanded, dijkstra_result.expanded);
+
+        (None, Some(astar_result.path))
+    } else if let (Some(d), None) = (dest, arguments.get("queue")) {
+        // Point-to-point query: Search from both ends so that not the whole graph has to be settled.
+        // (A queue given explicitly requests a full dijkstra run, e.g. to benchmark the queues.)
+        let timer = Instant::now();
+        let bidirectional_result = BidirectionalDijkstra::BidirectionalDijkstra::run(&graph, start.unwrap(), d).map_err(usage_failure)?;
+        println!("Bidirectional Dijkstra finished after {:?} (expanded nodes: {})", timer.elapsed(), bidirectional_result.expanded);
+
+        (None, Some(bidirectional_result.path))
     } else {
-        Some(Edge::new(
-            id_raw.unwrap().to_string(),
-            weight.unwrap(),
-            kind.unwrap(),
-            source.unwrap(),
-            target.unwrap(),
-            assign_key_for_edge(graph_keys, edge_keys),
-        ))
+        // Priority queue behind dijkstra algorithm (binary heap if not specified otherwise).
+        let queue: QueueKind = arguments.value("queue").map_err(usage_failure)?.unwrap_or(QueueKind::Binary);
+
+        // Dial's buckets are allocated for each possible weight so they are only suitable for small weights.
+        let timer = Instant::now();
+        let result = Dijkstra::Dijkstra::run_using(&graph, start.unwrap(), queue).map_err(usage_failure)?;
+        println!("Dijkstra ({:?} queue) finished after {:?}", queue, timer.elapsed());
+
+        // If a destination is given, only the path to it is of interest.
+        let path = dest.map(|d| Dijkstra::Dijkstra::path_to(&graph, &result, d));
+
+        (Some(result), path)
+    };
+
+    if let (Some(d), Some(p)) = (dest, path.as_ref()) {
+        Dijkstra::Dijkstra::print_path(&graph, start.unwrap(), d, p);
     }
-}
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    // All possible runtime parameters.
-    let mut input: Option<&str> = None;
-    let mut output: Option<&str> = None;
-    let mut start:Option<&str> = None;
-    let mut dest:Option<&str> = None;
-
-    // Read in passed parameters.
-    for arg in args.iter() {
-        // strip_prefix removes the given part at the beginning of a string slice.
-        if let Some(val) = arg.strip_prefix("-input=") {
-            input = Some(val); // GraphMl input path
-        } else if let Some(val) = arg.strip_prefix("-output=") {
-            output = Some(val); // Output file
-        } else if let Some(val) = arg.strip_prefix("-start=") {
-            start = Some(val); // Start node
-        } else if let Some(val) = arg.strip_prefix("-dest=") {
-            dest = Some(val); // Destination node
+    let opt = GraphPositioning::GraphOptimization::run(&graph, start.unwrap());
+
+    // Negative cycle has priority, afterwards the most specific result is marked.
+    let highlight = if let Some(cycle) = negative_cycle.as_ref() {
+        Highlight::NegativeCycle(cycle)
+    } else if let Some(dag) = dag.as_ref() {
+        Highlight::Dag(dag)
+    } else if let Some(path) = path.as_ref() {
+        Highlight::Path(path)
+    } else if let Some(result) = result.as_ref() {
+        Highlight::Tree(result)
+    } else {
+        Highlight::None
+    };
+
+    // Start and destination are known even if the highlighted result doesn't tell them (e.g. unreachable destination).
+    let mut plot = GraphOutput::GraphOutput::plot(&graph, &opt, &highlight, ranked_paths.as_ref());
+    plot.start = start.map(|s| s.index() as u32);
+    plot.dest = dest.map(|d| d.index() as u32).or(plot.dest);
+
+    if arguments.command == Command::Render {
+        let scene = Scene::build(&plot, plot.start, size);
+        match format {
+            ImageFormat::Svg => SvgRenderer::SvgRenderer::write(output, &scene),
+            ImageFormat::Png => PngRenderer::PngRenderer::write(output, &scene),
+        }
+        .map_err(io_failure)?;
+        println!("Plot was saved at {}", output);
+    } else {
+        if arguments.flag("data-v1") {
+            GraphOutput::GraphOutput::write2File(output.to_string(), &graph, &opt, &highlight, ranked_paths.as_ref()).map_err(io_failure)?;
+        } else {
+            PlotData::write(output, &plot).map_err(io_failure)?;
         }
+        println!("Graph success!");
     }
 
-    //let input = input.unwrap_or("Graph.xml");
-    //let output = output.unwrap_or("Graph.dat");
-    //let start = start.unwrap_or("A");
-    //let dest = dest.unwrap_or(""); // no dest graph: dijkstra is performed for complete graph
-    // TODO: std::process::exit(1) bedeutet, dass das Programm mit einem Fehler beendet wurde. Dieses also bei ungültigen Parametern etc. zurückgeben. Das Bash Skript fängt das auf und kann dann entscheiden ob weiter gemacht werden soll
+    // Distances and predecessors are written for all nodes, so a point-to-point search is completed by a full dijkstra run.
+    if let Some(graphml_file) = arguments.get("graphml") {
+        let full_result = match (result.as_ref(), negative_cycle.as_ref()) {
+            (None, None) => Some(Dijkstra::Dijkstra::run(&graph, start.unwrap()).map_err(usage_failure)?),
+            _ => None,
+        };
+        let annotations = Annotations {
+            paths: result.as_deref().or(full_result.as_deref()),
+            path: path.as_ref(),
+            positions: Some(&opt),
+        };
+        GraphMLWriter::GraphMLWriter::write(graphml_file, &graph, &annotations).map_err(io_failure)?;
+        println!("GraphML was saved at {}", graphml_file);
+    }
 
-    // Give some fundamental information just to exclude common mistakes.
-    println!("Current dir: {}", std::env::current_dir().unwrap().display());
-    println!("Trying to read: '{}'", input.unwrap());
-
-    // Read in the file ...
-    let xml_str = fs::read_to_string(input.unwrap()).expect("Something went wrong reading the file");
-    // ... and parse it as XML.
-    let root: Element = xml_str.parse().expect("Failed to parse XML");
-
-    // Containers to store the graph elements.
-    let mut nodes: Vec<Node> = Vec::new(); // stores all nodes
-    let mut edges: Vec<Edge> = Vec::new(); // stores all edges with references to nodes
-
-    let mut errors: Vec<String> = Vec::new(); // contains all error messages that occur
-
-    let mut invalid = false; // indicates if a parsing error occurred
-    
-    // Get the graph element (no root node!).
-    let graph: Option<&Element> = root.get_child("graph", NS);
-    
-    if graph.is_none() {
-        //errors.push("No graph element".to_string());
-        get_attr!(graph, "Err", "graph", 0, "no graph element", errors, invalid);
-        return; // at this point it makes no sense to go further. // TODO: Think about a solution to make this error also visible to the user even if the program stops here !
+    // Output shows the graph anyway, but scripts should know that there's no path.
+    if let Some(DijkstraPath::Unreachable) = path {
+        return Err(Failure::Unreachable);
     }
 
-    let graph = graph.unwrap(); // At this point a graph element exists !
-    let attr = graph.attr("id");
-    get_attr!(attr, "Warn", "graphId", 0, "Missing graph id", errors, invalid);
-    let graphId = attr.unwrap_or("unknown").to_string();
+    Ok(())
+}
 
+// Positions the nodes around --start (or the first node) and writes them as plot data without marking anything.
+fn layout(arguments: &Arguments) -> Result<(), Failure> {
+    let graph = read_graph(arguments)?;
 
-    // Iterate over node elements.
-    /*for (index, node) in graph.children().filter(|e: &&Element | e.name().to_lowercase() == "node").enumerate() {
-        match parseNode(node, index, &mut errors) {
-            Some(node) => {
-                nodes.push(node);
-            }
+    let start = match arguments.get("start") {
+        Some(id) => match graph.node_index(id) {
+            Some(start) => Some(start),
             None => {
-                continue
+                eprintln!("Start node {} does not exist!", id);
+                return Err(Failure::Usage);
             }
-        }
-    }*/
-
-    let key_elements: Vec<Element> = root.children()
-        .filter(|e| e.name() == "key" && e.ns() == NS)
-        .cloned()
-        .collect();
-    let keysForEdges = collect_keys_for::<EdgeScope>(&key_elements);
-    let keysForNodes = collect_keys_for::<NodeScope>(&key_elements);
-    let keysForAll = collect_keys_for::<AllScope>(&key_elements);
-
-    for (_, node) in graph.children()
-        .filter(|e: &&Element | e.name().eq_ignore_ascii_case("node")) // 1. filters only elements with name equal to "node" and returns bool [(Auto-Dereferencing! e.name() means: (**e).names())]
-        .enumerate() // 2. enumerates all filtered elements and provides (index: usize, node: &Element)
-        .filter_map(|(index , node)| {
-            parseNode(node, index, &mut errors, &keysForAll, &keysForNodes) // 4. after parseNode() and map() are executed on every piece filter_map removes all Nones and returns the Some values, resulting the (_, node: Node) iterator, used in the for-loop
-                .map(|n| (index, n)) // 3. map takes the result from parseNode (Option<Node>) and turns it into Option<(index, Node)> receives Option<Node> from filter_map and converts it to Option<(index, Node)>, needed for for-loop structure
-        }) // map returns Option<Node> and filter_map returns an iterator consisting of Node thats why node in the for-loop is of type Node and not &Element !
-    {
-        nodes.push(node); // 5. node is of type Node here (not Option!) because filter_map unwraps the Some(...)
+        },
+        None => graph.node_indices().next(),
+    };
+
+    // An empty graph has nothing to position.
+    let positions = start.map_or(Vec::new(), |start| GraphPositioning::GraphOptimization::run(&graph, start));
+
+    let output = arguments.get("output").unwrap_or_default();
+    if arguments.flag("data-v1") {
+        GraphOutput::GraphOutput::write2File(output.to_string(), &graph, &positions, &Highlight::None, None).map_err(io_failure)?;
+    } else {
+        let mut plot = GraphOutput::GraphOutput::plot(&graph, &positions, &Highlight::None, None);
+        plot.start = start.map(|s| s.index() as u32);
+        PlotData::write(output, &plot).map_err(io_failure)?;
     }
 
-    //for (index, edge) in graph.unwrap().children().filter()
+    println!("Layout success!");
+    Ok(())
+}
+
+// Writes the graph in another format (see ConvertFormat).
+fn convert(arguments: &Arguments) -> Result<(), Failure> {
+    let format: ConvertFormat = arguments.value("to").map_err(usage_failure)?.unwrap_or(ConvertFormat::Edges);
+    let graph = read_graph(arguments)?;
+    let output = arguments.get("output").unwrap_or_default();
 
-    for (_, edge) in graph.children()
-        .filter(|e: &&Element | e.name().eq_ignore_ascii_case("edge"))
-        .enumerate()
-        .filter_map(|(index, edge)| parseEdge(edge, &nodes, index, &mut errors, &keysForAll, &keysForEdges).map(|e| (index, e))) {
-        edges.push(edge)
+    match format {
+        ConvertFormat::Edges => GraphOutput::GraphOutput::write_edge_list_csv(output, &graph),
+        ConvertFormat::Matrix => GraphOutput::GraphOutput::write_matrix_csv(output, &graph, &graph.get_weight_matrix()),
+        ConvertFormat::GraphML => GraphMLWriter::GraphMLWriter::write(output, &graph, &Annotations::default()),
     }
+    .map_err(io_failure)?;
 
-    
-    // Print out all gathered error messages:
-    println!("Errors: {}", errors.join("\n")); // join() connects all elements in the vector to one single string seperated through new lines
+    println!("Written to {}", output);
+    Ok(())
+}
 
-    let start: Option<&Node> = nodes.iter().find(|n: &&Node |n.get_id() == start.unwrap());
-    let dest: Option<&Node> = nodes.iter().find(|n: &&Node |n.get_id() == dest.unwrap());
+// Prints all problems found in the file: Fails (validation error) if any element was rejected or value was ignored.
+fn validate(arguments: &Arguments) -> Result<(), Failure> {
+    let (input, document) = load(arguments)?;
 
-    if start.is_none() {
-        errors.push(format!("Missing start node"));
-        return;
+    for diagnostic in document.diagnostics.iter() {
+        eprintln!("{}\n", diagnostic.render(input));
     }
 
+    let count = |severity: Severity| document.diagnostics.iter().filter(|d| d.severity == severity).count();
+    let (warnings, infos) = (count(Severity::Warning), count(Severity::Info));
 
-    let graph = Graph::Graph::new(String::from(graphId), nodes.clone(), edges.clone(), Vec::new());
+    println!(
+        "{}: {} nodes, {} edges, {} warning(s), {} info(s)",
+        input,
+        document.graph.node_len(),
+        document.graph.edge_len(),
+        warnings,
+        infos
+    );
 
-    let result = Dijkstra::Dijkstra::run(&graph, start.unwrap());
+    if warnings > 0 {
+        return Err(Failure::Validation);
+    }
 
-    let opt = GraphPositioning::GraphOptimization::run(&graph, start.unwrap());
+    Ok(())
+}
+
+fn stats(arguments: &Arguments) -> Result<(), Failure> {
+    let graph = read_graph(arguments)?;
 
-    GraphOutput::GraphOutput::write2File(output.unwrap().to_string(), &graph, &opt, Some(&result));
+    println!("{}", GraphStats::GraphStats::of(&graph));
+    Ok(())
+}
 
-    println!("Graph success!");
+fn usage_failure(message: String) -> Failure {
+    eprintln!("{}", message);
+    Failure::Usage
+}
 
+fn io_failure(err: std::io::Error) -> Failure {
+    eprintln!("{}", err);
+    Failure::Io
 }
@@ -0,0 +1,144 @@
+// Integration tests for all-pairs shortest paths: Floyd-Warshall and Johnson are compared with single source algorithms
+// run from every node, the distance matrix is written as CSV.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use dijkstra_plot::AllPairs::{AllPairs, AllPairsResult, DistanceMatrix};
+use dijkstra_plot::BellmanFord::{BellmanFord, BellmanFordResult};
+use dijkstra_plot::Dijkstra::{Dijkstra, DijkstraHeapEle};
+use dijkstra_plot::Graph::{Graph, IgraphObject};
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphOutput::GraphOutput;
+
+const TESTGRAPH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testgraph.xml");
+
+// Negative weights without negative cycles: The only cycle a -> b -> c -> a costs 2 - 3 + 4 = 3, the undirected edge
+// (weight 1) can't be used with a negative weight.
+const NEGATIVE_WEIGHTS: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="negative" edgedefault="directed">
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <node id="d"/>
+        <node id="e"/>
+        <node id="lonely"/>
+        <edge id="ab" source="a" target="b" directed="true" weight="2"/>
+        <edge id="bc" source="b" target="c" directed="true" weight="-3"/>
+        <edge id="ca" source="c" target="a" directed="true" weight="4"/>
+        <edge id="ad" source="a" target="d" directed="true" weight="-1"/>
+        <edge id="dc" source="d" target="c" directed="true" weight="-2"/>
+        <edge id="ce" source="c" target="e" directed="false" weight="1"/>
+    </graph>
+</graphml>"#;
+
+fn load(xml: &str) -> Graph {
+    let document = GraphDocument::parse(xml).unwrap();
+    assert!(document.diagnostics.is_empty(), "{:?}", document.diagnostics);
+    document.graph
+}
+
+// Random mixed graph with non-negative weights, some nodes can't be reached.
+fn random_graph(seed: u64) -> Graph {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let node_len = 25;
+
+    let mut xml = String::from(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="random" edgedefault="directed">"#);
+    for v in 0..node_len {
+        xml.push_str(&format!(r#"<node id="n{}"/>"#, v));
+    }
+    for i in 0..60 {
+        let (u, v) = (rng.gen_range(0..node_len), rng.gen_range(0..node_len));
+        let directed = rng.gen_bool(0.8);
+        xml.push_str(&format!(r#"<edge id="e{}" source="n{}" target="n{}" weight="{}" directed="{}"/>"#, i, u, v, rng.gen_range(0..20), directed));
+    }
+    xml.push_str("</graph></graphml>");
+
+    load(&xml)
+}
+
+fn distances(result: AllPairsResult) -> DistanceMatrix {
+    match result {
+        AllPairsResult::Distances(matrix) => matrix,
+        AllPairsResult::NegativeCycle(_) => panic!("graph has no negative cycle"),
+    }
+}
+
+// Row of the matrix from the costs of a single source run.
+fn row(graph: &Graph, paths: &[DijkstraHeapEle]) -> Vec<Option<i64>> {
+    let mut row = vec![None; graph.node_len()];
+    for path in paths.iter().filter(|path| path.is_reachable()) {
+        row[path.owner.index()] = Some(path.cost());
+    }
+    row
+}
+
+fn dijkstra_matrix(graph: &Graph) -> DistanceMatrix {
+    graph.node_indices().map(|start| row(graph, &Dijkstra::run(graph, start).unwrap())).collect()
+}
+
+#[test]
+fn floyd_warshall_and_johnson_match_dijkstra() {
+    let mut graphs = vec![dijkstra_plot::load_graph(TESTGRAPH).unwrap().graph];
+    graphs.extend((0..5).map(random_graph));
+
+    for graph in graphs.iter() {
+        let expected = dijkstra_matrix(graph);
+
+        assert_eq!(distances(AllPairs::floyd_warshall(graph)), expected, "floyd-warshall on {}", graph.get_id());
+        assert_eq!(distances(AllPairs::johnson(graph)), expected, "johnson on {}", graph.get_id());
+    }
+}
+
+#[test]
+fn johnson_handles_negative_weights() {
+    let graph = load(NEGATIVE_WEIGHTS);
+
+    let expected: DistanceMatrix = graph
+        .node_indices()
+        .map(|start| match BellmanFord::run(&graph, start) {
+            BellmanFordResult::Paths(paths) => row(&graph, &paths),
+            BellmanFordResult::NegativeCycle(_) => panic!("graph has no negative cycle"),
+        })
+        .collect();
+
+    let johnson = distances(AllPairs::johnson(&graph));
+    assert_eq!(johnson, expected);
+    assert_eq!(distances(AllPairs::floyd_warshall(&graph)), expected);
+
+    // a -> d -> c is cheaper than a -> b -> c, e is reached over the undirected edge.
+    let index = |id: &str| graph.node_index(id).unwrap().index();
+    assert_eq!(johnson[index("a")][index("c")], Some(-3));
+    assert_eq!(johnson[index("a")][index("e")], Some(-2));
+    assert_eq!(johnson[index("e")][index("a")], Some(5));
+    assert_eq!(johnson[index("a")][index("lonely")], None);
+}
+
+#[test]
+fn matrix_csv_quotes_special_ids() {
+    let graph = load(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="csv" edgedefault="directed">
+            <node id="a,b"/>
+            <node id="say &quot;hi&quot;"/>
+            <node id="two&#10;lines"/>
+            <edge id="e0" source="a,b" target="say &quot;hi&quot;" directed="true" weight="1"/>
+            <edge id="e1" source="say &quot;hi&quot;" target="two&#10;lines" directed="true" weight="2"/>
+        </graph></graphml>"#,
+    );
+    let file_name = std::env::temp_dir().join(format!("dijkstra-plot-matrix-{}.csv", std::process::id()));
+    let file_name = file_name.to_str().unwrap();
+
+    GraphOutput::write_matrix_csv(file_name, &graph, &distances(AllPairs::floyd_warshall(&graph))).unwrap();
+    let csv = std::fs::read_to_string(file_name).unwrap();
+    std::fs::remove_file(file_name).unwrap();
+
+    assert_eq!(
+        csv,
+        concat!(
+            ",\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\"\n",
+            "\"a,b\",0,1,3\n",
+            "\"say \"\"hi\"\"\",inf,0,2\n",
+            "\"two\nlines\",inf,inf,0\n",
+        )
+    );
+}
@@ -0,0 +1,98 @@
+// Integration tests for A* search with heuristics on node coordinates.
+
+use dijkstra_plot::AStar::heuristic::{Euclidean, Manhattan, Zero};
+use dijkstra_plot::AStar::{AStar, Heuristic};
+use dijkstra_plot::Dijkstra::{Dijkstra, DijkstraPath};
+use dijkstra_plot::Graph::{Graph, NodeIndex};
+use dijkstra_plot::GraphDocument::GraphDocument;
+
+const SIZE: usize = 10;
+
+// SIZE x SIZE grid with unit distance between neighbours: Node "x,y" lies at (x, y), edges have weight 1 (so both
+// heuristics are admissible with scale 1).
+fn grid() -> Graph {
+    let mut xml = String::from(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+        <key id="x" for="node" attr.name="x" attr.type="double"/>
+        <key id="y" for="node" attr.name="y" attr.type="double"/>
+        <graph id="grid" edgedefault="undirected">"#,
+    );
+    for x in 0..SIZE {
+        for y in 0..SIZE {
+            xml.push_str(&format!(r#"<node id="{x},{y}"><data key="x">{x}</data><data key="y">{y}</data></node>"#));
+        }
+    }
+    for x in 0..SIZE {
+        for y in 0..SIZE {
+            if x + 1 < SIZE {
+                xml.push_str(&format!(r#"<edge id="h{x},{y}" source="{},{y}" target="{},{y}" directed="false" weight="1"/>"#, x, x + 1));
+            }
+            if y + 1 < SIZE {
+                xml.push_str(&format!(r#"<edge id="v{x},{y}" source="{x},{}" target="{x},{}" directed="false" weight="1"/>"#, y, y + 1));
+            }
+        }
+    }
+    xml.push_str("</graph></graphml>");
+
+    let document = GraphDocument::parse(&xml).unwrap();
+    assert!(document.diagnostics.is_empty(), "{:?}", document.diagnostics);
+    document.graph
+}
+
+fn node(graph: &Graph, x: usize, y: usize) -> NodeIndex {
+    graph.node_index(&format!("{},{}", x, y)).unwrap()
+}
+
+fn cost(path: &DijkstraPath) -> i64 {
+    match path {
+        DijkstraPath::Reachable { cost, .. } => *cost,
+        DijkstraPath::Unreachable => panic!("destination should be reachable"),
+    }
+}
+
+#[test]
+fn heuristics_find_dijkstra_path_expanding_fewer_nodes() {
+    let graph = grid();
+    let (start, dest) = (node(&graph, 0, 5), node(&graph, SIZE - 1, 5));
+    let expected = Dijkstra::path_to(&graph, &Dijkstra::run(&graph, start).unwrap(), dest);
+
+    let plain = AStar::run(&graph, start, dest, &Zero, ("x", "y")).unwrap();
+    assert_eq!(cost(&plain.path), cost(&expected));
+
+    let heuristics: [(&str, &dyn Heuristic); 2] = [("euclidean", &Euclidean { scale: 1.0 }), ("manhattan", &Manhattan { scale: 1.0 })];
+    for (name, heuristic) in heuristics {
+        let result = AStar::run(&graph, start, dest, heuristic, ("x", "y")).unwrap();
+
+        assert_eq!(cost(&result.path), cost(&expected), "{}", name);
+        // The straight row is the only cheapest path.
+        if let (DijkstraPath::Reachable { edges, .. }, DijkstraPath::Reachable { edges: expected_edges, .. }) = (&result.path, &expected) {
+            assert_eq!(edges, expected_edges, "{}", name);
+        }
+        assert!(result.expanded < plain.expanded, "{}: {} expanded nodes, dijkstra {}", name, result.expanded, plain.expanded);
+    }
+}
+
+#[test]
+fn scale_keeps_estimation_admissible() {
+    let graph = grid();
+    let (start, dest) = (node(&graph, 0, 0), node(&graph, SIZE - 1, SIZE - 1));
+    let expected = Dijkstra::path_to(&graph, &Dijkstra::run(&graph, start).unwrap(), dest);
+
+    // Scaled down estimation is still exact, just less informed.
+    let result = AStar::run(&graph, start, dest, &Euclidean { scale: 0.5 }, ("x", "y")).unwrap();
+    assert_eq!(cost(&result.path), cost(&expected));
+}
+
+#[test]
+fn missing_coordinate_keys_are_an_error() {
+    let graph = grid();
+    let (start, dest) = (node(&graph, 0, 0), node(&graph, 3, 3));
+
+    let error = AStar::run(&graph, start, dest, &Euclidean { scale: 1.0 }, ("lat", "lon")).err().expect("keys don't exist");
+    assert!(error.contains("3,3") && error.contains("lat") && error.contains("lon"), "{}", error);
+    assert!(AStar::run(&graph, start, dest, &Manhattan { scale: 1.0 }, ("x", "lon")).is_err());
+
+    // Without estimation coordinates aren't needed.
+    let result = AStar::run(&graph, start, dest, &Zero, ("lat", "lon")).unwrap();
+    assert_eq!(cost(&result.path), 6);
+}
@@ -0,0 +1,123 @@
+// Integration tests for typed GraphML attribute values.
+
+use dijkstra_plot::Graph::key_type::key_enum::KeyType;
+use dijkstra_plot::Graph::{AttrValue, IgraphObject};
+use dijkstra_plot::GraphDocument::GraphDocument;
+
+const TYPED_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <key id="x" for="node" attr.name="x" attr.type="double"><default>0.5</default></key>
+    <key id="n" for="node" attr.name="level" attr.type="int"/>
+    <key id="b" for="node" attr.name="visited" attr.type="boolean"><default>false</default></key>
+    <key id="l" for="node" attr.name="label" attr.type="string"><default> spaced </default></key>
+    <key id="bad" for="node" attr.name="broken" attr.type="long"><default>many</default></key>
+    <graph id="typed">
+        <node id="a">
+            <data key="x">2</data>
+            <data key="n">-7</data>
+            <data key="b">1</data>
+        </node>
+        <node id="b">
+            <data key="n">abc</data>
+        </node>
+    </graph>
+</graphml>"#;
+
+#[test]
+fn converts_values_according_to_key_type() {
+    let GraphDocument { graph, .. } = GraphDocument::parse(TYPED_GRAPH).unwrap();
+    let a = graph.node(graph.node_index("a").unwrap());
+
+    assert_eq!(a.attr("x"), Some(&AttrValue::Double(2.0)));
+    assert_eq!(a.attr_f64("x"), Some(2.0));
+    assert_eq!(a.attr("level"), Some(&AttrValue::Int(-7)));
+    assert_eq!(a.attr_i64("level"), Some(-7));
+    assert_eq!(a.attr_f64("level"), Some(-7.0));
+    assert_eq!(a.attr_bool("visited"), Some(true));
+    // Strings are taken as they are, other accessors don't convert them.
+    assert_eq!(a.attr_str("label"), Some(" spaced "));
+    assert_eq!(a.attr_f64("label"), None);
+    assert_eq!(a.attr("missing"), None);
+}
+
+#[test]
+fn reports_values_that_dont_match_their_type() {
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse(TYPED_GRAPH).unwrap();
+    let b = graph.node(graph.node_index("b").unwrap());
+
+    // Invalid data keeps the default (int key has none), invalid default leaves key without value.
+    assert_eq!(b.attr("level"), None);
+    assert_eq!(b.attr_f64("x"), Some(0.5));
+    assert_eq!(b.attr("broken"), None);
+
+    assert_eq!(diagnostics.len(), 2, "{:?}", diagnostics);
+    assert!(diagnostics.iter().any(|d| d.message.contains("key bad") && d.message.contains("'many'")));
+    assert!(diagnostics.iter().any(|d| d.message.contains("node b") && d.message.contains("'abc'") && d.message.contains("int")));
+}
+
+#[test]
+fn written_values_can_be_parsed_again() {
+    let values = [
+        (AttrValue::Bool(false), KeyType::Boolean),
+        (AttrValue::Int(i32::MIN), KeyType::Int),
+        (AttrValue::Long(i64::MAX), KeyType::Long),
+        (AttrValue::Float(0.1), KeyType::Float),
+        (AttrValue::Double(-1e-300), KeyType::Double),
+        (AttrValue::String("a b".to_string()), KeyType::String),
+    ];
+
+    for (value, attrtype) in values.iter() {
+        assert_eq!(&AttrValue::parse(&value.to_string(), attrtype).unwrap(), value);
+        assert_eq!(attrtype.to_string().parse::<KeyType>().unwrap(), *attrtype);
+    }
+}
+
+#[test]
+fn data_overrides_defaults_of_all_scopes() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/testgraph.xml");
+    let GraphDocument { graph, diagnostics } = dijkstra_plot::load_graph(path).unwrap();
+    let node = |id: &str| graph.node(graph.node_index(id).unwrap());
+    let edge = |id: &str| graph.edges().iter().find(|edge| edge.get_id() == id).unwrap();
+
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert_eq!(node("A").attr_str("key_all"), Some("Nothing"));
+    assert_eq!(node("B").attr_str("key_all"), Some("Hello World"));
+    assert_eq!(edge("e0").attr_str("edge_0"), Some("yellow"));
+    assert_eq!(edge("e0").attr_str("key_all"), Some("Hello World"));
+    assert_eq!(edge("e1").attr_str("edge_0"), Some("green"));
+    // Edge keys don't apply to nodes.
+    assert_eq!(node("A").attr_str("edge_0"), None);
+}
+
+#[test]
+fn graph_keys_are_attached_to_the_graph() {
+    let xml = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+        <key id="g0" for="graph" attr.name="title" attr.type="string"/>
+        <key id="g1" for="graph" attr.name="year" attr.type="int"><default>2023</default></key>
+        <key id="n0" for="node" attr.name="color" attr.type="string"/>
+        <key id="any" attr.name="note" attr.type="string"><default>-</default></key>
+        <graph id="g" edgedefault="undirected">
+            <data key="g0">Roads</data>
+            <data key="n0">red</data>
+            <node id="a">
+                <data key="g0">wrong</data>
+                <data key="any">first</data>
+            </node>
+        </graph>
+    </graphml>"#;
+
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse(xml).unwrap();
+    let a = graph.node(graph.node_index("a").unwrap());
+
+    assert_eq!(graph.attr_str("title"), Some("Roads"));
+    assert_eq!(graph.attr_i64("year"), Some(2023));
+    assert_eq!(graph.attr_str("color"), None);
+    // Keys without for attribute apply to all elements.
+    assert_eq!(graph.attr_str("note"), Some("-"));
+    assert_eq!(a.attr_str("note"), Some("first"));
+    assert_eq!(a.attr_str("title"), None);
+
+    // Data of node key on graph and of graph key on node are reported.
+    assert_eq!(diagnostics.len(), 2, "{:?}", diagnostics);
+    assert!(diagnostics[0].message.contains("graph g") && diagnostics[0].message.contains("n0"));
+    assert!(diagnostics[1].message.contains("node a") && diagnostics[1].message.contains("g0"));
+}
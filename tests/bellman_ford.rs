@@ -0,0 +1,93 @@
+// Integration tests for Bellman-Ford algorithm on graphs with negative weights (which dijkstra based searches refuse).
+
+use dijkstra_plot::AStar::heuristic::Zero;
+use dijkstra_plot::AStar::AStar;
+use dijkstra_plot::BellmanFord::{BellmanFord, BellmanFordResult};
+use dijkstra_plot::BidirectionalDijkstra::BidirectionalDijkstra;
+use dijkstra_plot::Dijkstra::Dijkstra;
+use dijkstra_plot::Graph::{Graph, IgraphObject};
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::PriorityQueue::QueueKind;
+use dijkstra_plot::ShortestPathDag::ShortestPathDag;
+use dijkstra_plot::Yen::Yen;
+
+// b -> c -> d -> b has total weight 2 - 4 + 1 = -1, it's reachable from s over a.
+const NEGATIVE_CYCLE: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="cycle" edgedefault="directed">
+        <node id="s"/>
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <node id="d"/>
+        <node id="z"/>
+        <edge id="sa" source="s" target="a" directed="true" weight="3"/>
+        <edge id="ab" source="a" target="b" directed="true" weight="1"/>
+        <edge id="bc" source="b" target="c" directed="true" weight="2"/>
+        <edge id="cd" source="c" target="d" directed="true" weight="-4"/>
+        <edge id="db" source="d" target="b" directed="true" weight="1"/>
+        <edge id="dz" source="d" target="z" directed="true" weight="5"/>
+    </graph>
+</graphml>"#;
+
+fn load(xml: &str) -> Graph {
+    GraphDocument::parse(xml).unwrap().graph
+}
+
+#[test]
+fn finds_closed_negative_cycle() {
+    let graph = load(NEGATIVE_CYCLE);
+
+    let BellmanFordResult::NegativeCycle(cycle) = BellmanFord::run(&graph, graph.node_index("s").unwrap()) else {
+        panic!("negative cycle should be found");
+    };
+
+    // Each edge starts where the previous one ended and the last one leads back to the first.
+    assert!(!cycle.is_empty());
+    for (i, &e) in cycle.iter().enumerate() {
+        let next = cycle[(i + 1) % cycle.len()];
+        assert_eq!(graph.edge(e).dest(), graph.edge(next).source(), "cycle isn't closed after edge {}", graph.edge(e).get_id());
+    }
+    assert!(cycle.iter().map(|&e| graph.edge(e).weight()).sum::<i64>() < 0);
+
+    let mut ids: Vec<&str> = cycle.iter().map(|&e| graph.edge(e).get_id()).collect();
+    ids.sort();
+    assert_eq!(ids, ["bc", "cd", "db"]);
+}
+
+#[test]
+fn ignores_negative_cycle_that_cant_be_reached() {
+    let graph = load(NEGATIVE_CYCLE);
+
+    // Nothing leads from z back into the cycle.
+    let BellmanFordResult::Paths(result) = BellmanFord::run(&graph, graph.node_index("z").unwrap()) else {
+        panic!("cycle isn't reachable from z");
+    };
+    assert_eq!(result.iter().filter(|ele| ele.is_reachable()).count(), 1);
+
+    // Without the closing edge there's no cycle and negative weights shorten paths.
+    let graph = load(&NEGATIVE_CYCLE.replace(r#"<edge id="db" source="d" target="b" directed="true" weight="1"/>"#, ""));
+    let BellmanFordResult::Paths(result) = BellmanFord::run(&graph, graph.node_index("s").unwrap()) else {
+        panic!("there's no cycle anymore");
+    };
+    let z = graph.node_index("z").unwrap();
+    assert_eq!(result.iter().find(|ele| ele.owner == z).unwrap().cost(), 3 + 1 + 2 - 4 + 5);
+}
+
+#[test]
+fn dijkstra_based_searches_refuse_negative_weights() {
+    let graph = load(NEGATIVE_CYCLE);
+    let (s, z) = (graph.node_index("s").unwrap(), graph.node_index("z").unwrap());
+
+    let errors = [
+        Dijkstra::run(&graph, s).err(),
+        Dijkstra::run_using(&graph, s, QueueKind::Radix).err(),
+        AStar::run(&graph, s, z, &Zero, ("x", "y")).err(),
+        BidirectionalDijkstra::run(&graph, s, z).err(),
+        Yen::run(&graph, s, z, 2).err(),
+        ShortestPathDag::build(&graph, s).err(),
+    ];
+    for error in errors {
+        let error = error.expect("negative weights must not be cut off");
+        assert!(error.contains("cd") && error.contains("-4"), "{}", error);
+    }
+}
@@ -0,0 +1,58 @@
+// Integration tests for bidirectional dijkstra: Costs and reachability are compared with dijkstra algorithm for every
+// pair of nodes of random graphs.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use dijkstra_plot::BidirectionalDijkstra::BidirectionalDijkstra;
+use dijkstra_plot::Dijkstra::{Dijkstra, DijkstraPath};
+use dijkstra_plot::Graph::Graph;
+use dijkstra_plot::GraphDocument::GraphDocument;
+
+// Random graph with the given share of directed edges (the others are undirected). Few edges per node so that some
+// nodes can't be reached, zero weights and parallel edges are included.
+fn random_graph(seed: u64, directed_share: f64) -> Graph {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let node_len = 20;
+
+    let mut xml = String::from(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="random" edgedefault="directed">"#);
+    for v in 0..node_len {
+        xml.push_str(&format!(r#"<node id="n{}"/>"#, v));
+    }
+    for i in 0..35 {
+        let (u, v) = (rng.gen_range(0..node_len), rng.gen_range(0..node_len));
+        let directed = rng.gen_bool(directed_share);
+        xml.push_str(&format!(r#"<edge id="e{}" source="n{}" target="n{}" weight="{}" directed="{}"/>"#, i, u, v, rng.gen_range(0..10), directed));
+    }
+    xml.push_str("</graph></graphml>");
+
+    let document = GraphDocument::parse(&xml).unwrap();
+    assert!(document.diagnostics.is_empty(), "{:?}", document.diagnostics);
+    document.graph
+}
+
+fn cost(path: &DijkstraPath) -> Option<i64> {
+    match path {
+        DijkstraPath::Reachable { cost, .. } => Some(*cost),
+        DijkstraPath::Unreachable => None,
+    }
+}
+
+#[test]
+fn matches_dijkstra_on_random_graphs() {
+    for seed in 0..10 {
+        for directed_share in [1.0, 0.7] {
+            let graph = random_graph(seed, directed_share);
+
+            for start in graph.node_indices() {
+                let result = Dijkstra::run(&graph, start).unwrap();
+                for dest in graph.node_indices() {
+                    let expected = cost(&Dijkstra::path_to(&graph, &result, dest));
+                    let path = BidirectionalDijkstra::run(&graph, start, dest).unwrap().path;
+
+                    assert_eq!(cost(&path), expected, "seed {}, directed share {}: {} -> {}", seed, directed_share, start.index(), dest.index());
+                }
+            }
+        }
+    }
+}
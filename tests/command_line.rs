@@ -0,0 +1,660 @@
+// Integration tests for the subcommands of the command line application: Graphs are written into temporary GraphML
+// files, the application is run on them and its console output and data file are checked.
+
+use std::process::{Command, Output};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use dijkstra_plot::Graph::IgraphObject;
+use dijkstra_plot::PlotData::PlotData;
+
+const TESTGRAPH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testgraph.xml");
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_dijkstra-plot")).args(args).output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+fn temp_file(name: &str) -> String {
+    std::env::temp_dir().join(format!("dijkstra-plot-cli-{}-{}", std::process::id(), name)).to_str().unwrap().to_string()
+}
+
+#[test]
+fn prints_help_for_application_and_commands() {
+    let output = run(&["--help"]);
+    assert_eq!(output.status.code(), Some(0));
+    for command in ["route", "layout", "render", "convert", "validate", "stats"] {
+        assert!(stdout(&output).contains(command), "{} is missing in help", command);
+    }
+
+    let output = run(&["route", "--help"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).contains("--start <NODE>"));
+    assert!(stdout(&output).contains("[default: Graph.dat]"));
+
+    assert_eq!(stdout(&run(&["help", "stats"])), stdout(&run(&["stats", "--help"])));
+}
+
+#[test]
+fn rejects_invalid_command_lines() {
+    assert_eq!(run(&[]).status.code(), Some(2));
+    assert_eq!(run(&["plot"]).status.code(), Some(2));
+    assert_eq!(run(&["stats", "--input", TESTGRAPH, "--colour=red"]).status.code(), Some(2));
+    assert_eq!(run(&["stats", "--input", TESTGRAPH, "--input", TESTGRAPH]).status.code(), Some(2));
+    assert_eq!(run(&["stats", "--input"]).status.code(), Some(2));
+    assert_eq!(run(&["stats", "--strict=yes", "--input", TESTGRAPH]).status.code(), Some(2));
+    // Required option is missing.
+    assert_eq!(run(&["validate"]).status.code(), Some(2));
+}
+
+#[test]
+fn validates_and_describes_graph() {
+    let output = run(&["validate", "--input", TESTGRAPH]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).contains("11 nodes, 14 edges, 0 warning(s)"));
+
+    let output = run(&["stats", "--input", TESTGRAPH]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).contains("edges:            14 (0 directed, 14 undirected)"));
+
+    let faulty = temp_file("faulty.xml");
+    std::fs::write(
+        &faulty,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="g"><node id="a"/><edge source="a" target="z"/></graph></graphml>"#,
+    )
+    .unwrap();
+    assert_eq!(run(&["validate", "--input", &faulty]).status.code(), Some(5));
+    std::fs::remove_file(faulty).unwrap();
+}
+
+#[test]
+fn converts_and_lays_out_graph() {
+    let edges = temp_file("edges.csv");
+    assert_eq!(run(&["convert", "--input", TESTGRAPH, "--output", &edges]).status.code(), Some(0));
+    let content = std::fs::read_to_string(&edges).unwrap();
+    std::fs::remove_file(&edges).unwrap();
+    assert_eq!(content.lines().next(), Some("id,source,target,weight,directed"));
+    assert_eq!(content.lines().count(), 15);
+
+    assert_eq!(run(&["convert", "--input", TESTGRAPH, "--output", &edges, "--to=gexf"]).status.code(), Some(2));
+
+    let data = temp_file("Graph.dat");
+    assert_eq!(run(&["layout", "--input", TESTGRAPH, "--output", &data, "--start=C"]).status.code(), Some(0));
+    let plot = dijkstra_plot::read_output(&data).unwrap();
+    // Nothing is marked.
+    assert_eq!(plot.nodes.len(), 11);
+    assert_eq!(plot.start.map(|no| plot.nodes[no as usize].id.as_str()), Some("C"));
+    assert!(plot.nodes.iter().all(|node| !node.marked && node.cost.is_none()) && plot.edges.iter().all(|edge| !edge.marked));
+
+    // Old format has no header.
+    assert_eq!(run(&["layout", "--input", TESTGRAPH, "--output", &data, "--data-v1"]).status.code(), Some(0));
+    let content = std::fs::read_to_string(&data).unwrap();
+    std::fs::remove_file(&data).unwrap();
+    assert_eq!(content.split("\n\n").next().unwrap().lines().count(), 11);
+    assert!(content.lines().filter(|line| !line.is_empty()).all(|line| line.ends_with(" 0")));
+}
+
+#[test]
+fn renders_svg_image() {
+    let image = temp_file("Plot.svg");
+    let output = run(&["render", "--input", TESTGRAPH, "--start", "A", "--dest", "K", "--output", &image]);
+    assert_eq!(output.status.code(), Some(0));
+
+    let content = std::fs::read_to_string(&image).unwrap();
+    std::fs::remove_file(&image).unwrap();
+    assert!(content.contains("<svg"));
+    assert!(content.contains("Start Node: A"));
+}
+
+#[test]
+fn renders_png_image() {
+    let image = temp_file("Plot.png");
+    let output = run(&["render", "--input", TESTGRAPH, "--start", "A", "--output", &image, "--size=4x3", "--dpi=50"]);
+    assert_eq!(output.status.code(), Some(0));
+
+    let content = std::fs::read(&image).unwrap();
+    std::fs::remove_file(&image).unwrap();
+    assert!(content.starts_with(b"\x89PNG\r\n\x1a\n"));
+    // Width and height of IHDR chunk.
+    assert_eq!(&content[16..24], &[0, 0, 0, 200, 0, 0, 0, 150]);
+
+    // Format overrides extension, invalid image options are usage errors.
+    let svg = temp_file("Plot.png.svg");
+    assert_eq!(run(&["render", "--input", TESTGRAPH, "--start", "A", "--output", &svg, "--format=png"]).status.code(), Some(0));
+    assert!(std::fs::read(&svg).unwrap().starts_with(b"\x89PNG"));
+    std::fs::remove_file(&svg).unwrap();
+    assert_eq!(run(&["render", "--input", TESTGRAPH, "--start", "A", "--format=gif"]).status.code(), Some(2));
+    assert_eq!(run(&["render", "--input", TESTGRAPH, "--start", "A", "--size=big"]).status.code(), Some(2));
+    assert_eq!(run(&["render", "--input", TESTGRAPH, "--start", "A", "--dpi=-3"]).status.code(), Some(2));
+}
+
+#[test]
+fn writes_graphml_with_results() {
+    let (data, graphml) = (temp_file("results.dat"), temp_file("results.graphml"));
+    let output = run(&["route", "--input", TESTGRAPH, "--output", &data, "--graphml", &graphml, "--start=A", "--dest=K"]);
+    assert_eq!(output.status.code(), Some(0));
+    std::fs::remove_file(&data).unwrap();
+
+    // Point-to-point search gives distances to all nodes nevertheless.
+    let graph = dijkstra_plot::load_graph(&graphml).unwrap().graph;
+    assert_eq!(graph.node_len(), 11);
+    assert!(graph.nodes().iter().all(|node| node.attr_i64("dijkstra.distance").is_some() && node.attr_f64("layout.x").is_some()));
+    assert_eq!(graph.node(graph.node_index("K").unwrap()).attr_bool("dijkstra.on_path"), Some(true));
+
+    // Converting it again keeps the file as it is.
+    let converted = temp_file("converted.graphml");
+    assert_eq!(run(&["convert", "--input", &graphml, "--output", &converted, "--to=graphml"]).status.code(), Some(0));
+    let (original, content) = (std::fs::read_to_string(&graphml).unwrap(), std::fs::read_to_string(&converted).unwrap());
+    std::fs::remove_file(&graphml).unwrap();
+    std::fs::remove_file(&converted).unwrap();
+    assert_eq!(original, content);
+}
+
+// Directed chain a -> b -> c with a shortcut a -> c and a node nobody can reach.
+const CHAIN_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="g" edgedefault="directed">
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <node id="lonely"/>
+        <edge id="ab" directed="true" weight="1" source="a" target="b"/>
+        <edge id="bc" directed="true" weight="2" source="b" target="c"/>
+        <edge id="ac" directed="true" weight="5" source="a" target="c"/>
+    </graph>
+</graphml>"#;
+
+// Random graph with 12 nodes "n0".."n11" and 30 edges (weights 0..20, some of them undirected), so some nodes may be
+// unreachable and equal-cost paths are likely.
+fn random_graph(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut xml = String::from(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="random" edgedefault="directed">"#);
+    for i in 0..12 {
+        xml.push_str(&format!(r#"<node id="n{}"/>"#, i));
+    }
+    for i in 0..30 {
+        xml.push_str(&format!(
+            r#"<edge id="e{}" directed="{}" weight="{}" source="n{}" target="n{}"/>"#,
+            i,
+            rng.gen_bool(0.7),
+            rng.gen_range(0..20),
+            rng.gen_range(0..12),
+            rng.gen_range(0..12)
+        ));
+    }
+    xml.push_str("</graph></graphml>");
+    xml
+}
+
+// Writes the graph into a temporary file and runs the route command on it with given parameters. Returns console output
+// and the written data file.
+fn route(name: &str, xml: &str, args: &[&str]) -> (Output, String) {
+    let input = temp_file(&format!("{}.xml", name));
+    let data = temp_file(&format!("{}.dat", name));
+    std::fs::write(&input, xml).unwrap();
+
+    let output = run(&[&["route", "--input", &input, "--output", &data], args].concat());
+    let written = std::fs::read_to_string(&data).unwrap_or_default();
+
+    let _ = std::fs::remove_file(input);
+    let _ = std::fs::remove_file(data);
+    (output, written)
+}
+
+// Ids of the nodes marked in a data file.
+fn marked_nodes(data: &str) -> Vec<String> {
+    let plot = PlotData::parse(data).unwrap();
+    plot.nodes.into_iter().filter(|node| node.marked).map(|node| node.id).collect()
+}
+
+// Number of marked edges in a data file.
+fn marked_edges(data: &str) -> usize {
+    PlotData::parse(data).unwrap().edges.iter().filter(|edge| edge.marked).count()
+}
+
+#[test]
+fn prints_and_marks_only_the_path_to_the_destination() {
+    let (output, data) = route("path", CHAIN_GRAPH, &["--start=a", "--dest=c"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("Shortest path from a to c (costs: 3): a -> b -> c"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("Edges on path: ab, bc"));
+
+    assert_eq!(marked_nodes(&data), ["a", "b", "c"]);
+    assert_eq!(marked_edges(&data), 2);
+}
+
+#[test]
+fn reports_unreachable_destination() {
+    let (output, data) = route("unreachable", CHAIN_GRAPH, &["--start=a", "--dest=lonely"]);
+    assert_eq!(output.status.code(), Some(6));
+    assert!(stdout(&output).contains("Node lonely is unreachable from node a!"), "{}", stdout(&output));
+
+    assert!(marked_nodes(&data).is_empty());
+    assert_eq!(marked_edges(&data), 0);
+}
+
+#[test]
+fn marks_shortest_path_tree_without_unreachable_nodes() {
+    let (output, data) = route("tree", CHAIN_GRAPH, &["--start=a"]);
+    assert!(output.status.success());
+
+    assert_eq!(marked_nodes(&data), ["a", "b", "c"]);
+    assert_eq!(marked_edges(&data), 2);
+}
+
+// Costs printed for the path to the destination (None if it's unreachable).
+fn printed_costs(output: &Output) -> Option<i64> {
+    let text = stdout(output);
+    let line = text.lines().find(|line| line.starts_with("Shortest path from") || line.contains("is unreachable"))?;
+    let costs = line.split("(costs: ").nth(1)?;
+    Some(costs[..costs.find(')').unwrap()].parse().unwrap())
+}
+
+#[test]
+fn all_priority_queues_find_equal_costs() {
+    for seed in 0..3 {
+        let xml = random_graph(seed);
+        for dest in ["n1", "n4", "n7", "n11"] {
+            let dest = format!("--dest={}", dest);
+            let (expected, _) = route("queues", &xml, &["--start=n0", &dest, "--queue=binary"]);
+            assert!(matches!(expected.status.code(), Some(0 | 6)), "{}", stderr(&expected));
+
+            for queue in ["pairing", "fibonacci", "radix", "dial"] {
+                let (output, _) = route("queues", &xml, &["--start=n0", &dest, &format!("--queue={}", queue)]);
+                assert!(stdout(&output).contains("queue) finished after"), "{}", stdout(&output));
+                assert_eq!(output.status.code(), expected.status.code(), "seed {}, {}, queue {}", seed, dest, queue);
+                assert_eq!(printed_costs(&output), printed_costs(&expected), "seed {}, {}, queue {}", seed, dest, queue);
+            }
+        }
+    }
+}
+
+#[test]
+fn dial_queue_refuses_large_weights() {
+    let xml = CHAIN_GRAPH.replace(r#"weight="5""#, &format!(r#"weight="{}""#, u32::MAX - 5));
+
+    let (output, _) = route("dial", &xml, &["--start=a", "--dest=c", "--queue=dial"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("Edge weights are too large for dial queue"), "{}", stderr(&output));
+
+    let (output, _) = route("radix", &xml, &["--start=a", "--dest=c", "--queue=radix"]);
+    assert_eq!(printed_costs(&output), Some(3));
+}
+
+// 7 x 7 grid with unit distance between neighbours: Node "x,y" lies at (x, y), edges have weight 1 (so euclidean and
+// manhattan heuristic are admissible with scale 1).
+fn grid_graph() -> String {
+    let mut xml = String::from(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+        <key id="x" for="node" attr.name="x" attr.type="double"/>
+        <key id="y" for="node" attr.name="y" attr.type="double"/>
+        <graph id="grid" edgedefault="undirected">"#,
+    );
+    for x in 0..7 {
+        for y in 0..7 {
+            xml.push_str(&format!(r#"<node id="{x},{y}"><data key="x">{x}</data><data key="y">{y}</data></node>"#));
+        }
+    }
+    for x in 0..7 {
+        for y in 0..7 {
+            if x + 1 < 7 {
+                xml.push_str(&format!(r#"<edge id="h{x},{y}" directed="false" weight="1" source="{},{y}" target="{},{y}"/>"#, x, x + 1));
+            }
+            if y + 1 < 7 {
+                xml.push_str(&format!(r#"<edge id="v{x},{y}" directed="false" weight="1" source="{x},{}" target="{x},{}"/>"#, y, y + 1));
+            }
+        }
+    }
+    xml.push_str("</graph></graphml>");
+    xml
+}
+
+// Expanded nodes of A* and plain dijkstra algorithm as printed after an A* search.
+fn expanded_nodes(output: &Output) -> (usize, usize) {
+    let text = stdout(output);
+    let line = text.lines().find(|line| line.starts_with("Expanded nodes: ")).expect("expanded nodes are printed");
+    let numbers: Vec<usize> = line.split(' ').filter_map(|word| word.parse().ok()).collect();
+    (numbers[0], numbers[1])
+}
+
+#[test]
+fn heuristics_find_dijkstra_costs_expanding_fewer_nodes() {
+    let xml = grid_graph();
+
+    for heuristic in ["euclidean", "manhattan"] {
+        let (output, data) = route(heuristic, &xml, &["--start=0,3", "--dest=6,3", &format!("--astar={}", heuristic)]);
+        assert!(output.status.success());
+        assert_eq!(printed_costs(&output), Some(6), "{}", heuristic);
+
+        let (astar, dijkstra) = expanded_nodes(&output);
+        assert!(astar < dijkstra, "{}: {} expanded nodes, dijkstra {}", heuristic, astar, dijkstra);
+
+        // The straight row is the only cheapest path.
+        assert_eq!(marked_nodes(&data), (0..7).map(|x| format!("{},3", x)).collect::<Vec<String>>(), "{}", heuristic);
+    }
+}
+
+#[test]
+fn heuristics_need_coordinates() {
+    let xml = grid_graph();
+
+    let (output, _) = route("lat", &xml, &["--start=0,0", "--dest=3,3", "--astar=euclidean", "--x-key=lat", "--y-key=lon"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("Destination node 3,3 has no numeric coordinates in keys lat and lon"), "{}", stderr(&output));
+    assert_eq!(printed_costs(&output), None);
+
+    // Without estimation coordinates aren't needed.
+    let (output, _) = route("zero", &xml, &["--start=0,0", "--dest=3,3", "--astar=zero", "--x-key=lat", "--y-key=lon"]);
+    assert_eq!(printed_costs(&output), Some(6));
+}
+
+// b -> c -> d -> b has total weight 2 - 4 + 1 = -1, it's reachable from s over a.
+const NEGATIVE_CYCLE: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="cycle" edgedefault="directed">
+        <node id="s"/>
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <node id="d"/>
+        <node id="z"/>
+        <edge id="sa" directed="true" weight="3" source="s" target="a"/>
+        <edge id="ab" directed="true" weight="1" source="a" target="b"/>
+        <edge id="bc" directed="true" weight="2" source="b" target="c"/>
+        <edge id="cd" directed="true" weight="-4" source="c" target="d"/>
+        <edge id="db" directed="true" weight="1" source="d" target="b"/>
+        <edge id="dz" directed="true" weight="5" source="d" target="z"/>
+    </graph>
+</graphml>"#;
+
+#[test]
+fn reports_reachable_negative_cycle() {
+    let (output, data) = route("cycle", NEGATIVE_CYCLE, &["--start=s", "--dest=z"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("Bellman-Ford is used instead of Dijkstra"));
+
+    // Cycle may be printed starting with any of its edges.
+    let text = stdout(&output);
+    let line = text.lines().find_map(|line| line.strip_prefix("Negative cycle found (costs: -1): ")).expect("cycle is printed");
+    let mut ids: Vec<&str> = line.split(", ").collect();
+    ids.sort();
+    assert_eq!(ids, ["bc", "cd", "db"]);
+    assert_eq!(printed_costs(&output), None);
+
+    assert_eq!(marked_nodes(&data), ["b", "c", "d"]);
+    assert_eq!(marked_edges(&data), 3);
+}
+
+#[test]
+fn negative_weights_shorten_paths_without_cycle() {
+    // Nothing leads from z back into the cycle.
+    let (output, _) = route("behind", NEGATIVE_CYCLE, &["--start=z"]);
+    assert!(!stdout(&output).contains("Negative cycle found"), "{}", stdout(&output));
+
+    // Without the closing edge there's no cycle anymore.
+    let xml = NEGATIVE_CYCLE.replace(r#"<edge id="db" directed="true" weight="1" source="d" target="b"/>"#, "");
+    let (output, _) = route("acyclic", &xml, &["--start=s", "--dest=z"]);
+    assert_eq!(printed_costs(&output), Some(3 + 1 + 2 - 4 + 5));
+}
+
+#[test]
+fn refuses_options_bellman_ford_would_ignore() {
+    let xml = NEGATIVE_CYCLE.replace(r#"<edge id="db" directed="true" weight="1" source="d" target="b"/>"#, "");
+
+    for option in ["--k-shortest=2", "--astar=euclidean", "--ties", "--enumerate=2", "--queue=radix"] {
+        let (output, _) = route("ignored", &xml, &["--start=s", "--dest=z", option]);
+        let name = option.split('=').next().unwrap();
+        assert_eq!(output.status.code(), Some(2), "{}", option);
+        assert!(stderr(&output).contains(&format!("{} can't be used", name)), "{}", stderr(&output));
+        assert_eq!(printed_costs(&output), None, "{}", option);
+    }
+}
+
+// Runs all-pairs shortest paths with given algorithm and returns the rows of the written CSV file (without quoted fields).
+fn distance_matrix(name: &str, xml: &str, algorithm: &str) -> Vec<Vec<String>> {
+    let matrix = temp_file(&format!("{}.csv", name));
+    let (output, _) = route(name, xml, &[&format!("--all-pairs={}", algorithm), &format!("--matrix={}", matrix)]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("All-pairs shortest paths"), "{}", stdout(&output));
+
+    let csv = std::fs::read_to_string(&matrix).unwrap();
+    std::fs::remove_file(matrix).unwrap();
+    csv.lines().map(|line| line.split(',').map(String::from).collect()).collect()
+}
+
+// Negative weights without negative cycles: The only cycle a -> b -> c -> a costs 2 - 3 + 4 = 3.
+const NEGATIVE_WEIGHTS: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="negative" edgedefault="directed">
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <node id="d"/>
+        <node id="lonely"/>
+        <edge id="ab" directed="true" weight="2" source="a" target="b"/>
+        <edge id="bc" directed="true" weight="-3" source="b" target="c"/>
+        <edge id="ca" directed="true" weight="4" source="c" target="a"/>
+        <edge id="ad" directed="true" weight="-1" source="a" target="d"/>
+        <edge id="dc" directed="true" weight="-2" source="d" target="c"/>
+    </graph>
+</graphml>"#;
+
+#[test]
+fn all_pairs_algorithms_match_single_source_runs() {
+    for seed in 0..3 {
+        let xml = random_graph(seed);
+        let floyd = distance_matrix("floyd", &xml, "floyd");
+        assert_eq!(distance_matrix("johnson", &xml, "johnson"), floyd, "seed {}", seed);
+
+        // Header lists node ids, row of n0 contains the costs dijkstra algorithm finds.
+        assert_eq!(floyd[0][1..].join(" "), (0..12).map(|v| format!("n{}", v)).collect::<Vec<String>>().join(" "));
+        for dest in [1, 4, 7, 11] {
+            let (output, _) = route("row", &xml, &["--start=n0", &format!("--dest=n{}", dest)]);
+            let expected = printed_costs(&output).map_or(String::from("inf"), |costs| costs.to_string());
+            assert_eq!(floyd[1][dest + 1], expected, "seed {}, n0 -> n{}", seed, dest);
+        }
+    }
+
+    let floyd = distance_matrix("floyd-negative", NEGATIVE_WEIGHTS, "floyd");
+    assert_eq!(distance_matrix("johnson-negative", NEGATIVE_WEIGHTS, "johnson"), floyd);
+    assert_eq!(floyd[1], ["a", "0", "2", "-3", "-1", "inf"]);
+    assert_eq!(floyd[5], ["lonely", "inf", "inf", "inf", "inf", "0"]);
+}
+
+#[test]
+fn quotes_node_ids_in_csv() {
+    let xml = CHAIN_GRAPH.replace(r#""a""#, r#""a,1""#).replace(r#""b""#, r#""say &quot;b&quot;""#);
+    let matrix = temp_file("quoted.csv");
+    let (output, _) = route("quoted", &xml, &["--all-pairs=floyd", &format!("--matrix={}", matrix)]);
+    assert!(output.status.success());
+
+    let csv = std::fs::read_to_string(&matrix).unwrap();
+    std::fs::remove_file(matrix).unwrap();
+    assert_eq!(csv.lines().next(), Some(r#","a,1","say ""b""",c,lonely"#));
+    assert!(csv.contains("\n\"a,1\",0,1,3,inf\n"), "{}", csv);
+
+    // Edge list quotes them the same way.
+    let input = temp_file("quoted-edges.xml");
+    let edges = temp_file("quoted-edges.csv");
+    std::fs::write(&input, &xml).unwrap();
+    assert_eq!(run(&["convert", "--input", &input, "--output", &edges]).status.code(), Some(0));
+    let csv = std::fs::read_to_string(&edges).unwrap();
+    std::fs::remove_file(input).unwrap();
+    std::fs::remove_file(edges).unwrap();
+    assert!(csv.contains("\nab,\"a,1\",\"say \"\"b\"\"\",1,true\n"), "{}", csv);
+}
+
+// All six loopless paths from s to t have different costs:
+// s a b c t (5), s b c t (6), s a c t (7), s a b t (8), s b t (9), s a t (10).
+const SMALL_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="small" edgedefault="directed">
+        <node id="s"/>
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <node id="t"/>
+        <edge id="sa" directed="true" weight="1" source="s" target="a"/>
+        <edge id="sb" directed="true" weight="3" source="s" target="b"/>
+        <edge id="ab" directed="true" weight="1" source="a" target="b"/>
+        <edge id="ac" directed="true" weight="4" source="a" target="c"/>
+        <edge id="bc" directed="true" weight="1" source="b" target="c"/>
+        <edge id="bt" directed="true" weight="6" source="b" target="t"/>
+        <edge id="ct" directed="true" weight="2" source="c" target="t"/>
+        <edge id="at" directed="true" weight="9" source="a" target="t"/>
+    </graph>
+</graphml>"#;
+
+// Ranked paths as printed by Yen's algorithm: Node ids (separated by spaces) and costs.
+fn ranked_paths(output: &Output) -> Vec<(String, i64)> {
+    stdout(output)
+        .lines()
+        .filter(|line| line.starts_with('#'))
+        .map(|line| {
+            let (costs, path) = line.split_once(" (costs: ").unwrap().1.split_once("): ").unwrap();
+            (path[..path.find(" [").unwrap()].replace(" -> ", " "), costs.parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn ranks_known_paths_of_small_graph() {
+    let expected = [("s a b c t", 5), ("s b c t", 6), ("s a c t", 7), ("s a b t", 8), ("s b t", 9), ("s a t", 10)];
+    let expected: Vec<(String, i64)> = expected.iter().map(|&(path, cost)| (path.to_string(), cost)).collect();
+
+    // Asking for more paths than there are gives all of them.
+    for k in [1, 3, 4, 6, 20] {
+        let (output, data) = route("ranked", SMALL_GRAPH, &["--start=s", "--dest=t", &format!("--k-shortest={}", k)]);
+        assert_eq!(ranked_paths(&output), expected[..k.min(6)], "k = {}", k);
+
+        // Cheapest path stays marked.
+        assert_eq!(marked_nodes(&data), ["s", "a", "b", "c", "t"], "k = {}", k);
+    }
+}
+
+// Costs of all loopless paths from u to t along (u, v, weight, directed) edges.
+fn enumerate(edges: &[(usize, usize, i64, bool)], u: usize, t: usize, visited: &mut Vec<bool>, cost: i64, costs: &mut Vec<i64>) {
+    if u == t {
+        costs.push(cost);
+        return;
+    }
+
+    visited[u] = true;
+    for &(a, b, weight, directed) in edges.iter() {
+        for (from, to) in [(a, b), (b, a)].into_iter().take(if directed { 1 } else { 2 }) {
+            if from == u && !visited[to] {
+                enumerate(edges, to, t, visited, cost + weight, costs);
+            }
+        }
+    }
+    visited[u] = false;
+}
+
+#[test]
+fn ranks_all_loopless_paths_of_random_graphs() {
+    let mut found = 0;
+    for seed in 0..5 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let edges: Vec<(usize, usize, i64, bool)> =
+            (0..14).map(|_| (rng.gen_range(0..7), rng.gen_range(0..7), rng.gen_range(1..10), rng.gen_bool(0.7))).collect();
+
+        let mut xml = String::from(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="random" edgedefault="directed">"#);
+        for v in 0..7 {
+            xml.push_str(&format!(r#"<node id="n{}"/>"#, v));
+        }
+        for (i, &(u, v, weight, directed)) in edges.iter().enumerate() {
+            xml.push_str(&format!(r#"<edge id="e{}" directed="{}" weight="{}" source="n{}" target="n{}"/>"#, i, directed, weight, u, v));
+        }
+        xml.push_str("</graph></graphml>");
+
+        let mut expected = Vec::new();
+        enumerate(&edges, 0, 1, &mut vec![false; 7], 0, &mut expected);
+        expected.sort();
+
+        // Paths of equal costs may come in any order, so only costs are compared. All paths are requested and some more.
+        let (output, _) = route("random-ranked", &xml, &["--start=n0", "--dest=n1", &format!("--k-shortest={}", expected.len() + 3)]);
+        let paths = ranked_paths(&output);
+        for (path, _) in paths.iter() {
+            let mut nodes: Vec<&str> = path.split(' ').collect();
+            assert_eq!((nodes.first(), nodes.last()), (Some(&"n0"), Some(&"n1")), "seed {}", seed);
+            nodes.sort();
+            nodes.dedup();
+            assert_eq!(nodes.len(), path.split(' ').count(), "seed {}: {} has a loop", seed, path);
+        }
+        let costs: Vec<i64> = paths.iter().map(|&(_, costs)| costs).collect();
+        assert_eq!(costs, expected, "seed {}", seed);
+        found += costs.len();
+    }
+    assert!(found > 5, "only {} paths found", found);
+}
+
+// s reaches a and b with costs 1, the zero-weight edge between them gives a second path to one of them.
+fn zero_weight_graph(zero_edge: &str) -> String {
+    format!(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="zero" edgedefault="directed">
+            <node id="s"/><node id="a"/><node id="b"/><node id="t"/>
+            <edge id="sa" directed="true" weight="1" source="s" target="a"/>
+            <edge id="sb" directed="true" weight="1" source="s" target="b"/>
+            {}
+            <edge id="at" directed="true" weight="1" source="a" target="t"/>
+            <edge id="bt" directed="true" weight="1" source="b" target="t"/>
+        </graph></graphml>"#,
+        zero_edge
+    )
+}
+
+// Number of shortest paths to each node as printed for the whole shortest-path DAG.
+fn path_counts(output: &Output) -> Vec<(String, u64)> {
+    stdout(output)
+        .lines()
+        .filter(|line| line.contains(" shortest path(s), "))
+        .map(|line| {
+            let (id, rest) = line.split_once(": costs ").unwrap();
+            (id.to_string(), rest.split(' ').nth(1).unwrap().parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn counts_paths_over_zero_weight_ties() {
+    // Whichever of a and b is settled first, the zero-weight edge is part of the DAG.
+    for (zero_edge, from, to) in [
+        (r#"<edge id="z" directed="true" weight="0" source="a" target="b"/>"#, "a", "b"),
+        (r#"<edge id="z" directed="true" weight="0" source="b" target="a"/>"#, "b", "a"),
+    ] {
+        let xml = zero_weight_graph(zero_edge);
+
+        let (output, data) = route("ties", &xml, &["--start=s", "--ties"]);
+        let counts = path_counts(&output);
+        assert!(counts.contains(&(from.to_string(), 1)) && counts.contains(&(to.to_string(), 2)), "{}: {:?}", zero_edge, counts);
+        assert!(counts.contains(&(String::from("t"), 3)), "{}: {:?}", zero_edge, counts);
+        assert_eq!(marked_edges(&data), 5, "{}", zero_edge);
+
+        // s a t, s b t and the one over the zero-weight edge.
+        let (output, _) = route("enumerate", &xml, &["--start=s", "--dest=t", "--enumerate=10"]);
+        assert!(stdout(&output).contains("3 shortest path(s) from s to t"), "{}", stdout(&output));
+        let paths = ranked_paths(&output);
+        assert_eq!(paths.len(), 3, "{}", zero_edge);
+        assert!(paths.iter().all(|&(_, costs)| costs == 2), "{}", zero_edge);
+    }
+}
+
+#[test]
+fn zero_weight_cycles_keep_dag_acyclic() {
+    // Undirected zero-weight edge: a -> b and b -> a are both tight, only one direction can be kept.
+    let xml = zero_weight_graph(r#"<edge id="z" directed="false" weight="0" source="a" target="b"/>"#);
+
+    let (output, _) = route("acyclic-dag", &xml, &["--start=s", "--ties"]);
+    let counts = path_counts(&output);
+    let mut ab: Vec<u64> = counts.iter().filter(|(id, _)| id == "a" || id == "b").map(|&(_, count)| count).collect();
+    ab.sort();
+    assert_eq!(ab, [1, 2]);
+    assert!(counts.contains(&(String::from("t"), 3)), "{:?}", counts);
+
+    let (output, _) = route("acyclic-paths", &xml, &["--start=s", "--dest=t", "--enumerate=10"]);
+    assert_eq!(ranked_paths(&output).len(), 3);
+}
@@ -0,0 +1,121 @@
+// Integration tests for diagnostics reported while reading GraphML files.
+
+use dijkstra_plot::Diagnostic::{DiagnosticCode, Position, Severity};
+use dijkstra_plot::GraphDocument::GraphDocument;
+
+const FAULTY_GRAPH: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <key id="k0" for="node" attr.name="color" attr.type="colour"/>
+    <key id="k1" for="node" attr.name="size" attr.type="int"/>
+    <key id="k1" for="node" attr.name="size" attr.type="double"/>
+    <graph id="g" edgedefault="directed">
+        <node id="a"/>
+        <node id="b">
+            <data key="k1">1</data>
+            <data key="k1">big</data>
+        </node>
+        <node id="a"/>
+        <edge id="e0" source="a" target="b" weight="1"/>
+	<edge id="e1" source="a" target="z" weight="1"/>
+        <edge id="e0" source="b" target="a" weight="2"/>
+    </graph>
+</graphml>"#;
+
+#[test]
+fn ignored_elements_are_reported_as_warnings() {
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse(FAULTY_GRAPH).unwrap();
+    let codes: Vec<DiagnosticCode> = diagnostics.iter().map(|d| d.code).collect();
+
+    assert_eq!(
+        codes,
+        vec![
+            DiagnosticCode::UnknownKeyType,
+            DiagnosticCode::DuplicateKeyId,
+            DiagnosticCode::InvalidValue,
+            DiagnosticCode::DuplicateNodeId,
+            DiagnosticCode::DanglingEndpoint,
+            DiagnosticCode::DuplicateEdgeId,
+        ]
+    );
+    assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning));
+    assert_eq!(graph.node_len(), 2);
+    assert_eq!(graph.edge_len(), 1);
+}
+
+#[test]
+fn diagnostics_point_at_their_element() {
+    let GraphDocument { diagnostics, .. } = GraphDocument::parse(FAULTY_GRAPH).unwrap();
+    let position = |code: DiagnosticCode| diagnostics.iter().find(|d| d.code == code).and_then(|d| d.position);
+    let element = |code: DiagnosticCode| diagnostics.iter().find(|d| d.code == code).and_then(|d| d.element_id.clone());
+
+    assert_eq!(position(DiagnosticCode::UnknownKeyType), Some(Position { line: 3, column: 5 }));
+    assert_eq!(element(DiagnosticCode::UnknownKeyType).as_deref(), Some("k0"));
+    assert_eq!(position(DiagnosticCode::DuplicateKeyId), Some(Position { line: 5, column: 5 }));
+    // Second <data> of node b.
+    assert_eq!(position(DiagnosticCode::InvalidValue), Some(Position { line: 10, column: 13 }));
+    assert_eq!(element(DiagnosticCode::InvalidValue).as_deref(), Some("b"));
+    assert_eq!(position(DiagnosticCode::DuplicateNodeId), Some(Position { line: 12, column: 9 }));
+    assert_eq!(position(DiagnosticCode::DanglingEndpoint), Some(Position { line: 14, column: 2 }));
+    assert_eq!(element(DiagnosticCode::DanglingEndpoint).as_deref(), Some("e1"));
+    assert_eq!(position(DiagnosticCode::DuplicateEdgeId), Some(Position { line: 15, column: 9 }));
+    assert_eq!(element(DiagnosticCode::DuplicateEdgeId).as_deref(), Some("e0"));
+}
+
+#[test]
+fn renders_source_line_with_marker() {
+    let GraphDocument { diagnostics, .. } = GraphDocument::parse(FAULTY_GRAPH).unwrap();
+    let dangling = diagnostics.iter().find(|d| d.code == DiagnosticCode::DanglingEndpoint).unwrap();
+
+    let rendered = dangling.render("faulty.xml");
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    assert_eq!(lines[0], "warning[GML015]: Unknown 'target' node z in edge e1 (edge is ignored)");
+    assert_eq!(lines[1], "  --> faulty.xml:14:2");
+    assert_eq!(lines[3], "14 | \t<edge id=\"e1\" source=\"a\" target=\"z\" weight=\"1\"/>");
+    assert_eq!(lines[4], "   | \t^");
+}
+
+// Node n has a nested graph (allowed by GraphML, but not read): Its nodes, edges and data must not be counted when
+// diagnostics of the outer graph are located.
+const NESTED_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <key id="k" for="node" attr.name="size" attr.type="int"/>
+    <graph id="outer" edgedefault="directed">
+        <node id="n">
+            <graph id="inner" edgedefault="directed">
+                <node id="i0"><data key="k">inner</data></node>
+                <node id="i1"/>
+                <edge id="inner" source="i0" target="i1"/>
+            </graph>
+            <data key="k">big</data>
+        </node>
+        <node id="m"/>
+        <node id="n"/>
+        <edge id="e0" source="n" target="z"/>
+    </graph>
+</graphml>"#;
+
+#[test]
+fn nested_graphs_dont_shift_positions() {
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse(NESTED_GRAPH).unwrap();
+    let position = |code: DiagnosticCode| diagnostics.iter().find(|d| d.code == code).and_then(|d| d.position);
+
+    assert_eq!(graph.node_len(), 2);
+    assert_eq!(graph.edge_len(), 0);
+    assert_eq!(diagnostics.len(), 3, "{:?}", diagnostics);
+    // Data of n is the one after the nested graph.
+    assert_eq!(position(DiagnosticCode::InvalidValue), Some(Position { line: 10, column: 13 }));
+    assert_eq!(position(DiagnosticCode::DuplicateNodeId), Some(Position { line: 13, column: 9 }));
+    assert_eq!(position(DiagnosticCode::DanglingEndpoint), Some(Position { line: 14, column: 9 }));
+}
+
+#[test]
+fn unreadable_documents_are_errors() {
+    let no_graph = GraphDocument::parse(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"/>"#).err().unwrap();
+    let no_xml = GraphDocument::parse("no xml").err().unwrap();
+    let no_file = GraphDocument::load("does/not/exist.xml").err().unwrap();
+
+    assert_eq!(no_graph.code, DiagnosticCode::MissingGraph);
+    assert_eq!(no_xml.code, DiagnosticCode::InvalidXml);
+    assert_eq!(no_file.code, DiagnosticCode::Io);
+    assert!([no_graph, no_xml, no_file].iter().all(|d| d.severity == Severity::Error && d.position.is_none()));
+}
@@ -0,0 +1,121 @@
+// Integration tests for edgedefault of graphs and direction of single edges.
+
+use dijkstra_plot::Dijkstra::DijkstraPath;
+use dijkstra_plot::Graph::graph_type::graph_enum::GraphType;
+use dijkstra_plot::Graph::{Graph, IgraphObject};
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphOutput::Highlight;
+
+// As written by networkx.write_graphml() for a DiGraph: No graph or edge ids, no directed attributes.
+const NETWORKX_GRAPH: &str = r#"<?xml version='1.0' encoding='utf-8'?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">
+  <key id="d0" for="edge" attr.name="weight" attr.type="double" />
+  <graph edgedefault="directed">
+    <node id="a" />
+    <node id="b" />
+    <node id="c" />
+    <edge source="a" target="b">
+      <data key="d0">2.0</data>
+    </edge>
+    <edge source="b" target="c">
+      <data key="d0">3.0</data>
+    </edge>
+  </graph>
+</graphml>"#;
+
+// Undirected by default, but c -> a may only be taken in this direction.
+const MIXED_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="mixed" edgedefault="undirected">
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <edge id="ab" source="a" target="b" weight="1"/>
+        <edge id="bc" source="b" target="c" directed="false" weight="1"/>
+        <edge id="ca" source="c" target="a" directed="true" weight="1"/>
+    </graph>
+</graphml>"#;
+
+fn cost(graph: &Graph, from: &str, to: &str) -> Option<i64> {
+    let (from, to) = (graph.node_index(from).unwrap(), graph.node_index(to).unwrap());
+    match dijkstra_plot::shortest_path(graph, from, to).unwrap() {
+        DijkstraPath::Reachable { cost, .. } => Some(cost),
+        DijkstraPath::Unreachable => None,
+    }
+}
+
+#[test]
+fn loads_networkx_export_unchanged() {
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse(NETWORKX_GRAPH).unwrap();
+
+    assert_eq!(graph.node_len(), 3);
+    assert_eq!(graph.edge_len(), 2);
+    assert!(graph.edges().iter().all(|edge| *edge.etype() == GraphType::Directed));
+    // Only the missing graph id is reported.
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+
+    assert_eq!(cost(&graph, "a", "c"), Some(5));
+    assert_eq!(cost(&graph, "c", "a"), None);
+}
+
+#[test]
+fn edge_direction_overrides_edgedefault() {
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse(MIXED_GRAPH).unwrap();
+    let etype = |id: &str| graph.edges().iter().find(|edge| edge.get_id() == id).unwrap().etype().clone();
+
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert!(etype("ab") == GraphType::Undirected);
+    assert!(etype("bc") == GraphType::Undirected);
+    assert!(etype("ca") == GraphType::Directed);
+
+    assert_eq!(cost(&graph, "c", "a"), Some(1));
+    assert_eq!(cost(&graph, "a", "c"), Some(2));
+}
+
+#[test]
+fn invalid_directions_are_reported() {
+    let xml = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+        <graph id="g" edgedefault="sideways">
+            <node id="a"/>
+            <node id="b"/>
+            <edge id="e0" source="a" target="b"/>
+            <edge id="e1" source="a" target="b" directed="maybe"/>
+        </graph>
+    </graphml>"#;
+
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse(xml).unwrap();
+
+    assert_eq!(graph.edge_len(), 1);
+    assert!(*graph.edges()[0].etype() == GraphType::Undirected);
+    assert_eq!(diagnostics.len(), 2, "{:?}", diagnostics);
+    assert!(diagnostics[0].message.contains("sideways"));
+    assert!(diagnostics[1].message.contains("e1"));
+}
+
+#[test]
+fn tree_marks_only_edges_in_their_direction() {
+    // a -> b is cheaper than b -> a, so only the first one belongs to the tree from a.
+    let xml = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+        <graph id="g" edgedefault="directed">
+            <node id="a"/>
+            <node id="b"/>
+            <edge id="ba" source="b" target="a" weight="1"/>
+            <edge id="ab" source="a" target="b" weight="5"/>
+        </graph>
+    </graphml>"#;
+    let graph = GraphDocument::parse(xml).unwrap().graph;
+    let start = graph.node_index("a").unwrap();
+
+    let result = dijkstra_plot::shortest_paths(&graph, start).unwrap();
+    let positions = dijkstra_plot::layout(&graph, start);
+
+    let file = std::env::temp_dir().join(format!("dijkstra-plot-direction-{}.dat", std::process::id()));
+    dijkstra_plot::write_output(file.to_str().unwrap(), &graph, &positions, &Highlight::Tree(&result)).unwrap();
+    let plot = dijkstra_plot::read_output(file.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&file).unwrap();
+
+    let edges = plot.edges;
+    assert_eq!(edges.len(), 2);
+    assert!(!edges[0].marked, "b -> a is not part of the tree");
+    assert!(edges[1].marked);
+    assert!(edges[0].directed && edges[1].directed);
+}
@@ -0,0 +1,69 @@
+// Integration tests for reading edge weights from GraphML keys.
+
+use dijkstra_plot::Graph::{Graph, IgraphObject};
+use dijkstra_plot::GraphDocument::{GraphDocument, ReadOptions};
+
+const WEIGHTED_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <key id="d0" for="edge" attr.name="weight" attr.type="double"><default>4</default></key>
+    <key id="d1" for="edge" attr.name="length" attr.type="long"/>
+    <graph id="weighted">
+        <node id="a"/>
+        <node id="b"/>
+        <edge id="data" source="a" target="b" directed="true"><data key="d0">2.0</data><data key="d1">7</data></edge>
+        <edge id="default" source="a" target="b" directed="true"/>
+        <edge id="attribute" source="a" target="b" directed="true" weight="9"/>
+        <edge id="both" source="a" target="b" directed="true" weight="9"><data key="d0">3</data></edge>
+        <edge id="fraction" source="a" target="b" directed="true"><data key="d0">0.5</data></edge>
+    </graph>
+</graphml>"#;
+
+fn weight_of(graph: &Graph, id: &str) -> Option<i64> {
+    graph.edges().iter().find(|edge| edge.get_id() == id).map(|edge| edge.weight())
+}
+
+#[test]
+fn reads_weights_from_data_default_and_attribute() {
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse(WEIGHTED_GRAPH).unwrap();
+
+    assert_eq!(weight_of(&graph, "data"), Some(2));
+    assert_eq!(weight_of(&graph, "default"), Some(4));
+    assert_eq!(weight_of(&graph, "attribute"), Some(9));
+    assert_eq!(weight_of(&graph, "both"), Some(3));
+
+    // Fractional weights can't be used, the edge is skipped.
+    assert_eq!(weight_of(&graph, "fraction"), None);
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    assert!(diagnostics[0].message.contains("fraction"));
+}
+
+#[test]
+fn weight_key_can_be_chosen_by_id_or_name() {
+    for name in ["length", "d1"] {
+        let options = ReadOptions { weight_key: Some(name.to_string()), unit_weight: 1 };
+        let graph = GraphDocument::parse_with(WEIGHTED_GRAPH, &options).unwrap().graph;
+
+        assert_eq!(weight_of(&graph, "data"), Some(7));
+        assert_eq!(weight_of(&graph, "default"), Some(1)); // length has no default
+        assert_eq!(weight_of(&graph, "attribute"), Some(9));
+        assert_eq!(weight_of(&graph, "fraction"), Some(1));
+    }
+}
+
+#[test]
+fn edges_without_weight_get_unit_weight() {
+    let xml = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="plain">
+        <node id="a"/>
+        <node id="b"/>
+        <edge id="e0" source="a" target="b" directed="false"/>
+    </graph></graphml>"#;
+
+    let options = ReadOptions { weight_key: Some("cost".to_string()), unit_weight: 5 };
+    let GraphDocument { graph, diagnostics } = GraphDocument::parse_with(xml, &options).unwrap();
+
+    assert_eq!(weight_of(&graph, "e0"), Some(5));
+    // Key that was asked for doesn't exist.
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("cost"));
+
+    assert_eq!(weight_of(&GraphDocument::parse(xml).unwrap().graph, "e0"), Some(1));
+}
@@ -0,0 +1,66 @@
+// Integration tests for the exit codes of the command line application.
+
+use std::process::Command;
+
+const FAULTY_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="g" edgedefault="directed">
+        <node id="a"/>
+        <node id="b"/>
+        <edge id="e0" source="a" target="b" weight="1"/>
+        <edge id="e1" source="a" target="z" weight="1"/>
+    </graph>
+</graphml>"#;
+
+fn temp_file(name: &str) -> String {
+    std::env::temp_dir().join(format!("dijkstra-plot-exit-{}-{}", std::process::id(), name)).to_str().unwrap().to_string()
+}
+
+// Runs route command with given parameters (output file is added) and returns its exit code.
+fn exit_code(args: &[&str]) -> i32 {
+    let output = temp_file("Graph.dat");
+    let status = Command::new(env!("CARGO_BIN_EXE_dijkstra-plot"))
+        .arg("route")
+        .args(args)
+        .arg(format!("--output={}", output))
+        .output()
+        .unwrap()
+        .status;
+    let _ = std::fs::remove_file(output);
+
+    status.code().unwrap()
+}
+
+#[test]
+fn reports_success_and_usage_errors() {
+    let testgraph = concat!("--input=", env!("CARGO_MANIFEST_DIR"), "/testgraph.xml");
+
+    assert_eq!(exit_code(&[testgraph, "--start=A", "--dest=K"]), 0);
+    assert_eq!(exit_code(&["--start=A"]), 2);
+    assert_eq!(exit_code(&[testgraph, "--start=nobody"]), 2);
+    assert_eq!(exit_code(&[testgraph, "--start=A", "--k-shortest=many", "--dest=K"]), 2);
+}
+
+#[test]
+fn distinguishes_io_and_parse_errors() {
+    let broken = temp_file("broken.xml");
+    std::fs::write(&broken, "<graphml").unwrap();
+
+    assert_eq!(exit_code(&["--input=does/not/exist.xml", "--start=A"]), 3);
+    assert_eq!(exit_code(&[&format!("--input={}", broken), "--start=A"]), 4);
+
+    std::fs::remove_file(broken).unwrap();
+}
+
+#[test]
+fn strict_mode_refuses_rejected_elements() {
+    let faulty = temp_file("faulty.xml");
+    std::fs::write(&faulty, FAULTY_GRAPH).unwrap();
+    let input = format!("--input={}", faulty);
+
+    assert_eq!(exit_code(&[&input, "--start=a"]), 0);
+    assert_eq!(exit_code(&[&input, "--start=a", "--strict"]), 5);
+    // b -> a doesn't exist (edges are directed).
+    assert_eq!(exit_code(&[&input, "--start=b", "--dest=a"]), 6);
+
+    std::fs::remove_file(faulty).unwrap();
+}
@@ -0,0 +1,150 @@
+// Integration tests for changing a graph after it was loaded.
+
+use dijkstra_plot::Dijkstra::DijkstraPath;
+use dijkstra_plot::Graph::graph_type::graph_enum::GraphType;
+use dijkstra_plot::Graph::key_type::key_enum::KeyType;
+use dijkstra_plot::Graph::{AttrValue, Edge, Graph, IgraphObject, Key, Node, NodeIndex};
+use dijkstra_plot::GraphDocument::GraphDocument;
+
+// s -> a -> t with costs 1 + 1, a shortcut s -> t with costs 5.
+fn line_graph() -> Graph {
+    GraphDocument::parse(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="line">
+            <node id="s"/>
+            <node id="a"/>
+            <node id="t"/>
+            <edge id="e0" source="s" target="a" directed="true" weight="1"/>
+            <edge id="e1" source="a" target="t" directed="true" weight="1"/>
+            <edge id="e2" source="s" target="t" directed="true" weight="5"/>
+        </graph></graphml>"#,
+    )
+    .unwrap()
+    .graph
+}
+
+fn cost(graph: &Graph, from: &str, to: &str) -> Option<i64> {
+    let (from, to) = (graph.node_index(from).unwrap(), graph.node_index(to).unwrap());
+    match dijkstra_plot::shortest_path(graph, from, to).unwrap() {
+        DijkstraPath::Reachable { cost, .. } => Some(cost),
+        DijkstraPath::Unreachable => None,
+    }
+}
+
+#[test]
+fn adds_nodes_and_edges_with_unique_ids() {
+    let mut graph = line_graph();
+
+    let u = graph.add_node(Node::new("u".to_string(), Vec::new())).unwrap();
+    assert_eq!(u, NodeIndex::new(3));
+    assert_eq!(graph.node_len(), 4);
+    assert!(graph.add_node(Node::new("s".to_string(), Vec::new())).is_err());
+
+    let t = graph.node_index("t").unwrap();
+    let e = graph.add_edge(Edge::new("e3".to_string(), 2, GraphType::Directed, t, u, Vec::new())).unwrap();
+    assert_eq!(graph.edge_len(), 4);
+    assert_eq!(graph.edge_index("e3"), Some(e));
+    assert_eq!(cost(&graph, "s", "u"), Some(4));
+
+    // Duplicate id and unknown endpoint are rejected without changing the graph.
+    assert!(graph.add_edge(Edge::new("e0".to_string(), 1, GraphType::Directed, t, u, Vec::new())).is_err());
+    assert!(graph.add_edge(Edge::new("e4".to_string(), 1, GraphType::Directed, t, NodeIndex::new(9), Vec::new())).is_err());
+    assert_eq!(graph.edge_len(), 4);
+}
+
+#[test]
+fn connections_follow_added_nodes_and_edges() {
+    let mut graph = line_graph();
+    let (s, a) = (graph.node_index("s").unwrap(), graph.node_index("a").unwrap());
+    let before = graph.neighbors(s).to_vec();
+
+    // New nodes don't change connections of the others.
+    let added: Vec<NodeIndex> = (0..20).map(|i| graph.add_node(Node::new(format!("n{}", i), Vec::new())).unwrap()).collect();
+    assert_eq!(graph.neighbors(s), &before[..]);
+    assert!(added.iter().all(|&v| graph.neighbors(v).is_empty()));
+
+    // Chain s -> n0 -> ... -> n19 -> a, added edge by edge.
+    let mut last = s;
+    for &v in added.iter().chain(std::iter::once(&a)) {
+        let e = graph.add_edge(Edge::new(format!("chain{}", v.index()), 0, GraphType::Directed, last, v, Vec::new())).unwrap();
+        assert!(graph.neighbors(last).contains(&(v, e)));
+        last = v;
+    }
+    assert_eq!(graph.neighbors(s).len(), before.len() + 1);
+    assert_eq!(cost(&graph, "s", "t"), Some(1));
+}
+
+#[test]
+fn new_graph_needs_unique_ids_and_existing_endpoints() {
+    let node = |id: &str| Node::new(id.to_string(), Vec::new());
+    let edge = |id: &str, source: usize, dest: usize| Edge::new(id.to_string(), 1, GraphType::Directed, NodeIndex::new(source), NodeIndex::new(dest), Vec::new());
+    let new = |nodes: Vec<Node>, edges: Vec<Edge>| Graph::new("g".to_string(), nodes, edges, Vec::new());
+
+    let graph = new(vec![node("a"), node("b")], vec![edge("e0", 0, 1), edge("e1", 1, 0)]).unwrap();
+    assert_eq!((graph.node_len(), graph.edge_len()), (2, 2));
+
+    assert!(new(vec![node("a"), node("a")], Vec::new()).err().unwrap().contains("Node a"));
+    assert!(new(vec![node("a"), node("b")], vec![edge("e0", 0, 1), edge("e0", 1, 0)]).err().unwrap().contains("Edge e0"));
+    assert!(new(vec![node("a"), node("b")], vec![edge("e0", 0, 2)]).err().unwrap().contains("Edge e0"));
+}
+
+#[test]
+fn removing_a_node_removes_its_edges_and_renumbers() {
+    let mut graph = line_graph();
+
+    let removed = graph.remove_node(graph.node_index("a").unwrap()).unwrap();
+    assert_eq!(removed.get_id(), "a");
+
+    assert_eq!(graph.node_len(), 2);
+    assert_eq!(graph.edge_len(), 1);
+    assert_eq!(graph.node_index("t"), Some(NodeIndex::new(1)));
+    // Remaining edge still connects s and t.
+    let edge = graph.edge(graph.edge_index("e2").unwrap());
+    assert_eq!(graph.node(edge.source()).get_id(), "s");
+    assert_eq!(graph.node(edge.dest()).get_id(), "t");
+    assert_eq!(cost(&graph, "s", "t"), Some(5));
+
+    assert!(graph.remove_node(NodeIndex::new(2)).is_none());
+}
+
+#[test]
+fn removing_an_edge_changes_shortest_path() {
+    let mut graph = line_graph();
+
+    let removed = graph.remove_edge(graph.edge_index("e1").unwrap()).unwrap();
+    assert_eq!(removed.get_id(), "e1");
+    assert_eq!(graph.edge_len(), 2);
+    assert_eq!(cost(&graph, "s", "t"), Some(5));
+}
+
+#[test]
+fn changes_weight_and_direction() {
+    let mut graph = line_graph();
+
+    graph.set_weight(graph.edge_index("e2").unwrap(), 1);
+    assert_eq!(cost(&graph, "s", "t"), Some(1));
+
+    assert_eq!(cost(&graph, "t", "s"), None);
+    graph.set_direction(graph.edge_index("e2").unwrap(), GraphType::Undirected);
+    assert_eq!(cost(&graph, "t", "s"), Some(1));
+}
+
+#[test]
+fn sets_and_removes_attribute_values() {
+    let mut graph = line_graph();
+    let s = graph.node_index("s").unwrap();
+    let key = |value: &str| Key {
+        id: "d0".to_string(),
+        attrname: "color".to_string(),
+        attrtype: KeyType::String,
+        value: Some(AttrValue::String(value.to_string())),
+    };
+
+    Graph::add_key(graph.node_mut(s), key("red"));
+    assert_eq!(graph.node(s).attr_str("color"), Some("red"));
+
+    Graph::add_key(graph.node_mut(s), key("blue"));
+    assert_eq!(graph.node(s).attr_str("color"), Some("blue"));
+
+    Graph::delete_key(graph.node_mut(s), "d0");
+    assert_eq!(graph.node(s).attr_str("color"), None);
+}
@@ -0,0 +1,45 @@
+// Integration tests for the metrics of a graph.
+
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphStats::GraphStats;
+
+#[test]
+fn counts_edges_degrees_and_components() {
+    let graph = GraphDocument::parse(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="g" edgedefault="directed">
+            <node id="a"/>
+            <node id="b"/>
+            <node id="c"/>
+            <node id="lonely"/>
+            <edge id="e0" source="a" target="b" weight="2"/>
+            <edge id="e1" source="b" target="a" weight="-1"/>
+            <edge id="e2" source="b" target="c" directed="false" weight="5"/>
+            <edge id="e3" source="c" target="c" weight="1"/>
+        </graph></graphml>"#,
+    )
+    .unwrap()
+    .graph;
+
+    let stats = GraphStats::of(&graph);
+
+    assert_eq!((stats.nodes, stats.edges), (4, 4));
+    assert_eq!((stats.directed_edges, stats.undirected_edges), (3, 1));
+    assert_eq!((stats.self_loops, stats.parallel_edges), (1, 1));
+    assert_eq!((stats.isolated_nodes, stats.components), (1, 2));
+    assert_eq!((stats.min_degree, stats.max_degree), (0, 3));
+    assert_eq!(stats.mean_degree, 2.0);
+    // a -> b, b -> a, b -> c, c -> b out of 12 ordered pairs.
+    assert!((stats.density - 4.0 / 12.0).abs() < 1e-9);
+    assert_eq!((stats.min_weight, stats.max_weight, stats.negative_weights), (Some(-1), Some(5), 1));
+}
+
+#[test]
+fn empty_graph_has_no_weights() {
+    let graph = GraphDocument::parse(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="g"/></graphml>"#).unwrap().graph;
+
+    let stats = GraphStats::of(&graph);
+
+    assert_eq!((stats.nodes, stats.edges, stats.components), (0, 0, 0));
+    assert_eq!(stats.min_weight, None);
+    assert_eq!(stats.density, 0.0);
+}
@@ -0,0 +1,147 @@
+// Integration tests for writing graphs (and computed results) as GraphML.
+
+use dijkstra_plot::Dijkstra::{Dijkstra, DijkstraPath};
+use dijkstra_plot::Graph::graph_type::graph_enum::GraphType;
+use dijkstra_plot::Graph::key_type::key_enum::KeyType;
+use dijkstra_plot::Graph::{AttrValue, Graph, IgraphObject, Key};
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphMLWriter::{self, Annotations, GraphMLWriter as Writer};
+
+const TESTGRAPH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testgraph.xml");
+
+// Mixed directions, weights in a double key, values that need escaping and keys for all elements.
+const MIXED_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <key id="w" for="edge" attr.name="weight" attr.type="double"/>
+    <key id="label" for="all" attr.name="label" attr.type="string"><default>none</default></key>
+    <key id="x" for="node" attr.name="x" attr.type="int"/>
+    <graph id="mixed &amp; more" edgedefault="directed">
+        <data key="label"> a &lt;graph&gt; </data>
+        <node id="a&quot;1"><data key="x">3</data></node>
+        <node id="b"><data key="label">line 1
+line 2	tab</data></node>
+        <node id="c"/>
+        <edge id="ab" source="a&quot;1" target="b"><data key="w">2.0</data></edge>
+        <edge id="bc" source="b" target="c" directed="false"><data key="w">4.5e1</data></edge>
+        <edge source="c" target="a&quot;1" weight="7"/>
+    </graph>
+</graphml>"#;
+
+fn reload(xml: &str) -> Graph {
+    let document = GraphDocument::parse(xml).unwrap();
+    assert!(document.diagnostics.is_empty(), "{:?}", document.diagnostics);
+    document.graph
+}
+
+fn sorted(keys: &[Key]) -> Vec<Key> {
+    let mut keys = keys.to_vec();
+    keys.sort_by(|a, b| a.id.cmp(&b.id));
+    keys
+}
+
+// Everything the reader gives: ids, endpoints, weights, directions and all keys with their values. Edges that had no
+// weight key get the generated one, which is left out.
+fn assert_same_graph(expected: &Graph, actual: &Graph) {
+    assert_eq!(expected.get_id(), actual.get_id());
+    assert_eq!(sorted(expected.keys()), sorted(actual.keys()));
+
+    assert_eq!(expected.node_len(), actual.node_len());
+    for (e, a) in expected.nodes().iter().zip(actual.nodes()) {
+        assert_eq!(e.get_id(), a.get_id());
+        assert_eq!(sorted(e.keys()), sorted(a.keys()), "keys of node {}", e.get_id());
+    }
+
+    assert_eq!(expected.edge_len(), actual.edge_len());
+    for (e, a) in expected.edges().iter().zip(actual.edges()) {
+        assert_eq!(e.get_id(), a.get_id());
+        assert_eq!((e.source(), e.dest(), e.weight()), (a.source(), a.dest(), a.weight()), "edge {}", e.get_id());
+        assert!(*e.etype() == *a.etype(), "direction of edge {}", e.get_id());
+
+        let has_weight_key = e.keys().iter().any(|key| key.attrname == "weight");
+        let keys: Vec<Key> = a.keys().iter().filter(|key| has_weight_key || key.attrname != "weight").cloned().collect();
+        assert_eq!(sorted(e.keys()), sorted(&keys), "keys of edge {}", e.get_id());
+    }
+}
+
+#[test]
+fn keeps_graph_after_computing_and_saving() {
+    let graph = dijkstra_plot::load_graph(TESTGRAPH).unwrap().graph;
+    let (start, dest) = (graph.node_index("A").unwrap(), graph.node_index("K").unwrap());
+
+    let paths = dijkstra_plot::shortest_paths(&graph, start).unwrap();
+    let path = Dijkstra::path_to(&graph, &paths, dest);
+    let positions = dijkstra_plot::layout(&graph, start);
+    let annotations = Annotations { paths: Some(&paths), path: Some(&path), positions: Some(&positions) };
+
+    let annotated = Writer::annotate(&graph, &annotations);
+    let xml = Writer::render(&annotated);
+    let reloaded = reload(&xml);
+    assert_same_graph(&annotated, &reloaded);
+
+    // Results are found under the generated names.
+    let k = reloaded.node(dest);
+    let cost = paths.iter().find(|ele| ele.owner == dest).unwrap().cost();
+    assert_eq!(k.attr_i64(GraphMLWriter::DISTANCE), Some(cost));
+    assert_eq!(k.attr_bool(GraphMLWriter::ON_PATH), Some(true));
+    assert!(k.attr_str(GraphMLWriter::PREDECESSOR).is_some());
+    assert_eq!(reloaded.node(start).attr(GraphMLWriter::PREDECESSOR), None);
+    assert_eq!(reloaded.node(start).attr_f64(GraphMLWriter::LAYOUT_X), Some(f64::from(positions.iter().find(|p| p.node == start).unwrap().pos.0)));
+    let on_path = reloaded.edges().iter().filter(|edge| edge.attr_bool(GraphMLWriter::ON_PATH) == Some(true)).count();
+    match path {
+        DijkstraPath::Reachable { ref edges, .. } => assert_eq!(on_path, edges.len()),
+        DijkstraPath::Unreachable => panic!("K should be reachable"),
+    }
+
+    // Writing the read graph again gives the same file, so results of a new run replace the old ones.
+    assert_eq!(Writer::render(&reloaded), xml);
+    let again = Writer::annotate(&reloaded, &annotations);
+    assert_eq!(Writer::render(&again), xml);
+}
+
+#[test]
+fn keeps_directions_weights_and_special_characters() {
+    let graph = reload(MIXED_GRAPH);
+    let xml = Writer::render(&graph);
+    let reloaded = reload(&xml);
+
+    assert_same_graph(&graph, &reloaded);
+    assert_eq!(reloaded.get_id(), "mixed & more");
+    assert_eq!(reloaded.attr_str("label"), Some(" a <graph> "));
+    assert_eq!(reloaded.node(reloaded.node_index("b").unwrap()).attr_str("label"), Some("line 1\nline 2\ttab"));
+    let bc = reloaded.edge(reloaded.edge_index("bc").unwrap());
+    assert_eq!((bc.weight(), bc.attr("weight")), (45, Some(&AttrValue::Double(45.0))));
+    assert!(*bc.etype() == GraphType::Undirected);
+    // Edge without weight data (its id was generated when reading) keeps its weight attribute.
+    let e2 = reloaded.edge(reloaded.edge_index("e2").unwrap());
+    assert_eq!((e2.weight(), e2.attr("weight")), (7, None));
+    assert!(xml.contains(r#"weight="7""#), "{}", xml);
+
+    // Key ids are kept, so the file reads like the original one.
+    assert!(xml.contains(r#"<key id="label" for="all" attr.name="label" attr.type="string"/>"#), "{}", xml);
+    assert!(xml.contains(r#"<key id="w" for="edge" attr.name="weight" attr.type="double"/>"#), "{}", xml);
+}
+
+#[test]
+fn generated_keys_dont_replace_keys_of_the_file() {
+    let mut graph = reload(MIXED_GRAPH);
+    let a = graph.node_index("a\"1").unwrap();
+
+    // A key (like one of the file) that has the id a generated key would get.
+    for v in graph.node_indices().collect::<Vec<_>>() {
+        let value = (v == a).then_some(AttrValue::Int(1));
+        Graph::add_key(graph.node_mut(v), Key { id: "node.layout.x".to_string(), attrname: "level".to_string(), attrtype: KeyType::Int, value });
+    }
+
+    let paths = dijkstra_plot::shortest_paths(&graph, a).unwrap();
+    let positions = dijkstra_plot::layout(&graph, a);
+    let annotated = Writer::annotate(&graph, &Annotations { paths: Some(&paths), positions: Some(&positions), ..Annotations::default() });
+    let reloaded = reload(&Writer::render(&annotated));
+
+    assert_same_graph(&annotated, &reloaded);
+    let node = reloaded.node(a);
+    assert_eq!(node.attr_i64("x"), Some(3));
+    assert_eq!(node.attr_i64("level"), Some(1));
+    assert!(node.attr_f64(GraphMLWriter::LAYOUT_X).is_some());
+    assert_eq!(node.attr_i64(GraphMLWriter::DISTANCE), Some(0));
+    // Without a path there's no on-path flag.
+    assert_eq!(node.attr(GraphMLWriter::ON_PATH), None);
+}
@@ -0,0 +1,255 @@
+// Integration tests: Use the library the same way another crate would.
+
+use dijkstra_plot::Dijkstra::{DijkstraPath, INFINITE_COSTS};
+use dijkstra_plot::Graph::{Graph, IgraphObject};
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphOutput::Highlight;
+use dijkstra_plot::ShortestPathDag::ShortestPathDag;
+
+fn testgraph() -> GraphDocument {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/testgraph.xml");
+    dijkstra_plot::load_graph(path).expect("testgraph.xml can be loaded")
+}
+
+fn small_graph() -> Graph {
+    GraphDocument::parse(SMALL_GRAPH).unwrap().graph
+}
+
+const SMALL_GRAPH: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="small" edgedefault="directed">
+        <node id="s"/>
+        <node id="a"/>
+        <node id="b"/>
+        <node id="t"/>
+        <node id="lonely"/>
+        <edge id="e0" source="s" target="a" directed="true" weight="1"/>
+        <edge id="e1" source="s" target="b" directed="true" weight="1"/>
+        <edge id="e2" source="a" target="t" directed="true" weight="2"/>
+        <edge id="e3" source="b" target="t" directed="true" weight="2"/>
+        <edge id="e4" source="t" target="s" directed="true" weight="1"/>
+    </graph>
+</graphml>"#;
+
+#[test]
+fn loads_all_nodes_and_edges() {
+    let GraphDocument { graph, diagnostics } = testgraph();
+
+    assert!(diagnostics.is_empty(), "unexpected diagnostics: {:?}", diagnostics);
+    assert_eq!(graph.node_len(), 11);
+    assert_eq!(graph.edge_len(), 14);
+    assert!(graph.node_index("A").is_some());
+    assert!(graph.node_index("Z").is_none());
+}
+
+#[test]
+fn reports_invalid_elements_and_documents() {
+    let document = GraphDocument::parse(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="g">
+            <node id="a"/>
+            <edge id="e0" source="a" target="missing" directed="true" weight="1"/>
+        </graph></graphml>"#,
+    )
+    .unwrap();
+
+    assert_eq!(document.graph.edge_len(), 0);
+    assert_eq!(document.diagnostics.len(), 1);
+
+    assert!(GraphDocument::parse(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"/>"#).is_err());
+    assert!(GraphDocument::parse("no xml").is_err());
+    assert!(dijkstra_plot::load_graph("does/not/exist.xml").is_err());
+}
+
+#[test]
+fn adjacency_follows_edge_direction() {
+    let graph = small_graph();
+    let neighbors = |id: &str| -> Vec<&str> {
+        let u = graph.node_index(id).unwrap();
+        graph.neighbors(u).iter().map(|&(v, _)| graph.node(v).get_id()).collect()
+    };
+
+    assert_eq!(neighbors("s"), vec!["a", "b"]);
+    assert_eq!(neighbors("t"), vec!["s"]);
+    assert!(neighbors("lonely").is_empty());
+
+    // Undirected edges can be taken from both ends.
+    let graph = testgraph().graph;
+    for e in graph.edge_indices() {
+        let (u, v) = (graph.edge(e).source(), graph.edge(e).dest());
+        assert!(graph.neighbors(u).contains(&(v, e)));
+        assert!(graph.neighbors(v).contains(&(u, e)));
+    }
+}
+
+#[test]
+fn computes_costs_to_all_nodes() {
+    let graph = testgraph().graph;
+    let start = graph.node_index("A").unwrap();
+
+    let result = dijkstra_plot::shortest_paths(&graph, start).unwrap();
+    let cost_of = |id: &str| result.iter().find(|ele| graph.node(ele.owner).get_id() == id).map(|ele| ele.cost());
+
+    assert_eq!(result.len(), graph.node_len());
+    assert_eq!(cost_of("A"), Some(0));
+    assert_eq!(cost_of("I"), Some(3));
+    assert_eq!(cost_of("K"), Some(10));
+    assert_eq!(cost_of("C"), Some(11));
+}
+
+#[test]
+fn point_to_point_path_matches_full_search() {
+    let graph = testgraph().graph;
+    let start = graph.node_index("A").unwrap();
+
+    let result = dijkstra_plot::shortest_paths(&graph, start).unwrap();
+
+    for dest in graph.node_indices() {
+        let expected = result.iter().find(|ele| ele.owner == dest).unwrap().cost();
+        let id = graph.node(dest).get_id();
+
+        match dijkstra_plot::shortest_path(&graph, start, dest).unwrap() {
+            DijkstraPath::Reachable { nodes, edges, cost } => {
+                assert_eq!(cost, expected, "costs to {}", id);
+                assert_eq!(nodes.len(), edges.len() + 1);
+                assert_eq!(nodes.first(), Some(&start));
+                assert_eq!(nodes.last(), Some(&dest));
+                // Each edge connects the nodes next to it on the path.
+                for (i, &e) in edges.iter().enumerate() {
+                    let (u, v) = (graph.edge(e).source(), graph.edge(e).dest());
+                    assert!((u, v) == (nodes[i], nodes[i + 1]) || (v, u) == (nodes[i], nodes[i + 1]));
+                }
+            }
+            DijkstraPath::Unreachable => panic!("{} should be reachable", id),
+        }
+    }
+}
+
+#[test]
+fn unreachable_nodes_have_infinite_costs() {
+    let graph = small_graph();
+    let start = graph.node_index("s").unwrap();
+    let lonely = graph.node_index("lonely").unwrap();
+
+    let result = dijkstra_plot::shortest_paths(&graph, start).unwrap();
+
+    assert_eq!(result.iter().find(|ele| ele.owner == lonely).unwrap().cost(), INFINITE_COSTS);
+    assert!(matches!(dijkstra_plot::shortest_path(&graph, start, lonely).unwrap(), DijkstraPath::Unreachable));
+    // Edges are directed: Way back to s only leads over t.
+    let back = dijkstra_plot::shortest_path(&graph, graph.node_index("a").unwrap(), start).unwrap();
+    assert!(matches!(back, DijkstraPath::Reachable { cost: 3, .. }));
+
+    // Shortest-path tree doesn't contain unreachable nodes.
+    let positions = dijkstra_plot::layout(&graph, start);
+    let plot = dijkstra_plot::GraphOutput::GraphOutput::plot(&graph, &positions, &Highlight::Tree(&result), None);
+    assert!(!plot.nodes[lonely.index()].marked);
+    assert_eq!(plot.nodes.iter().filter(|node| node.marked).count(), 4);
+}
+
+#[test]
+fn counts_equal_cost_paths() {
+    let graph = small_graph();
+    let start = graph.node_index("s").unwrap();
+    let dest = graph.node_index("t").unwrap();
+
+    let dag = ShortestPathDag::build(&graph, start).unwrap();
+    let paths = dag.paths_to(dest, 10);
+
+    assert_eq!(dag.count(dest), 2);
+    assert_eq!(paths.len(), 2);
+    assert!(paths.iter().all(|path| matches!(path, DijkstraPath::Reachable { cost: 3, .. })));
+}
+
+// s reaches a and b with costs 1, the zero-weight edge between them gives a second path to one of them.
+fn zero_weight_graph(zero_edge: &str) -> Graph {
+    let xml = format!(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="zero" edgedefault="directed">
+            <node id="s"/><node id="a"/><node id="b"/><node id="t"/>
+            <edge id="sa" source="s" target="a" directed="true" weight="1"/>
+            <edge id="sb" source="s" target="b" directed="true" weight="1"/>
+            {}
+            <edge id="at" source="a" target="t" directed="true" weight="1"/>
+            <edge id="bt" source="b" target="t" directed="true" weight="1"/>
+        </graph></graphml>"#,
+        zero_edge
+    );
+    GraphDocument::parse(&xml).unwrap().graph
+}
+
+#[test]
+fn counts_paths_over_zero_weight_ties() {
+    // Whichever of a and b is settled first, the zero-weight edge is part of the DAG.
+    let edges = [
+        (r#"<edge id="z" source="a" target="b" directed="true" weight="0"/>"#, "a", "b"),
+        (r#"<edge id="z" source="b" target="a" directed="true" weight="0"/>"#, "b", "a"),
+    ];
+    for (zero_edge, from, to) in edges {
+        let graph = zero_weight_graph(zero_edge);
+        let (s, t) = (graph.node_index("s").unwrap(), graph.node_index("t").unwrap());
+        let dag = ShortestPathDag::build(&graph, s).unwrap();
+
+        assert!(dag.contains_edge(graph.edge_index("z").unwrap()), "{}", zero_edge);
+        assert_eq!(dag.count(graph.node_index(from).unwrap()), 1, "{}", zero_edge);
+        assert_eq!(dag.count(graph.node_index(to).unwrap()), 2, "{}", zero_edge);
+        // s a t, s b t and the one over the zero-weight edge.
+        assert_eq!(dag.count(t), 3, "{}", zero_edge);
+        assert_eq!(dag.paths_to(t, 10).len(), 3, "{}", zero_edge);
+        assert!(dag.paths_to(t, 10).iter().all(|path| matches!(path, DijkstraPath::Reachable { cost: 2, .. })));
+    }
+}
+
+#[test]
+fn zero_weight_cycles_keep_dag_acyclic() {
+    // Undirected zero-weight edge: a -> b and b -> a are both tight, only one direction can be kept.
+    let graph = zero_weight_graph(r#"<edge id="z" source="a" target="b" directed="false" weight="0"/>"#);
+    let (s, t) = (graph.node_index("s").unwrap(), graph.node_index("t").unwrap());
+    let dag = ShortestPathDag::build(&graph, s).unwrap();
+
+    let (a, b) = (graph.node_index("a").unwrap(), graph.node_index("b").unwrap());
+    let mut counts = [dag.count(a), dag.count(b)];
+    counts.sort();
+    assert_eq!(counts, [1, 2]);
+    assert_eq!(dag.count(t), 3);
+    assert_eq!(dag.paths_to(t, 10).len(), 3);
+}
+
+#[test]
+fn layout_positions_every_node() {
+    let graph = testgraph().graph;
+    let start = graph.node_index("A").unwrap();
+
+    let positions = dijkstra_plot::layout(&graph, start);
+
+    assert_eq!(positions.len(), graph.node_len());
+    for node in graph.node_indices() {
+        assert_eq!(positions.iter().filter(|pos| pos.node == node).count(), 1);
+    }
+}
+
+#[test]
+fn writes_data_file_with_marked_path() {
+    let graph = testgraph().graph;
+    let start = graph.node_index("A").unwrap();
+    let dest = graph.node_index("K").unwrap();
+
+    let path = dijkstra_plot::shortest_path(&graph, start, dest).unwrap();
+    let positions = dijkstra_plot::layout(&graph, start);
+
+    let file = std::env::temp_dir().join(format!("dijkstra-plot-library-{}.dat", std::process::id()));
+    dijkstra_plot::write_output(file.to_str().unwrap(), &graph, &positions, &Highlight::Path(&path)).unwrap();
+
+    let plot = dijkstra_plot::read_output(file.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&file).unwrap();
+
+    let DijkstraPath::Reachable { nodes: path_nodes, edges: path_edges, cost } = path else {
+        panic!("K should be reachable");
+    };
+
+    // Start and destination are named, marked entries are the ones of the path.
+    assert_eq!(plot.nodes.len(), graph.node_len());
+    assert_eq!(plot.start.map(|no| plot.nodes[no as usize].id.as_str()), Some("A"));
+    assert_eq!(plot.dest.map(|no| plot.nodes[no as usize].id.as_str()), Some("K"));
+    assert_eq!(plot.nodes.iter().filter(|node| node.marked).count(), path_nodes.len());
+    assert_eq!(plot.nodes[dest.index()].cost, Some(cost));
+    assert_eq!(plot.edges.len(), graph.edge_len());
+    assert_eq!(plot.edges.iter().filter(|edge| edge.marked).count(), path_edges.len());
+}
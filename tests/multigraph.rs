@@ -0,0 +1,115 @@
+// Integration tests for graphs with several edges between the same nodes (parallel edges with different weights).
+
+use std::collections::BTreeSet;
+use std::process::Command;
+
+use dijkstra_plot::AStar::heuristic::Zero;
+use dijkstra_plot::AStar::AStar;
+use dijkstra_plot::BellmanFord::{BellmanFord, BellmanFordResult};
+use dijkstra_plot::BidirectionalDijkstra::BidirectionalDijkstra;
+use dijkstra_plot::Dijkstra::{Dijkstra, DijkstraPath};
+use dijkstra_plot::Graph::{EdgeIndex, Graph, IgraphObject};
+use dijkstra_plot::GraphOutput::{GraphOutput, Highlight};
+use dijkstra_plot::Yen::Yen;
+
+const MULTIGRAPH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/multigraph.xml");
+const MULTIGRAPH_DIRECTED: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/multigraph_directed.xml");
+
+fn load(path: &str) -> Graph {
+    dijkstra_plot::load_graph(path).unwrap().graph
+}
+
+fn ids(graph: &Graph, edges: &[EdgeIndex]) -> Vec<String> {
+    edges.iter().map(|&e| graph.edge(e).get_id().to_string()).collect()
+}
+
+fn path_ids(graph: &Graph, path: &DijkstraPath) -> (Vec<String>, i64) {
+    match path {
+        DijkstraPath::Reachable { edges, cost, .. } => (ids(graph, edges), *cost),
+        DijkstraPath::Unreachable => panic!("destination should be reachable"),
+    }
+}
+
+fn marked(graph: &Graph, highlight: &Highlight) -> BTreeSet<String> {
+    let positions = dijkstra_plot::layout(graph, graph.node_indices().next().unwrap());
+    let plot = GraphOutput::plot(graph, &positions, highlight, None);
+    plot.edges.iter().filter(|edge| edge.marked).map(|edge| edge.id.clone()).collect()
+}
+
+fn set(ids: &[&str]) -> BTreeSet<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[test]
+fn records_edge_taken_to_each_node() {
+    let graph = load(MULTIGRAPH);
+    let result = Dijkstra::run(&graph, graph.node_index("s").unwrap()).unwrap();
+
+    let taken: Vec<(String, Option<String>)> = result
+        .iter()
+        .map(|entry| (graph.node(entry.owner).get_id().to_string(), entry.prev_edge.map(|e| graph.edge(e).get_id().to_string())))
+        .collect();
+    assert!(taken.contains(&(String::from("s"), None)));
+    assert!(taken.contains(&(String::from("a"), Some(String::from("e1")))));
+    assert!(taken.contains(&(String::from("t"), Some(String::from("e3")))));
+    assert!(taken.contains(&(String::from("b"), Some(String::from("e4")))));
+
+    // Only the edges taken are marked, not their expensive (or equally cheap) parallels.
+    assert_eq!(marked(&graph, &Highlight::Tree(&result)), set(&["e1", "e3", "e4"]));
+}
+
+#[test]
+fn all_algorithms_take_cheapest_parallel_edge() {
+    let graph = load(MULTIGRAPH);
+    let (s, t) = (graph.node_index("s").unwrap(), graph.node_index("t").unwrap());
+    let expected = (vec![String::from("e1"), String::from("e3")], 3);
+
+    let result = Dijkstra::run(&graph, s).unwrap();
+    assert_eq!(path_ids(&graph, &Dijkstra::path_to(&graph, &result, t)), expected);
+    assert_eq!(path_ids(&graph, &BidirectionalDijkstra::run(&graph, s, t).unwrap().path), expected);
+    assert_eq!(path_ids(&graph, &AStar::run(&graph, s, t, &Zero, ("x", "y")).unwrap().path), expected);
+    let BellmanFordResult::Paths(result) = BellmanFord::run(&graph, s) else {
+        panic!("graph has no negative cycle");
+    };
+    assert_eq!(path_ids(&graph, &Dijkstra::path_to(&graph, &result, t)), expected);
+
+    // Equally cheap parallel edge gives another path of the same costs.
+    let paths = Yen::run(&graph, s, t, 2).unwrap();
+    assert_eq!(path_ids(&graph, &paths[0]), expected);
+    assert_eq!(path_ids(&graph, &paths[1]), (vec![String::from("e6"), String::from("e3")], 3));
+
+    let path = BidirectionalDijkstra::run(&graph, s, t).unwrap().path;
+    assert_eq!(marked(&graph, &Highlight::Path(&path)), set(&["e1", "e3"]));
+}
+
+#[test]
+fn parallel_edges_against_direction_are_not_taken() {
+    let graph = load(MULTIGRAPH_DIRECTED);
+    let (a, c) = (graph.node_index("a").unwrap(), graph.node_index("c").unwrap());
+    let expected = (vec![String::from("e2"), String::from("e5")], 5);
+
+    let result = Dijkstra::run(&graph, a).unwrap();
+    assert_eq!(path_ids(&graph, &Dijkstra::path_to(&graph, &result, c)), expected);
+    assert_eq!(path_ids(&graph, &BidirectionalDijkstra::run(&graph, a, c).unwrap().path), expected);
+    assert_eq!(marked(&graph, &Highlight::Tree(&result)), set(&["e2", "e5"]));
+}
+
+#[test]
+fn command_line_marks_taken_edges() {
+    let data = std::env::temp_dir().join(format!("dijkstra-plot-multigraph-{}.dat", std::process::id()));
+    let data = data.to_str().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dijkstra-plot")).args(["route", "--input", MULTIGRAPH, "--start=s", "--output", data]).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let plot = dijkstra_plot::read_output(data).unwrap();
+    let marked: BTreeSet<String> = plot.edges.iter().filter(|edge| edge.marked).map(|edge| edge.id.clone()).collect();
+    assert_eq!(marked, set(&["e1", "e3", "e4"]));
+
+    // Format version 1 marks the same edges (in order of the file).
+    let output = Command::new(env!("CARGO_BIN_EXE_dijkstra-plot")).args(["route", "--input", MULTIGRAPH, "--start=s", "--output", data, "--data-v1"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let content = std::fs::read_to_string(data).unwrap();
+    std::fs::remove_file(data).unwrap();
+    let flags: Vec<bool> = content.split("\n\n").nth(1).unwrap().lines().map(|line| line.ends_with(" 1")).collect();
+    assert_eq!(flags, vec![false, true, false, true, true, false, false]);
+}
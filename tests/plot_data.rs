@@ -0,0 +1,109 @@
+// Integration tests for reading and writing plot data files (version 2).
+
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphOutput::{GraphOutput, Highlight};
+use dijkstra_plot::PlotData::{PlotData, PlotDataError};
+use dijkstra_plot::Yen::Yen;
+
+const GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="g" edgedefault="directed">
+        <node id="New York"/>
+        <node id="say &quot;hi&quot;\"/>
+        <node id="t"/>
+        <node id="lonely"/>
+        <edge id="first edge" source="New York" target="say &quot;hi&quot;\" weight="1"/>
+        <edge id="e1" source="say &quot;hi&quot;\" target="t" weight="2"/>
+        <edge id="e2" source="New York" target="t" weight="5"/>
+        <edge id="e3" source="t" target="New York" weight="4"/>
+    </graph>
+</graphml>"#;
+
+fn parse_error(text: &str) -> PlotDataError {
+    match PlotData::parse(text) {
+        Ok(_) => panic!("{} should be rejected", text),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn reads_what_was_written() {
+    let graph = GraphDocument::parse(GRAPH).unwrap().graph;
+    let (s, t) = (graph.node_index("New York").unwrap(), graph.node_index("t").unwrap());
+
+    let paths = Yen::run(&graph, s, t, 2).unwrap();
+    let positions = dijkstra_plot::layout(&graph, s);
+    let mut plot = GraphOutput::plot(&graph, &positions, &Highlight::Path(&paths[0]), Some(&paths));
+    plot.negative_cycle = Some(vec![String::from("first edge"), String::from("e1")]);
+
+    let data = PlotData::render(&plot);
+    assert!(data.starts_with("dijkstra-plot-data 2\nstart \"New York\"\ndest \"t\"\n"));
+    assert!(data.contains(r#""say \"hi\"\\""#));
+    let read = PlotData::parse(&data).unwrap();
+
+    assert_eq!((read.start, read.dest), (Some(s.index() as u32), Some(t.index() as u32)));
+    assert_eq!(read.negative_cycle, plot.negative_cycle);
+    assert_eq!(read.nodes.len(), plot.nodes.len());
+    for (read, node) in read.nodes.iter().zip(plot.nodes.iter()) {
+        assert_eq!((read.no, read.x, read.y, &read.id, read.cost, read.marked), (node.no, node.x, node.y, &node.id, node.cost, node.marked));
+    }
+    // Costs along the path (lonely isn't on it).
+    let costs: Vec<Option<i64>> = read.nodes.iter().map(|node| node.cost).collect();
+    assert_eq!(costs, vec![Some(0), Some(1), Some(3), None]);
+
+    assert_eq!(read.edges.len(), plot.edges.len());
+    for (read, edge) in read.edges.iter().zip(plot.edges.iter()) {
+        assert_eq!((&read.id, read.from, read.to, read.weight, read.directed, read.marked, read.bend), (&edge.id, edge.from, edge.to, edge.weight, edge.directed, edge.marked, edge.bend));
+    }
+
+    let ranks: Vec<(usize, u32, u32, i64, f32)> = read.paths.iter().map(|path| (path.rank, path.from, path.to, path.cost, path.bend)).collect();
+    assert_eq!(ranks, plot.paths.iter().map(|path| (path.rank, path.from, path.to, path.cost, path.bend)).collect::<Vec<_>>());
+    assert_eq!(read.paths.len(), 3);
+}
+
+#[test]
+fn finds_columns_by_name() {
+    let data = "# comment\n\
+        dijkstra-plot-data 2\n\
+        \n\
+        nodes id:string marked:bool no:int y:float x:float cost:int color:string\n\
+        \"a\" true 0 2.5 1 7 \"red\"\n\
+        \"b\" false 1 0 0 8 \"blue\"\n\
+        edges bend:float marked:bool directed:bool weight:int to:int from:int id:string\n\
+        0 false true 3 1 0 \"a b\"\n\
+        start \"b\"\n";
+    let plot = PlotData::parse(data).unwrap();
+
+    assert_eq!((plot.nodes[0].x, plot.nodes[0].y, plot.nodes[0].cost), (1.0, 2.5, Some(7)));
+    assert_eq!(plot.nodes[1].id, "b");
+    assert_eq!((plot.edges[0].id.as_str(), plot.edges[0].from, plot.edges[0].to, plot.edges[0].directed), ("a b", 0, 1, true));
+    assert_eq!((plot.start, plot.dest), (Some(1), None));
+    assert!(plot.paths.is_empty() && plot.negative_cycle.is_none());
+}
+
+#[test]
+fn rejects_invalid_files_with_line_number() {
+    let header = "dijkstra-plot-data 2\n";
+    let nodes = "nodes no:int x:float y:float id:string cost:int? marked:bool\n";
+
+    // Version 1 has no header.
+    assert_eq!(parse_error("0 1.5 2.5 A 1\n").line, 1);
+    assert_eq!(parse_error("").line, 0);
+    assert!(parse_error("dijkstra-plot-data 3\n").message.contains("unsupported version 3"));
+
+    let err = parse_error(&format!("{}nodes no:int x:float y:float id:string marked:bool\n", header));
+    assert_eq!(err, PlotDataError { line: 2, message: String::from("nodes table lacks column cost") });
+    assert_eq!(parse_error(&format!("{}nodes no:float x:float y:float id:string cost:int? marked:bool\n", header)).line, 2);
+    // Unknown values only in optional columns.
+    assert_eq!(parse_error(&format!("{}nodes no:int x:float? y:float id:string cost:int? marked:bool\n", header)).line, 2);
+
+    assert_eq!(parse_error(&format!("{}{}0 1 2 \"a\" - yes\n", header, nodes)).line, 3);
+    assert_eq!(parse_error(&format!("{}{}0 1 2 a - true\n", header, nodes)).line, 3);
+    assert_eq!(parse_error(&format!("{}{}0 1 2 \"a - true\n", header, nodes)).line, 3);
+    assert_eq!(parse_error(&format!("{}{}1 1 2 \"a\" - true\n", header, nodes)).line, 3);
+    assert_eq!(parse_error(&format!("{}{}0 1 2 \"a\" - true 5\n", header, nodes)).line, 3);
+    assert_eq!(parse_error(&format!("{}0 1 2 \"a\" - true\n", header)).line, 2);
+
+    let edges = "edges id:string from:int to:int weight:int directed:bool marked:bool bend:float\n";
+    assert_eq!(parse_error(&format!("{}{}0 1 2 \"a\" - true\n{}\"e\" 0 1 1 false false 0\n", header, nodes, edges)).line, 5);
+    assert_eq!(parse_error(&format!("{}start \"x\"\n{}0 1 2 \"a\" - true\n", header, nodes)).line, 2);
+}
@@ -0,0 +1,102 @@
+// Integration tests for plot.py reading data files (version 2). matplotlib is replaced by a stub that prints what would
+// be drawn, so the script runs without display (and without matplotlib being installed).
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphOutput::{GraphOutput, Highlight};
+use dijkstra_plot::PlotData::PlotData;
+use dijkstra_plot::Yen::Yen;
+
+const SCRIPT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/plot.py");
+
+// Prints title and texts of annotations instead of drawing them.
+const PYPLOT_STUB: &str = r#"
+class Axes:
+    def __getattr__(self, name):
+        def record(*args, **kwargs):
+            if name == 'annotate':
+                print('annotate', args[0])
+            return Axes()
+        return record
+
+def subplots(*args, **kwargs):
+    return None, Axes()
+
+def title(text, **kwargs):
+    print('title', text)
+
+def Line2D(*args, **kwargs):
+    return None
+
+def savefig(*args, **kwargs):
+    pass
+
+def show():
+    pass
+"#;
+
+// Edge ids are the keywords of the data file: Quoted they are values and must not start a table (or name the start node).
+const GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="g" edgedefault="directed">
+        <node id="s"/>
+        <node id="a"/>
+        <node id="t"/>
+        <edge id="start" source="s" target="a" weight="1"/>
+        <edge id="nodes" source="a" target="t" weight="2"/>
+        <edge id="edges" source="s" target="t" weight="5"/>
+        <edge id="paths" source="t" target="s" weight="7"/>
+    </graph>
+</graphml>"#;
+
+// Directory with the matplotlib stub (to be put on PYTHONPATH).
+fn stub_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dijkstra-plot-matplotlib-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("matplotlib")).unwrap();
+    std::fs::write(dir.join("matplotlib").join("__init__.py"), "").unwrap();
+    std::fs::write(dir.join("matplotlib").join("pyplot.py"), PYPLOT_STUB).unwrap();
+    dir
+}
+
+// Runs plot.py on given data file and returns its output.
+fn run_script(data: &Path) -> String {
+    let stub = stub_dir();
+    let output = Command::new("python3")
+        .arg(SCRIPT)
+        .arg(format!("-input={}", data.display()))
+        .arg("-output=unused.png")
+        .env("PYTHONPATH", &stub)
+        .output()
+        .expect("python3 is needed to run plot.py");
+    std::fs::remove_dir_all(&stub).unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    assert!(output.status.success(), "{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+    stdout
+}
+
+#[test]
+fn reads_edges_named_like_keywords() {
+    let graph = GraphDocument::parse(GRAPH).unwrap().graph;
+    let (s, t) = (graph.node_index("s").unwrap(), graph.node_index("t").unwrap());
+
+    let paths = Yen::run(&graph, s, t, 2).unwrap();
+    let positions = dijkstra_plot::layout(&graph, s);
+    let plot = GraphOutput::plot(&graph, &positions, &Highlight::Path(&paths[0]), Some(&paths));
+
+    let data = std::env::temp_dir().join(format!("dijkstra-plot-keywords-{}.dat", std::process::id()));
+    PlotData::write(data.to_str().unwrap(), &plot).unwrap();
+    let written = std::fs::read_to_string(&data).unwrap();
+    assert!(written.contains("\n\"start\" 0 1 1 ") && written.contains("\npaths rank:int"), "{}", written);
+    let ids: Vec<String> = PlotData::parse(&written).unwrap().edges.into_iter().map(|edge| edge.id).collect();
+    assert_eq!(ids, ["start", "nodes", "edges", "paths"]);
+
+    let stdout = run_script(&data);
+    std::fs::remove_file(&data).unwrap();
+
+    assert!(stdout.contains("title Dijkstra Graph Plot (Start Node: s)\n"), "{}", stdout);
+    // Each edge gets its weight as label, each node its id (directed edges are annotations without text).
+    let mut labels: Vec<&str> = stdout.lines().filter_map(|line| line.strip_prefix("annotate ")).filter(|label| !label.is_empty()).collect();
+    labels.sort();
+    assert_eq!(labels, ["1", "2", "5", "7", "a", "s", "t"], "{}", stdout);
+}
@@ -0,0 +1,64 @@
+// Integration tests for rasterizing graphs as PNG image.
+
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphOutput::{GraphOutput, Highlight};
+use dijkstra_plot::PlotScene::{ImageSize, Scene};
+use dijkstra_plot::PngRenderer::PngRenderer;
+
+const GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="g" edgedefault="undirected">
+        <node id="s"/>
+        <node id="a"/>
+        <node id="t"/>
+        <edge id="e0" source="s" target="a" weight="1"/>
+        <edge id="e1" source="a" target="t" weight="1"/>
+        <edge id="e2" source="s" target="t" weight="5"/>
+    </graph>
+</graphml>"#;
+
+fn scene(size: ImageSize) -> Scene {
+    let graph = GraphDocument::parse(GRAPH).unwrap().graph;
+    let (s, t) = (graph.node_index("s").unwrap(), graph.node_index("t").unwrap());
+
+    let path = dijkstra_plot::shortest_path(&graph, s, t).unwrap();
+    let positions = dijkstra_plot::layout(&graph, s);
+    let plot = GraphOutput::plot(&graph, &positions, &Highlight::Path(&path), None);
+    Scene::build(&plot, Some(s.index() as u32), size)
+}
+
+#[test]
+fn rasterizes_marked_path_with_default_size() {
+    let pixmap = PngRenderer::render(&scene(ImageSize::default())).unwrap();
+    assert_eq!((pixmap.width(), pixmap.height()), (1200, 1200));
+
+    let pixels = pixmap.pixels();
+    let count = |rgb: (u8, u8, u8)| pixels.iter().filter(|p| (p.red(), p.green(), p.blue()) == rgb).count();
+    // Background, marked path, unmarked edge and text (anti-aliased edges give shades in between).
+    assert!(count((255, 255, 255)) > 1200 * 1200 / 2);
+    assert!(count((255, 0, 0)) > 1000);
+    assert!(count((0, 0, 255)) > 100);
+    assert!(count((0, 0, 0)) > 100);
+    assert!(pixels.iter().any(|p| p.red() > 0 && p.red() < 255 && p.green() == p.red()));
+}
+
+#[test]
+fn writes_png_with_configured_size_and_resolution() {
+    let size = ImageSize { width: 6.0, height: 4.0, dpi: 150.0 };
+    let file_name = std::env::temp_dir().join(format!("dijkstra-plot-png-{}.png", std::process::id()));
+    let file_name = file_name.to_str().unwrap();
+    PngRenderer::write(file_name, &scene(size)).unwrap();
+
+    let decoder = png::Decoder::new(std::fs::File::open(file_name).unwrap());
+    let reader = decoder.read_info().unwrap();
+    let info = reader.info();
+    assert_eq!((info.width, info.height), (900, 600));
+    assert_eq!(info.color_type, png::ColorType::Rgb);
+    // 150 pixels per inch.
+    assert_eq!(info.pixel_dims.map(|dims| dims.xppu), Some(5906));
+    drop(reader);
+    std::fs::remove_file(file_name).unwrap();
+
+    "7x5".parse::<ImageSize>().unwrap();
+    assert!("7".parse::<ImageSize>().is_err());
+    assert!("0x5".parse::<ImageSize>().is_err());
+}
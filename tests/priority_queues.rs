@@ -0,0 +1,120 @@
+// Integration tests for the priority queues behind dijkstra algorithm: Random pushes (inserts and decreased keys) and pops
+// are compared with std::collections::BinaryHeap.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap as StdHeap;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use dijkstra_plot::Dijkstra::{Dijkstra, MAX_DIAL_WEIGHT};
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::PriorityQueue::{BinaryHeap, DialQueue, FibonacciHeap, PairingHeap, PriorityQueue, QueueKind, RadixHeap};
+
+const ITEMS: usize = 300;
+const MAX_WEIGHT: i64 = 40;
+
+// Keys are used like dijkstra algorithm does: Never below the last popped key and at most MAX_WEIGHT above it (required
+// by the monotone queues), popped items are never pushed again. Items with equal keys may be popped in any order.
+fn check_against_std<Q: PriorityQueue>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut queue = Q::new(ITEMS, MAX_WEIGHT);
+    let mut reference: StdHeap<Reverse<(i64, usize)>> = StdHeap::new(); // outdated entries are skipped
+    let mut keys: Vec<Option<i64>> = vec![None; ITEMS]; // current key of queued items
+    let mut popped = vec![false; ITEMS];
+    let mut last = 0;
+
+    for step in 0..20_000 {
+        if step < 15_000 && rng.gen_bool(0.6) {
+            let item = rng.gen_range(0..ITEMS);
+            if popped[item] {
+                continue;
+            }
+            let key = last + rng.gen_range(0..=MAX_WEIGHT);
+
+            queue.push(item, key);
+            if keys[item].is_none_or(|k| key < k) {
+                keys[item] = Some(key);
+                reference.push(Reverse((key, item)));
+            }
+        } else {
+            while let Some(&Reverse((key, item))) = reference.peek() {
+                if keys[item] == Some(key) {
+                    break;
+                }
+                reference.pop();
+            }
+            let expected = reference.peek().map(|&Reverse((key, _))| key);
+
+            match queue.pop() {
+                Some((item, key)) => {
+                    assert_eq!(Some(key), expected, "seed {}, step {}", seed, step);
+                    assert_eq!(keys[item], Some(key), "seed {}, step {}: item {} wasn't queued with key {}", seed, step, item, key);
+                    keys[item] = None;
+                    popped[item] = true;
+                    last = key;
+                }
+                None => assert_eq!(expected, None, "seed {}, step {}", seed, step),
+            }
+        }
+
+        assert_eq!(queue.is_empty(), keys.iter().all(Option::is_none), "seed {}, step {}", seed, step);
+    }
+}
+
+fn check<Q: PriorityQueue>() {
+    for seed in 0..5 {
+        check_against_std::<Q>(seed);
+    }
+}
+
+#[test]
+fn binary_heap_pops_in_key_order() {
+    check::<BinaryHeap>();
+}
+
+#[test]
+fn pairing_heap_pops_in_key_order() {
+    check::<PairingHeap>();
+}
+
+#[test]
+fn fibonacci_heap_pops_in_key_order() {
+    check::<FibonacciHeap>();
+}
+
+#[test]
+fn radix_heap_pops_in_key_order() {
+    check::<RadixHeap>();
+}
+
+#[test]
+fn dial_queue_pops_in_key_order() {
+    check::<DialQueue>();
+}
+
+#[test]
+fn dial_queue_refuses_large_weights() {
+    let xml = format!(
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="g">
+            <node id="a"/><node id="b"/><node id="c"/>
+            <edge id="ab" source="a" target="b" directed="true" weight="{}"/><edge id="bc" source="b" target="c" directed="true" weight="3"/>
+        </graph></graphml>"#,
+        i64::MAX - 5
+    );
+    let graph = GraphDocument::parse(&xml).unwrap().graph;
+    assert!(Dijkstra::max_weight(&graph) > MAX_DIAL_WEIGHT);
+
+    let start = graph.node_index("a").unwrap();
+    let error = Dijkstra::run_using(&graph, start, QueueKind::Dial).err().expect("dial queue can't take these weights");
+    assert!(error.contains("too large for dial queue"), "{}", error);
+
+    // Radix heap isn't limited by the weights, costs aren't cut off.
+    let costs = |queue: QueueKind| -> Vec<(usize, i64)> {
+        let mut costs: Vec<(usize, i64)> = Dijkstra::run_using(&graph, start, queue).unwrap().iter().map(|ele| (ele.owner.index(), ele.cost())).collect();
+        costs.sort();
+        costs
+    };
+    assert_eq!(costs(QueueKind::Radix), [(0, 0), (1, i64::MAX - 5), (2, i64::MAX - 2)]);
+    assert_eq!(costs(QueueKind::Binary), costs(QueueKind::Radix));
+}
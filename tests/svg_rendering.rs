@@ -0,0 +1,123 @@
+// Integration tests for drawing graphs as SVG image.
+
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::GraphOutput::{GraphOutput, Highlight};
+use dijkstra_plot::PlotScene::{ImageSize, Scene};
+use dijkstra_plot::SvgRenderer::SvgRenderer;
+use dijkstra_plot::Yen::Yen;
+
+const GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="g" edgedefault="undirected">
+        <node id="s"/>
+        <node id="a&amp;b"/>
+        <node id="t"/>
+        <edge id="e0" source="s" target="a&amp;b" weight="1"/>
+        <edge id="e1" source="a&amp;b" target="t" weight="1"/>
+        <edge id="e2" source="s" target="t" weight="5"/>
+    </graph>
+</graphml>"#;
+
+fn count(svg: &str, pattern: &str) -> usize {
+    svg.matches(pattern).count()
+}
+
+#[test]
+fn draws_marked_path_and_start_node() {
+    let graph = GraphDocument::parse(GRAPH).unwrap().graph;
+    let (s, t) = (graph.node_index("s").unwrap(), graph.node_index("t").unwrap());
+
+    let path = dijkstra_plot::shortest_path(&graph, s, t).unwrap();
+    let positions = dijkstra_plot::layout(&graph, t);
+    let plot = GraphOutput::plot(&graph, &positions, &Highlight::Path(&path), None);
+
+    let svg = SvgRenderer::render(&Scene::build(&plot, Some(t.index() as u32), ImageSize::default()));
+
+    assert!(svg.starts_with("<?xml"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains("Dijkstra Graph Plot (Start Node: t)"));
+    // Ids are escaped.
+    assert!(svg.contains(">a&amp;b</text>"));
+    // Three edges and two lines in legend, two of the edges (and one legend line) are marked.
+    assert_eq!(count(&svg, "<line"), 5);
+    assert_eq!(count(&svg, r##"stroke="#ff0000" stroke-width="4""##), 3);
+    // Three nodes (all marked) and two legend markers, start node is larger.
+    assert_eq!(count(&svg, "<circle"), 5);
+    assert_eq!(count(&svg, r##"fill="#ff0000""##), 4);
+    assert_eq!(count(&svg, r#"r="7.91""#), 1);
+    for weight in ["1", "5"] {
+        assert!(svg.contains(&format!(">{}</text>", weight)));
+    }
+}
+
+#[test]
+fn draws_ranked_paths_below_edges_with_legend() {
+    let graph = GraphDocument::parse(GRAPH).unwrap().graph;
+    let (s, t) = (graph.node_index("s").unwrap(), graph.node_index("t").unwrap());
+
+    let paths = Yen::run(&graph, s, t, 2).unwrap();
+    let positions = dijkstra_plot::layout(&graph, s);
+    let plot = GraphOutput::plot(&graph, &positions, &Highlight::Path(&paths[0]), Some(&paths));
+
+    let svg = SvgRenderer::render(&Scene::build(&plot, None, ImageSize::default()));
+
+    assert!(svg.contains("Start Node: s"));
+    assert!(svg.contains("Path #1 (costs: 2)"));
+    assert!(svg.contains("Path #2 (costs: 5)"));
+    // Path #2 (one edge) is drawn first, then path #1 (two edges), then the edges themselves.
+    let first_edge = svg.find(r#"stroke-width="4" stroke-linecap="square""#).unwrap();
+    let rank_2 = svg.find(r#"stroke-width="14""#).unwrap();
+    let rank_1 = svg.find(r#"stroke-width="9""#).unwrap();
+    assert!(rank_2 < rank_1 && rank_1 < first_edge);
+    assert_eq!(count(&svg, r#"stroke-opacity="0.6""#), 3 + 2);
+}
+
+const MULTIGRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="g" edgedefault="directed">
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <edge id="e0" source="a" target="b" weight="1"/>
+        <edge id="e1" source="a" target="b" weight="4"/>
+        <edge id="e2" source="b" target="a" weight="2"/>
+        <edge id="e3" source="b" target="c" weight="3" directed="false"/>
+    </graph>
+</graphml>"#;
+
+#[test]
+fn draws_arrowheads_and_separates_parallel_edges() {
+    let graph = GraphDocument::parse(MULTIGRAPH).unwrap().graph;
+    let a = graph.node_index("a").unwrap();
+
+    let positions = dijkstra_plot::layout(&graph, a);
+    let plot = GraphOutput::plot(&graph, &positions, &Highlight::None, None);
+
+    let edges: Vec<(&str, bool, f32)> = plot.edges.iter().map(|edge| (edge.id.as_str(), edge.directed, edge.bend)).collect();
+    // Three edges between a and b: One straight in the middle, the others on both sides (b -> a is reversed, so its bend
+    // is negated).
+    assert_eq!(edges, vec![("e0", true, -1.0), ("e1", true, 0.0), ("e2", true, -1.0), ("e3", false, 0.0)]);
+
+    let svg = SvgRenderer::render(&Scene::build(&plot, None, ImageSize::default()));
+    assert_eq!(count(&svg, "<polygon"), 3);
+    assert_eq!(count(&svg, "<path"), 2);
+    // Straight edges and two lines in legend.
+    assert_eq!(count(&svg, "<line"), 2 + 2);
+}
+
+#[test]
+fn ranked_paths_follow_curves_of_parallel_edges() {
+    let graph = GraphDocument::parse(MULTIGRAPH).unwrap().graph;
+    let (a, b) = (graph.node_index("a").unwrap(), graph.node_index("b").unwrap());
+
+    // Path #1 takes curved e0, path #2 straight e1.
+    let paths = Yen::run(&graph, a, b, 2).unwrap();
+    let positions = dijkstra_plot::layout(&graph, a);
+    let plot = GraphOutput::plot(&graph, &positions, &Highlight::Path(&paths[0]), Some(&paths));
+    let bends: Vec<(usize, f32)> = plot.paths.iter().map(|path| (path.rank, path.bend)).collect();
+    assert_eq!(bends, vec![(1, -1.0), (2, 0.0)]);
+
+    let svg = SvgRenderer::render(&Scene::build(&plot, None, ImageSize::default()));
+    // Curved edges and the underlay of path #1, which is the curve with width of rank 1.
+    assert_eq!(count(&svg, "<path"), 2 + 1);
+    let underlay = svg.find(r#"stroke-width="9""#).unwrap();
+    assert_eq!(svg[..underlay].rfind("<path"), svg[..underlay].rfind('<'));
+}
@@ -0,0 +1,134 @@
+// Integration tests for Yen's algorithm: Ranked loopless paths are compared with all loopless paths enumerated by depth
+// first search.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use dijkstra_plot::Dijkstra::DijkstraPath;
+use dijkstra_plot::Graph::{EdgeIndex, Graph, IgraphObject, NodeIndex};
+use dijkstra_plot::GraphDocument::GraphDocument;
+use dijkstra_plot::Yen::Yen;
+
+// All six loopless paths from s to t have different costs:
+// s a b c t (5), s b c t (6), s a c t (7), s a b t (8), s b t (9), s a t (10).
+const SMALL_GRAPH: &str = r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+    <graph id="small" edgedefault="directed">
+        <node id="s"/>
+        <node id="a"/>
+        <node id="b"/>
+        <node id="c"/>
+        <node id="t"/>
+        <edge id="sa" source="s" target="a" directed="true" weight="1"/>
+        <edge id="sb" source="s" target="b" directed="true" weight="3"/>
+        <edge id="ab" source="a" target="b" directed="true" weight="1"/>
+        <edge id="ac" source="a" target="c" directed="true" weight="4"/>
+        <edge id="bc" source="b" target="c" directed="true" weight="1"/>
+        <edge id="bt" source="b" target="t" directed="true" weight="6"/>
+        <edge id="ct" source="c" target="t" directed="true" weight="2"/>
+        <edge id="at" source="a" target="t" directed="true" weight="9"/>
+    </graph>
+</graphml>"#;
+
+fn load(xml: &str) -> Graph {
+    let document = GraphDocument::parse(xml).unwrap();
+    assert!(document.diagnostics.is_empty(), "{:?}", document.diagnostics);
+    document.graph
+}
+
+// Node ids and costs of a found path.
+fn ids(graph: &Graph, path: &DijkstraPath) -> (String, i64) {
+    match path {
+        DijkstraPath::Reachable { nodes, cost, .. } => (nodes.iter().map(|&v| graph.node(v).get_id()).collect::<Vec<_>>().join(" "), *cost),
+        DijkstraPath::Unreachable => panic!("ranked paths are reachable"),
+    }
+}
+
+// Costs of all loopless paths from u to t (each sequence of edges counts, so parallel edges give several paths).
+fn enumerate(graph: &Graph, u: NodeIndex, t: NodeIndex, visited: &mut Vec<bool>, cost: i64, costs: &mut Vec<i64>) {
+    if u == t {
+        costs.push(cost);
+        return;
+    }
+
+    visited[u.index()] = true;
+    for &(v, e) in graph.neighbors(u).iter() {
+        if !visited[v.index()] {
+            enumerate(graph, v, t, visited, cost + graph.edge(e).weight(), costs);
+        }
+    }
+    visited[u.index()] = false;
+}
+
+// Each path has to be a loopless walk along existing connections from s to t with the given costs.
+fn assert_valid(graph: &Graph, s: NodeIndex, t: NodeIndex, path: &DijkstraPath) {
+    let DijkstraPath::Reachable { nodes, edges, cost } = path else {
+        panic!("ranked paths are reachable");
+    };
+
+    assert_eq!((nodes.first(), nodes.last()), (Some(&s), Some(&t)));
+    assert_eq!(nodes.len(), edges.len() + 1);
+    for (i, &e) in edges.iter().enumerate() {
+        assert!(graph.neighbors(nodes[i]).contains(&(nodes[i + 1], e)), "edge {} doesn't lead from {} to {}", e.index(), nodes[i].index(), nodes[i + 1].index());
+        assert!(!nodes[..=i].contains(&nodes[i + 1]), "path has a loop");
+    }
+    assert_eq!(*cost, edges.iter().map(|&e| graph.edge(e).weight()).sum::<i64>());
+}
+
+#[test]
+fn ranks_known_paths_of_small_graph() {
+    let graph = load(SMALL_GRAPH);
+    let (s, t) = (graph.node_index("s").unwrap(), graph.node_index("t").unwrap());
+    let expected = [("s a b c t", 5), ("s b c t", 6), ("s a c t", 7), ("s a b t", 8), ("s b t", 9), ("s a t", 10)];
+    let expected: Vec<(String, i64)> = expected.iter().map(|&(path, cost)| (path.to_string(), cost)).collect();
+
+    for k in [1, 3, 4, 6] {
+        let paths: Vec<(String, i64)> = Yen::run(&graph, s, t, k).unwrap().iter().map(|path| ids(&graph, path)).collect();
+        assert_eq!(paths, expected[..k], "k = {}", k);
+    }
+
+    // Asking for more paths than there are gives all of them.
+    let paths: Vec<(String, i64)> = Yen::run(&graph, s, t, 20).unwrap().iter().map(|path| ids(&graph, path)).collect();
+    assert_eq!(paths, expected);
+}
+
+#[test]
+fn ranks_all_loopless_paths_of_random_graphs() {
+    for seed in 0..10 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let node_len = 8;
+
+        let mut xml = String::from(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns"><graph id="random" edgedefault="directed">"#);
+        for v in 0..node_len {
+            xml.push_str(&format!(r#"<node id="n{}"/>"#, v));
+        }
+        for i in 0..16 {
+            let (u, v) = (rng.gen_range(0..node_len), rng.gen_range(0..node_len));
+            xml.push_str(&format!(r#"<edge id="e{}" source="n{}" target="n{}" weight="{}" directed="{}"/>"#, i, u, v, rng.gen_range(1..10), rng.gen_bool(0.7)));
+        }
+        xml.push_str("</graph></graphml>");
+        let graph = load(&xml);
+        let (s, t) = (graph.node_index("n0").unwrap(), graph.node_index("n1").unwrap());
+
+        let mut expected = Vec::new();
+        enumerate(&graph, s, t, &mut vec![false; node_len], 0, &mut expected);
+        expected.sort();
+
+        // Paths of equal costs may come in any order, so only costs are compared. All paths are requested and some more.
+        let paths = Yen::run(&graph, s, t, expected.len() + 3).unwrap();
+        for path in paths.iter() {
+            assert_valid(&graph, s, t, path);
+        }
+        let edges: Vec<&Vec<EdgeIndex>> = paths
+            .iter()
+            .map(|path| match path {
+                DijkstraPath::Reachable { edges, .. } => edges,
+                DijkstraPath::Unreachable => panic!("ranked paths are reachable"),
+            })
+            .collect();
+        for (i, path) in edges.iter().enumerate() {
+            assert!(!edges[..i].contains(path), "seed {}: path {} was found twice", seed, i);
+        }
+        let costs: Vec<i64> = paths.iter().map(|path| ids(&graph, path).1).collect();
+        assert_eq!(costs, expected, "seed {}", seed);
+    }
+}